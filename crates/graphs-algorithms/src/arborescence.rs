@@ -0,0 +1,539 @@
+//! Minimum and maximum spanning arborescences of directed graphs.
+
+use core::ops::Neg;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    data::DataRef,
+    edges::DirectedEdges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+use graphs_union_find::rollback::RollbackUnionFind;
+use thiserror::Error;
+
+use crate::{EDGE, weight::Weight};
+
+/// Represents spanning arborescences, that is, directed spanning trees rooted at some node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Arborescence<E, W> {
+    /// The parent edge of every node, indexed by node indices.
+    ///
+    /// The root (along with node indices not present in the graph) has no parent edge.
+    pub parents: Vec<Option<E>>,
+
+    /// The total weight of the parent edges.
+    pub weight: W,
+}
+
+impl<E, W> Arborescence<E, W> {
+    /// Constructs [`Self`].
+    pub const fn new(parents: Vec<Option<E>>, weight: W) -> Self {
+        Self { parents, weight }
+    }
+}
+
+/// Represents [`Arborescence`] of the graph `G`, with edge weights `W`.
+pub type ArborescenceOf<G, W> = Arborescence<<G as Base>::EdgeId, W>;
+
+/// Represents errors returned when some node can not be reached from the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("node `{node}` is unreachable from the root")]
+pub struct Unreachable<N> {
+    /// The unreachable node.
+    pub node: N,
+}
+
+impl<N> Unreachable<N> {
+    /// Constructs [`Self`].
+    pub const fn new(node: N) -> Self {
+        Self { node }
+    }
+}
+
+/// Represents [`Unreachable`] errors of the graph `G`.
+pub type UnreachableIn<G> = Unreachable<<G as Base>::NodeId>;
+
+/// Represents results of [`min_arborescence`] and [`max_arborescence`].
+pub type Output<G, W> = Result<ArborescenceOf<G, W>, UnreachableIn<G>>;
+
+struct Candidate<E, W> {
+    edge: E,
+    source: usize,
+    target: usize,
+    weight: W,
+}
+
+impl<E, W> Candidate<E, W> {
+    const fn new(edge: E, source: usize, target: usize, weight: W) -> Self {
+        Self {
+            edge,
+            source,
+            target,
+            weight,
+        }
+    }
+}
+
+struct Entry<W> {
+    candidate: usize,
+    key: W,
+    reduction: W,
+    rank: usize,
+    left: usize,
+    right: usize,
+}
+
+/// Leftist heaps with lazy key reductions, stored in one arena.
+///
+/// Heaps are identified by their root entries, and [`usize::LIMIT`] denotes empty heaps.
+struct Heaps<W> {
+    entries: Vec<Entry<W>>,
+}
+
+impl<W: Weight> Heaps<W> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn singleton(&mut self, candidate: usize, key: W) -> usize {
+        let entry = Entry {
+            candidate,
+            key,
+            reduction: W::ZERO,
+            rank: 1,
+            left: usize::LIMIT,
+            right: usize::LIMIT,
+        };
+
+        self.entries.push(entry);
+
+        self.entries.len() - 1
+    }
+
+    fn rank(&self, heap: usize) -> usize {
+        if heap.is_limit() {
+            0
+        } else {
+            self.entries[heap].rank
+        }
+    }
+
+    fn push_down(&mut self, heap: usize) {
+        let entry = &mut self.entries[heap];
+
+        let reduction = entry.reduction;
+
+        entry.key = entry.key - reduction;
+        entry.reduction = W::ZERO;
+
+        let (left, right) = (entry.left, entry.right);
+
+        for child in [left, right] {
+            if !child.is_limit() {
+                let entry = &mut self.entries[child];
+
+                entry.reduction = entry.reduction + reduction;
+            }
+        }
+    }
+
+    fn merge(&mut self, one: usize, two: usize) -> usize {
+        if one.is_limit() {
+            return two;
+        }
+
+        if two.is_limit() {
+            return one;
+        }
+
+        self.push_down(one);
+        self.push_down(two);
+
+        let (top, other) = if self.entries[two].key < self.entries[one].key {
+            (two, one)
+        } else {
+            (one, two)
+        };
+
+        let right = self.merge(self.entries[top].right, other);
+        let left = self.entries[top].left;
+
+        // keep the right spine short, which bounds the recursion depth
+
+        let (left, right) = if self.rank(left) < self.rank(right) {
+            (right, left)
+        } else {
+            (left, right)
+        };
+
+        let rank = self.rank(right) + 1;
+
+        let entry = &mut self.entries[top];
+
+        entry.left = left;
+        entry.right = right;
+        entry.rank = rank;
+
+        top
+    }
+
+    fn top(&mut self, heap: usize) -> (usize, W) {
+        self.push_down(heap);
+
+        let entry = &self.entries[heap];
+
+        (entry.candidate, entry.key)
+    }
+
+    fn pop(&mut self, heap: usize) -> usize {
+        self.push_down(heap);
+
+        let entry = &self.entries[heap];
+
+        self.merge(entry.left, entry.right)
+    }
+
+    fn reduce(&mut self, heap: usize, amount: W) {
+        let entry = &mut self.entries[heap];
+
+        entry.reduction = entry.reduction + amount;
+    }
+}
+
+struct Contraction {
+    node: usize,
+    time: usize,
+    candidates: Vec<usize>,
+}
+
+/// Finds the minimum spanning arborescence of the given graph rooted at the given `root`.
+///
+/// This function implements the Chu-Liu/Edmonds algorithm, specifically Tarjan's variant
+/// running in `O(E log V)` time, which contracts cycles using mergeable heaps of incoming edges
+/// and a union-find that can be rolled back to recover the chosen edges.
+///
+/// Edge costs are computed once per edge via the given `cost` function. Loops are ignored.
+///
+/// # Errors
+///
+/// Returns [`Unreachable`] if some node can not be reached from the `root`.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn min_arborescence<G, W, F>(graph: G, root: G::NodeId, mut cost: F) -> Output<G, W>
+where
+    G: DirectedEdges + NodeIdentifiers + NodeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let bound = graph.node_bound();
+
+    let mut candidates = Vec::new();
+
+    for node in graph.node_identifiers() {
+        let target = graph.node_index(node);
+
+        for edge in graph.incoming_edges(node) {
+            let (source, _) = graph.endpoints(edge).expect(EDGE);
+
+            let source = graph.node_index(source);
+
+            // loops never belong to arborescences
+            if source == target {
+                continue;
+            }
+
+            let value = cost(graph.edge_value(edge).expect(EDGE));
+
+            candidates.push(Candidate::new(edge, source, target, value));
+        }
+    }
+
+    let mut heaps = Heaps::with_capacity(candidates.len());
+
+    let mut incoming = vec![usize::LIMIT; bound];
+
+    for (position, candidate) in candidates.iter().enumerate() {
+        let singleton = heaps.singleton(position, candidate.weight);
+
+        let target = candidate.target;
+
+        incoming[target] = heaps.merge(incoming[target], singleton);
+    }
+
+    let mut union_find: RollbackUnionFind = RollbackUnionFind::new(bound);
+
+    let root_index = graph.node_index(root);
+
+    let mut seen = vec![usize::LIMIT; bound];
+
+    seen[root_index] = root_index;
+
+    let mut chosen = vec![usize::LIMIT; bound];
+
+    let mut path = Vec::new();
+    let mut taken = Vec::new();
+
+    let mut contractions = Vec::new();
+
+    let mut weight = W::ZERO;
+
+    for start in graph.node_identifiers() {
+        let start = graph.node_index(start);
+
+        let mut node = start;
+
+        path.clear();
+        taken.clear();
+
+        while seen[node].is_limit() {
+            // find the cheapest edge entering the component from the outside
+
+            let (position, key) = loop {
+                let heap = incoming[node];
+
+                if heap.is_limit() {
+                    return Err(Unreachable::new(graph.node_id(node)));
+                }
+
+                let (position, key) = heaps.top(heap);
+
+                if union_find.find(candidates[position].source) == node {
+                    incoming[node] = heaps.pop(heap);
+                } else {
+                    break (position, key);
+                }
+            };
+
+            // reduce the costs of all edges entering the component
+
+            heaps.reduce(incoming[node], key);
+
+            incoming[node] = heaps.pop(incoming[node]);
+
+            taken.push(position);
+            path.push(node);
+
+            seen[node] = start;
+
+            weight = weight + key;
+
+            node = union_find.find(candidates[position].source);
+
+            if seen[node] == start {
+                // found a cycle, contract it into one component
+
+                let time = union_find.time();
+
+                let mut merged = usize::LIMIT;
+
+                while let Some(other) = path.pop() {
+                    merged = heaps.merge(merged, incoming[other]);
+
+                    if !union_find.union(node, other) {
+                        break;
+                    }
+                }
+
+                let cycle = taken.split_off(path.len());
+
+                node = union_find.find(node);
+
+                incoming[node] = merged;
+
+                seen[node] = usize::LIMIT;
+
+                contractions.push(Contraction {
+                    node,
+                    time,
+                    candidates: cycle,
+                });
+            }
+        }
+
+        for &position in &taken {
+            chosen[union_find.find(candidates[position].target)] = position;
+        }
+    }
+
+    // expand contracted cycles in reverse order, recovering the chosen edges
+
+    for contraction in contractions.iter().rev() {
+        union_find.rollback(contraction.time);
+
+        let entering = chosen[contraction.node];
+
+        for &position in &contraction.candidates {
+            chosen[union_find.find(candidates[position].target)] = position;
+        }
+
+        chosen[union_find.find(candidates[entering].target)] = entering;
+    }
+
+    let parents = chosen
+        .into_iter()
+        .enumerate()
+        .map(|(index, position)| {
+            if index == root_index || position.is_limit() {
+                None
+            } else {
+                Some(candidates[position].edge)
+            }
+        })
+        .collect();
+
+    Ok(Arborescence::new(parents, weight))
+}
+
+/// Finds the maximum spanning arborescence of the given graph rooted at the given `root`.
+///
+/// This function negates the costs and calls [`min_arborescence`], see it for more information.
+///
+/// # Errors
+///
+/// Returns [`Unreachable`] if some node can not be reached from the `root`.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn max_arborescence<G, W, F>(graph: G, root: G::NodeId, mut cost: F) -> Output<G, W>
+where
+    G: DirectedEdges + NodeIdentifiers + NodeIndexed + Endpoints + DataRef,
+    W: Weight + Neg<Output = W>,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let mut arborescence = min_arborescence(graph, root, |value| -cost(value))?;
+
+    arborescence.weight = -arborescence.weight;
+
+    Ok(arborescence)
+}
+
+/// Represents the minimum spanning arborescence algorithm.
+///
+/// See [`min_arborescence`] for more information.
+pub struct MinArborescence<N, F> {
+    root: N,
+    cost: F,
+}
+
+impl<N, F> MinArborescence<N, F> {
+    /// Constructs [`Self`] with the given `root` and `cost` function.
+    pub const fn new(root: N, cost: F) -> Self {
+        Self { root, cost }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for MinArborescence<G::NodeId, F>
+where
+    G: DirectedEdges + NodeIdentifiers + NodeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Output<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        min_arborescence(graph, self.root, &mut self.cost)
+    }
+}
+
+/// Represents the maximum spanning arborescence algorithm.
+///
+/// See [`max_arborescence`] for more information.
+pub struct MaxArborescence<N, F> {
+    root: N,
+    cost: F,
+}
+
+impl<N, F> MaxArborescence<N, F> {
+    /// Constructs [`Self`] with the given `root` and `cost` function.
+    pub const fn new(root: N, cost: F) -> Self {
+        Self { root, cost }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for MaxArborescence<G::NodeId, F>
+where
+    G: DirectedEdges + NodeIdentifiers + NodeIndexed + Endpoints + DataRef,
+    W: Weight + Neg<Output = W>,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Output<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        max_arborescence(graph, self.root, &mut self.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::DiGraph;
+
+    fn graph() -> DiGraph<i64> {
+        DiGraph::weighted(
+            4,
+            [
+                (0, 1, 10),
+                (0, 2, 10),
+                (1, 2, 1),
+                (2, 1, 1),
+                (2, 3, 2),
+                (1, 3, 8),
+                (3, 3, -5),
+            ],
+        )
+    }
+
+    #[test]
+    fn minimum() {
+        let graph = graph();
+
+        let arborescence = min_arborescence(&graph, NodeId::new(0), |&cost| cost).unwrap();
+
+        assert_eq!(arborescence.weight, 13);
+
+        let parents: Vec<_> = arborescence
+            .parents
+            .iter()
+            .map(|parent| parent.map(|edge| edge.get()))
+            .collect();
+
+        // either of the edges into the cycle can be used
+        assert!(
+            parents == [None, Some(0), Some(2), Some(4)]
+                || parents == [None, Some(3), Some(1), Some(4)]
+        );
+    }
+
+    #[test]
+    fn maximum() {
+        let graph = graph();
+
+        let arborescence = max_arborescence(&graph, NodeId::new(0), |&cost| cost).unwrap();
+
+        assert_eq!(arborescence.weight, 28);
+    }
+
+    #[test]
+    fn unreachable() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (2, 1)]);
+
+        let error = min_arborescence(&graph, NodeId::new(0), |()| 1).unwrap_err();
+
+        assert_eq!(error.node, NodeId::new(2));
+    }
+}
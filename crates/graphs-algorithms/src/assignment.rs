@@ -0,0 +1,320 @@
+//! Minimum-cost assignments in bipartite graphs.
+
+use core::ops::Neg;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    data::DataRef,
+    edges::Edges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+use thiserror::Error;
+
+use crate::{EDGE, weight::Weight};
+
+/// Represents assignments of every left node to distinct right nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Assignment<E, W> {
+    /// The edges assigning left nodes to right nodes, in the order of left nodes.
+    pub edges: Vec<E>,
+
+    /// The total cost of the assignment.
+    pub cost: W,
+}
+
+impl<E, W> Assignment<E, W> {
+    /// Constructs [`Self`].
+    pub const fn new(edges: Vec<E>, cost: W) -> Self {
+        Self { edges, cost }
+    }
+}
+
+/// Represents [`Assignment`] of the graph `G`, with edge costs `W`.
+pub type AssignmentOf<G, W> = Assignment<<G as Base>::EdgeId, W>;
+
+/// Represents errors returned when some left node can not be assigned
+/// without leaving some other left node unassigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("node `{node}` can not be assigned")]
+pub struct Unassignable<N> {
+    /// The node that could not be assigned.
+    pub node: N,
+}
+
+impl<N> Unassignable<N> {
+    /// Constructs [`Self`].
+    pub const fn new(node: N) -> Self {
+        Self { node }
+    }
+}
+
+/// Represents [`Unassignable`] errors of the graph `G`.
+pub type UnassignableIn<G> = Unassignable<<G as Base>::NodeId>;
+
+/// Represents results of [`hungarian`].
+pub type Output<G, W> = Result<AssignmentOf<G, W>, UnassignableIn<G>>;
+
+/// Finds the minimum-cost assignment of left nodes to right nodes using the Hungarian algorithm,
+/// where the `left` function determines which side of the partition every node is on.
+///
+/// Every left node is assigned to exactly one right node via some edge between them,
+/// so there must be at least as many right nodes as there are left ones.
+/// Among parallel edges, the cheapest ones are used.
+///
+/// Left nodes are added one by one, each time finding the shortest augmenting path
+/// via Dijkstra-like relaxation of reduced costs, which are kept non-negative by potentials.
+/// This function runs in `O(L^2 R)` time and uses `O(L R)` memory for the cost matrix.
+///
+/// Edge costs are computed once per edge via the given `cost` function.
+/// Edges connecting nodes on the same side are ignored.
+///
+/// # Errors
+///
+/// Returns [`Unassignable`] if there is no assignment of all left nodes.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn hungarian<G, W, F, C>(graph: G, mut left: F, mut cost: C) -> Output<G, W>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints + DataRef,
+    W: Weight + Neg<Output = W>,
+    F: FnMut(G::NodeId) -> bool,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    let bound = graph.node_bound();
+
+    let mut sides = vec![false; bound];
+    let mut positions = vec![usize::LIMIT; bound];
+
+    let mut lefts = Vec::new();
+    let mut rights = 0;
+
+    for node in graph.node_identifiers() {
+        let index = graph.node_index(node);
+
+        sides[index] = left(node);
+
+        if sides[index] {
+            positions[index] = lefts.len();
+
+            lefts.push(node);
+        } else {
+            positions[index] = rights;
+
+            rights += 1;
+        }
+    }
+
+    // the cheapest edge between every left and right node, if any
+
+    let mut matrix: Vec<Option<(W, G::EdgeId)>> = vec![None; lefts.len() * rights];
+
+    for (row, &node) in lefts.iter().enumerate() {
+        for edge in graph.edges(node) {
+            let other = graph.opposite(edge, node).expect(EDGE);
+
+            let other_index = graph.node_index(other);
+
+            if sides[other_index] {
+                continue;
+            }
+
+            let value = cost(graph.edge_value(edge).expect(EDGE));
+
+            let entry = &mut matrix[row * rights + positions[other_index]];
+
+            if entry.is_none_or(|(best, _)| value.compare(&best).is_lt()) {
+                *entry = Some((value, edge));
+            }
+        }
+    }
+
+    // rows and columns are shifted by one, so that row zero and column zero are virtual
+
+    let mut row_potentials = vec![W::ZERO; lefts.len() + 1];
+    let mut column_potentials = vec![W::ZERO; rights + 1];
+
+    let mut assigned = vec![0; rights + 1];
+    let mut way = vec![0; rights + 1];
+
+    let mut slack: Vec<Option<W>> = vec![None; rights + 1];
+    let mut used = vec![false; rights + 1];
+
+    for row in 1..=lefts.len() {
+        assigned[0] = row;
+
+        let mut column = 0;
+
+        slack.fill(None);
+        used.fill(false);
+
+        loop {
+            used[column] = true;
+
+            let current = assigned[column];
+
+            let mut delta: Option<W> = None;
+            let mut next = 0;
+
+            for other in 1..=rights {
+                if used[other] {
+                    continue;
+                }
+
+                if let Some((value, _)) = matrix[(current - 1) * rights + other - 1] {
+                    let reduced = value - row_potentials[current] - column_potentials[other];
+
+                    if slack[other].is_none_or(|slack| reduced.compare(&slack).is_lt()) {
+                        slack[other] = Some(reduced);
+                        way[other] = column;
+                    }
+                }
+
+                if let Some(slack) = slack[other]
+                    && delta.is_none_or(|delta| slack.compare(&delta).is_lt())
+                {
+                    delta = Some(slack);
+                    next = other;
+                }
+            }
+
+            let Some(delta) = delta else {
+                return Err(Unassignable::new(lefts[row - 1]));
+            };
+
+            for other in 0..=rights {
+                if used[other] {
+                    row_potentials[assigned[other]] = row_potentials[assigned[other]] + delta;
+                    column_potentials[other] = column_potentials[other] - delta;
+                } else if let Some(slack) = &mut slack[other] {
+                    *slack = *slack - delta;
+                }
+            }
+
+            column = next;
+
+            if assigned[column] == 0 {
+                break;
+            }
+        }
+
+        // flip the augmenting path
+
+        while column != 0 {
+            let previous = way[column];
+
+            assigned[column] = assigned[previous];
+
+            column = previous;
+        }
+    }
+
+    let mut edges = vec![None; lefts.len()];
+    let mut total = W::ZERO;
+
+    for column in 1..=rights {
+        let row = assigned[column];
+
+        if row == 0 {
+            continue;
+        }
+
+        let (value, edge) =
+            matrix[(row - 1) * rights + column - 1].expect("expected assigned edge");
+
+        edges[row - 1] = Some(edge);
+
+        total = total + value;
+    }
+
+    let edges = edges
+        .into_iter()
+        .map(|edge| edge.expect("expected every left node to be assigned"))
+        .collect();
+
+    Ok(Assignment::new(edges, total))
+}
+
+/// Represents the Hungarian assignment algorithm.
+///
+/// See [`hungarian`] for more information.
+pub struct Hungarian<F, C> {
+    left: F,
+    cost: C,
+}
+
+impl<F, C> Hungarian<F, C> {
+    /// Constructs [`Self`] with the given `left` and `cost` functions.
+    pub const fn new(left: F, cost: C) -> Self {
+        Self { left, cost }
+    }
+}
+
+impl<G, W, F, C> Algorithm<G> for Hungarian<F, C>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints + DataRef,
+    W: Weight + Neg<Output = W>,
+    F: FnMut(G::NodeId) -> bool,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Output<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        hungarian(graph, &mut self.left, &mut self.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::UnGraph;
+
+    const COSTS: [[i64; 3]; 3] = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+
+    fn graph() -> UnGraph<i64> {
+        let edges =
+            (0..3).flat_map(|left| (0..3).map(move |right| (left, 3 + right, COSTS[left][right])));
+
+        UnGraph::weighted(6, edges)
+    }
+
+    #[test]
+    fn minimum_cost() {
+        let graph = graph();
+
+        let assignment = hungarian(&graph, |node| node.get() < 3, |&cost| cost).unwrap();
+
+        assert_eq!(assignment.cost, 5);
+
+        let mut pairs: Vec<_> = assignment
+            .edges
+            .iter()
+            .map(|&edge| graph.endpoints(edge).unwrap())
+            .map(|(left, right)| (left.get(), right.get()))
+            .collect();
+
+        pairs.sort_unstable();
+
+        assert_eq!(pairs, [(0, 4), (1, 3), (2, 5)]);
+    }
+
+    #[test]
+    fn unassignable() {
+        let graph = UnGraph::weighted(5, [(0, 3, 1), (1, 3, 1), (2, 4, 1)]);
+
+        let error = hungarian(&graph, |node| node.get() < 3, |&cost| cost).unwrap_err();
+
+        assert!(error.node == NodeId::new(0) || error.node == NodeId::new(1));
+    }
+}
@@ -0,0 +1,318 @@
+//! Biconnected components, articulation points and bridges of undirected graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    edges::Edges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+
+use crate::EDGE;
+
+/// Represents the biconnectivity structure of undirected graphs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Biconnectivity<N, E> {
+    /// The nodes whose removal increases the number of connected components.
+    pub articulation_points: Vec<N>,
+
+    /// The edges whose removal increases the number of connected components.
+    pub bridges: Vec<E>,
+
+    /// The partition of edges into biconnected components.
+    ///
+    /// Every loop forms its own component.
+    pub biconnected: Vec<Vec<E>>,
+
+    /// The partition of nodes into 2-edge-connected components,
+    /// that is, connected components remaining after removing all bridges.
+    pub two_edge_connected: Vec<Vec<N>>,
+}
+
+/// Represents [`Biconnectivity`] of the graph `G`.
+pub type BiconnectivityOf<G> = Biconnectivity<<G as Base>::NodeId, <G as Base>::EdgeId>;
+
+struct Frame<N, E, I> {
+    node: N,
+    index: usize,
+    parent: Option<E>,
+    edges: I,
+}
+
+/// Computes the biconnectivity structure of the given graph using the Hopcroft-Tarjan algorithm.
+///
+/// Only the exact edge used to enter some node is ignored when searching from it,
+/// so parallel edges are handled correctly (they are never bridges, for instance).
+///
+/// This function uses an explicit stack instead of recursion, so it can handle deep graphs.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn biconnectivity<G>(graph: G) -> BiconnectivityOf<G>
+where
+    G: Undirected + NodeIdentifiers + Edges + Endpoints + NodeIndexed,
+{
+    let bound = graph.node_bound();
+
+    let mut discovered = vec![usize::LIMIT; bound];
+    let mut low = vec![usize::LIMIT; bound];
+    let mut articulation = vec![false; bound];
+
+    let mut time = 0;
+
+    let mut frames: Vec<Frame<G::NodeId, G::EdgeId, G::Iterator<'_>>> = Vec::new();
+
+    let mut edge_stack = Vec::new();
+    let mut node_stack = Vec::new();
+
+    let mut loops = Vec::new();
+
+    let mut result = Biconnectivity {
+        articulation_points: Vec::new(),
+        bridges: Vec::new(),
+        biconnected: Vec::new(),
+        two_edge_connected: Vec::new(),
+    };
+
+    for root in graph.node_identifiers() {
+        let root_index = graph.node_index(root);
+
+        if !discovered[root_index].is_limit() {
+            continue;
+        }
+
+        discovered[root_index] = time;
+        low[root_index] = time;
+
+        time += 1;
+
+        node_stack.push(root);
+
+        frames.push(Frame {
+            node: root,
+            index: root_index,
+            parent: None,
+            edges: graph.edges(root),
+        });
+
+        let mut root_children = 0usize;
+
+        while let Some(frame) = frames.last_mut() {
+            let node = frame.node;
+            let index = frame.index;
+
+            if let Some(edge) = frame.edges.next() {
+                // only skip the exact edge we came from, not its parallel edges
+                if frame.parent == Some(edge) {
+                    continue;
+                }
+
+                let other = graph.opposite(edge, node).expect(EDGE);
+
+                if other == node {
+                    loops.push(edge);
+
+                    continue;
+                }
+
+                let other_index = graph.node_index(other);
+
+                if discovered[other_index].is_limit() {
+                    discovered[other_index] = time;
+                    low[other_index] = time;
+
+                    time += 1;
+
+                    edge_stack.push(edge);
+                    node_stack.push(other);
+
+                    frames.push(Frame {
+                        node: other,
+                        index: other_index,
+                        parent: Some(edge),
+                        edges: graph.edges(other),
+                    });
+                } else if discovered[other_index] < discovered[index] {
+                    // back edge to some ancestor
+
+                    low[index] = low[index].min(discovered[other_index]);
+
+                    edge_stack.push(edge);
+                }
+
+                // otherwise, this is a back edge from some descendant, which we have handled
+
+                continue;
+            }
+
+            let Some(Frame { parent, .. }) = frames.pop() else {
+                break;
+            };
+
+            let Some(parent_frame) = frames.last() else {
+                // finished the root, so the remaining nodes form the last component
+
+                result.two_edge_connected.push(node_stack.split_off(0));
+
+                if root_children > 1 {
+                    articulation[root_index] = true;
+                }
+
+                continue;
+            };
+
+            let parent_index = parent_frame.index;
+
+            low[parent_index] = low[parent_index].min(low[index]);
+
+            if parent_index == root_index {
+                root_children += 1;
+            }
+
+            if low[index] >= discovered[parent_index] {
+                // the parent separates the subtree of this node from the rest
+
+                if parent_index != root_index {
+                    articulation[parent_index] = true;
+                }
+
+                let parent = parent.expect("expected tree edge");
+
+                let mut component = Vec::new();
+
+                while let Some(edge) = edge_stack.pop() {
+                    component.push(edge);
+
+                    if edge == parent {
+                        break;
+                    }
+                }
+
+                result.biconnected.push(component);
+
+                if low[index] > discovered[parent_index] {
+                    result.bridges.push(parent);
+
+                    let start = node_stack
+                        .iter()
+                        .rposition(|&other| other == node)
+                        .expect("expected node on the stack");
+
+                    result.two_edge_connected.push(node_stack.split_off(start));
+                }
+            }
+        }
+    }
+
+    result.articulation_points = articulation
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, articulation)| articulation)
+        .map(|(index, _)| graph.node_id(index))
+        .collect();
+
+    // loops can be yielded twice, once for each endpoint
+
+    loops.sort_unstable();
+    loops.dedup();
+
+    result
+        .biconnected
+        .extend(loops.into_iter().map(|edge| vec![edge]));
+
+    result
+}
+
+/// Represents the Hopcroft-Tarjan biconnectivity algorithm.
+///
+/// See [`biconnectivity`] for more information.
+pub struct Biconnected;
+
+impl<G> Algorithm<G> for Biconnected
+where
+    G: Undirected + NodeIdentifiers + Edges + Endpoints + NodeIndexed,
+{
+    type Output = BiconnectivityOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        biconnectivity(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::UnGraph;
+
+    fn sorted<T, I: IntoIterator<Item = T>>(items: I, index: fn(T) -> usize) -> Vec<usize> {
+        let mut indices: Vec<_> = items.into_iter().map(index).collect();
+
+        indices.sort_unstable();
+
+        indices
+    }
+
+    #[test]
+    fn structure() {
+        let graph = UnGraph::unweighted(
+            6,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 4),
+                (4, 3),
+                (4, 5),
+                (5, 5),
+            ],
+        );
+
+        let structure = biconnectivity(&graph);
+
+        assert_eq!(
+            sorted(structure.articulation_points, |node| node.get()),
+            [2, 3, 4]
+        );
+
+        // parallel edges are never bridges
+        assert_eq!(sorted(structure.bridges, |edge| edge.get()), [3, 6]);
+
+        let mut biconnected: Vec<_> = structure
+            .biconnected
+            .into_iter()
+            .map(|component| sorted(component, |edge| edge.get()))
+            .collect();
+
+        biconnected.sort_unstable();
+
+        assert_eq!(biconnected, [vec![0, 1, 2], vec![3], vec![4, 5], vec![6], vec![7]]);
+
+        let mut two_edge_connected: Vec<_> = structure
+            .two_edge_connected
+            .into_iter()
+            .map(|component| sorted(component, |node| node.get()))
+            .collect();
+
+        two_edge_connected.sort_unstable();
+
+        assert_eq!(two_edge_connected, [vec![0, 1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn isolated() {
+        let structure = biconnectivity(&UnGraph::unweighted(2, &[]));
+
+        assert!(structure.articulation_points.is_empty());
+        assert!(structure.bridges.is_empty());
+        assert!(structure.biconnected.is_empty());
+        assert_eq!(structure.two_edge_connected.len(), 2);
+    }
+}
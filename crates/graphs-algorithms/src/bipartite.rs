@@ -0,0 +1,292 @@
+//! Bipartiteness testing of undirected graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    edges::Edges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+use thiserror::Error;
+
+use crate::EDGE;
+
+/// Represents partitions of nodes into two sides, such that every edge connects different sides.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Bipartition<N> {
+    /// The nodes on the left side.
+    pub left: Vec<N>,
+
+    /// The nodes on the right side.
+    pub right: Vec<N>,
+
+    /// Whether every node is on the left side, indexed by node indices.
+    ///
+    /// Node indices not present in the graph are considered to be on the right side.
+    pub sides: Vec<bool>,
+}
+
+impl<N> Bipartition<N> {
+    /// Constructs [`Self`].
+    pub const fn new(left: Vec<N>, right: Vec<N>, sides: Vec<bool>) -> Self {
+        Self { left, right, sides }
+    }
+
+    /// Checks whether the node with the given index is on the left side.
+    pub fn is_left(&self, index: usize) -> bool {
+        self.sides.get(index).copied().unwrap_or(false)
+    }
+}
+
+/// Represents [`Bipartition`] of the graph `G`.
+pub type BipartitionOf<G> = Bipartition<<G as Base>::NodeId>;
+
+/// Represents errors returned when graphs are not bipartite, along with some odd cycle found.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("graph contains an odd cycle of {} edges", .edges.len())]
+pub struct OddCycle<E> {
+    /// The edges of the cycle, in order.
+    ///
+    /// Every edge shares one endpoint with the next one,
+    /// and the last edge shares the other endpoint with the first one.
+    pub edges: Vec<E>,
+}
+
+impl<E> OddCycle<E> {
+    /// Constructs [`Self`].
+    pub const fn new(edges: Vec<E>) -> Self {
+        Self { edges }
+    }
+}
+
+/// Represents [`OddCycle`] errors of the graph `G`.
+pub type OddCycleIn<G> = OddCycle<<G as Base>::EdgeId>;
+
+/// Represents results of [`bipartition`].
+pub type Output<G> = Result<BipartitionOf<G>, OddCycleIn<G>>;
+
+/// Partitions the nodes of the given graph into two sides via breadth-first search.
+///
+/// Every connected component is colored separately, starting from its first node
+/// (in the order of node identifiers), which is put on the left side.
+///
+/// If some edge connects two nodes of the same color, both of them are at the same depth
+/// of the search tree, so the tree paths from them to their lowest common ancestor
+/// along with this edge form an odd cycle. Loops are odd cycles too.
+///
+/// # Errors
+///
+/// Returns [`OddCycle`] containing some odd cycle of the graph if it is not bipartite.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn bipartition<G>(graph: G) -> Output<G>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    let bound = graph.node_bound();
+
+    let mut depths = vec![usize::LIMIT; bound];
+    let mut parents: Vec<Option<(G::NodeId, G::EdgeId)>> = vec![None; bound];
+
+    let mut queue = VecDeque::new();
+
+    for start in graph.node_identifiers() {
+        let start_index = graph.node_index(start);
+
+        if !depths[start_index].is_limit() {
+            continue;
+        }
+
+        depths[start_index] = 0;
+
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            let depth = depths[graph.node_index(node)];
+
+            for edge in graph.edges(node) {
+                let other = graph.opposite(edge, node).expect(EDGE);
+
+                let other_index = graph.node_index(other);
+
+                let other_depth = depths[other_index];
+
+                if other_depth.is_limit() {
+                    depths[other_index] = depth + 1;
+                    parents[other_index] = Some((node, edge));
+
+                    queue.push_back(other);
+                } else if other_depth % 2 == depth % 2 {
+                    return Err(witness(&graph, &depths, &parents, node, other, edge));
+                }
+            }
+        }
+    }
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    let mut sides = vec![false; bound];
+
+    for node in graph.node_identifiers() {
+        let index = graph.node_index(node);
+
+        if depths[index].is_multiple_of(2) {
+            sides[index] = true;
+
+            left.push(node);
+        } else {
+            right.push(node);
+        }
+    }
+
+    Ok(Bipartition::new(left, right, sides))
+}
+
+/// Builds the odd cycle closed by the `edge` connecting the nodes `one` and `two` of the same color.
+fn witness<G>(
+    graph: &G,
+    depths: &[usize],
+    parents: &[Option<(G::NodeId, G::EdgeId)>],
+    one: G::NodeId,
+    two: G::NodeId,
+    edge: G::EdgeId,
+) -> OddCycleIn<G>
+where
+    G: NodeIndexed,
+{
+    let parent = |node: G::NodeId| parents[graph.node_index(node)].expect("expected tree parent");
+
+    let depth = |node: G::NodeId| depths[graph.node_index(node)];
+
+    // climb from both nodes until they meet at their lowest common ancestor
+
+    let mut down = Vec::new();
+    let mut up = Vec::new();
+
+    let mut one = one;
+    let mut two = two;
+
+    while depth(one) > depth(two) {
+        let (next, edge) = parent(one);
+
+        down.push(edge);
+
+        one = next;
+    }
+
+    while depth(two) > depth(one) {
+        let (next, edge) = parent(two);
+
+        up.push(edge);
+
+        two = next;
+    }
+
+    while one != two {
+        let (next, edge) = parent(one);
+
+        down.push(edge);
+
+        one = next;
+
+        let (next, edge) = parent(two);
+
+        up.push(edge);
+
+        two = next;
+    }
+
+    // go down from the ancestor to the first node, then back up from the second one
+
+    down.reverse();
+
+    down.push(edge);
+
+    down.extend(up);
+
+    OddCycle::new(down)
+}
+
+/// Represents the breadth-first bipartiteness test.
+///
+/// See [`bipartition`] for more information.
+pub struct Bipartite;
+
+impl<G> Algorithm<G> for Bipartite
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    type Output = Output<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        bipartition(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::UnGraph;
+
+    #[test]
+    fn partition() {
+        let graph = UnGraph::unweighted(7, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 1), (4, 5)]);
+
+        let bipartition = bipartition(&graph).unwrap();
+
+        let left: Vec<_> = bipartition.left.iter().map(|node| node.get()).collect();
+        let right: Vec<_> = bipartition.right.iter().map(|node| node.get()).collect();
+
+        assert_eq!(left, [0, 2, 4, 6]);
+        assert_eq!(right, [1, 3, 5]);
+
+        assert!(bipartition.is_left(2));
+        assert!(!bipartition.is_left(5));
+        assert!(!bipartition.is_left(7));
+    }
+
+    #[test]
+    fn odd_cycle() {
+        let graph = UnGraph::unweighted(6, &[(5, 0), (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+
+        let cycle = bipartition(&graph).unwrap_err();
+
+        let mut edges: Vec<_> = cycle.edges.iter().map(|edge| edge.get()).collect();
+
+        // consecutive edges share endpoints
+        for (index, &edge) in cycle.edges.iter().enumerate() {
+            let next = cycle.edges[(index + 1) % cycle.edges.len()];
+
+            let (one, two) = graph.endpoints(edge).unwrap();
+            let (three, four) = graph.endpoints(next).unwrap();
+
+            assert!(one == three || one == four || two == three || two == four);
+        }
+
+        edges.sort_unstable();
+
+        assert_eq!(edges, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn loops() {
+        let graph = UnGraph::unweighted(2, &[(0, 1), (1, 1)]);
+
+        let cycle = bipartition(&graph).unwrap_err();
+
+        assert_eq!(cycle.edges.len(), 1);
+        assert_eq!(cycle.edges[0].get(), 1);
+    }
+}
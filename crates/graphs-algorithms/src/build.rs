@@ -0,0 +1,18 @@
+//! Errors that can occur when building graphs returned by algorithms.
+
+use graphs_core::build::Build;
+use thiserror::Error;
+
+/// Represents errors that can occur when building graphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum BuildError<N, E> {
+    /// Adding some node failed.
+    #[error("failed to add node: {0}")]
+    Node(N),
+    /// Adding some edge failed.
+    #[error("failed to add edge: {0}")]
+    Edge(E),
+}
+
+/// Represents [`BuildError`] returned when building the graph `C`.
+pub type BuildErrorOf<C> = BuildError<<C as Build>::NodeError, <C as Build>::EdgeError>;
@@ -0,0 +1,672 @@
+//! Betweenness, closeness and harmonic centrality.
+
+use core::cmp::Reverse;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::collections::{BinaryHeap, VecDeque};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::{EdgeIndexed, NodeIndexed},
+    kinds::Kind,
+};
+
+use crate::{EDGE, compact::compact_with, heap::Entry, random::Random};
+
+/// Represents sources of shortest path searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Sources {
+    /// All nodes, which gives exact results.
+    #[default]
+    All,
+
+    /// The given number of distinct nodes, sampled uniformly using the given seed,
+    /// which gives approximate results, extrapolated to all nodes.
+    Sample {
+        /// The number of sources.
+        count: usize,
+
+        /// The seed of the random number generator.
+        seed: u64,
+    },
+}
+
+/// Represents betweenness centrality of nodes and edges.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Betweenness {
+    /// The betweenness of every node, indexed by node indices.
+    ///
+    /// Node indices not present in the graph have zero betweenness.
+    pub nodes: Vec<f64>,
+
+    /// The betweenness of every edge, indexed by edge indices.
+    ///
+    /// Edge indices not present in the graph have zero betweenness.
+    pub edges: Vec<f64>,
+}
+
+impl Betweenness {
+    /// Constructs [`Self`].
+    pub const fn new(nodes: Vec<f64>, edges: Vec<f64>) -> Self {
+        Self { nodes, edges }
+    }
+
+    /// Returns the betweenness of the node with the given index, if the index is in bounds.
+    pub fn node_of(&self, index: usize) -> Option<f64> {
+        self.nodes.get(index).copied()
+    }
+
+    /// Returns the betweenness of the edge with the given index, if the index is in bounds.
+    pub fn edge_of(&self, index: usize) -> Option<f64> {
+        self.edges.get(index).copied()
+    }
+}
+
+/// Represents arcs of networks, going out of nodes.
+struct Arc {
+    target: usize,
+    edge: usize,
+    weight: f64,
+}
+
+/// Represents graphs with nodes referred to by their positions and arcs grouped by sources.
+///
+/// Edges of undirected graphs give arcs both ways, and loops are ignored.
+struct Network {
+    indices: Vec<usize>,
+    starts: Vec<usize>,
+    arcs: Vec<Arc>,
+}
+
+impl Network {
+    /// Constructs [`Self`], along with the edges that arcs refer to.
+    fn new<G, F>(graph: &G, mut weight: F) -> (Self, Vec<G::EdgeId>)
+    where
+        G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+        F: FnMut(&G::EdgeValue) -> f64,
+    {
+        let (indices, positions) = compact_with(graph, |node| graph.node_index(node));
+
+        let count = indices.len();
+
+        let mut edges = Vec::new();
+        let mut triples = Vec::new();
+
+        for edge in graph.edge_identifiers() {
+            let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+            if one == two {
+                continue;
+            }
+
+            let value = weight(graph.edge_value(edge).expect(EDGE));
+
+            let source = positions[graph.node_index(one)];
+            let target = positions[graph.node_index(two)];
+
+            triples.push((source, target, edges.len(), value));
+
+            if !G::Kind::DIRECTED {
+                triples.push((target, source, edges.len(), value));
+            }
+
+            edges.push(edge);
+        }
+
+        // group arcs by their sources, keeping the order of edges
+        triples.sort_by_key(|&(source, _, _, _)| source);
+
+        let mut starts = vec![0; count + 1];
+
+        for &(source, _, _, _) in &triples {
+            starts[source + 1] += 1;
+        }
+
+        for position in 0..count {
+            starts[position + 1] += starts[position];
+        }
+
+        let arcs = triples
+            .into_iter()
+            .map(|(_, target, edge, weight)| Arc {
+                target,
+                edge,
+                weight,
+            })
+            .collect();
+
+        (
+            Self {
+                indices,
+                starts,
+                arcs,
+            },
+            edges,
+        )
+    }
+
+    fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn arcs(&self, position: usize) -> &[Arc] {
+        &self.arcs[self.starts[position]..self.starts[position + 1]]
+    }
+}
+
+/// Represents single-source shortest path searches, counting shortest paths.
+struct Search {
+    /// The nodes reached, in the order of non-decreasing distances.
+    order: Vec<usize>,
+    distances: Vec<f64>,
+    counts: Vec<f64>,
+    /// The nodes and arcs preceding every node on shortest paths.
+    predecessors: Vec<Vec<(usize, usize)>>,
+    finished: Vec<bool>,
+    queue: VecDeque<usize>,
+    heap: BinaryHeap<Reverse<Entry<f64, usize>>>,
+}
+
+impl Search {
+    fn new(count: usize) -> Self {
+        Self {
+            order: Vec::new(),
+            distances: vec![f64::INFINITY; count],
+            counts: vec![0.0; count],
+            predecessors: vec![Vec::new(); count],
+            finished: vec![false; count],
+            queue: VecDeque::new(),
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Resets the nodes reached by the previous search.
+    fn reset(&mut self) {
+        for &node in &self.order {
+            self.distances[node] = f64::INFINITY;
+            self.counts[node] = 0.0;
+            self.predecessors[node].clear();
+            self.finished[node] = false;
+        }
+
+        self.order.clear();
+    }
+
+    /// Relaxes the arc from the given node, returning whether the target got closer.
+    fn relax(&mut self, node: usize, position: usize, arc: &Arc, distance: f64) -> bool {
+        let target = arc.target;
+
+        if distance < self.distances[target] {
+            self.distances[target] = distance;
+            self.counts[target] = self.counts[node];
+
+            self.predecessors[target].clear();
+            self.predecessors[target].push((node, position));
+
+            true
+        } else {
+            if distance == self.distances[target] {
+                self.counts[target] += self.counts[node];
+
+                self.predecessors[target].push((node, position));
+            }
+
+            false
+        }
+    }
+
+    /// Searches from the given source via breadth-first search, ignoring weights.
+    fn breadth_first(&mut self, network: &Network, source: usize) {
+        self.reset();
+
+        self.distances[source] = 0.0;
+        self.counts[source] = 1.0;
+
+        self.queue.push_back(source);
+
+        while let Some(node) = self.queue.pop_front() {
+            self.order.push(node);
+
+            let distance = self.distances[node] + 1.0;
+
+            for (offset, arc) in network.arcs(node).iter().enumerate() {
+                let position = network.starts[node] + offset;
+
+                if self.relax(node, position, arc, distance) {
+                    self.queue.push_back(arc.target);
+                }
+            }
+        }
+    }
+
+    /// Searches from the given source via Dijkstra's algorithm.
+    fn dijkstra(&mut self, network: &Network, source: usize) {
+        self.reset();
+
+        self.distances[source] = 0.0;
+        self.counts[source] = 1.0;
+
+        self.heap.push(Reverse(Entry::new(0.0, source)));
+
+        while let Some(Reverse(Entry {
+            key: distance,
+            value: node,
+        })) = self.heap.pop()
+        {
+            // skip outdated entries
+            if self.finished[node] || distance > self.distances[node] {
+                continue;
+            }
+
+            self.finished[node] = true;
+
+            self.order.push(node);
+
+            for (offset, arc) in network.arcs(node).iter().enumerate() {
+                // paths through finished nodes can only be longer, given positive weights
+                if self.finished[arc.target] {
+                    continue;
+                }
+
+                let position = network.starts[node] + offset;
+
+                let next = distance + arc.weight;
+
+                if self.relax(node, position, arc, next) {
+                    self.heap.push(Reverse(Entry::new(next, arc.target)));
+                }
+            }
+        }
+    }
+
+    fn run(&mut self, network: &Network, source: usize, weighted: bool) {
+        if weighted {
+            self.dijkstra(network, source);
+        } else {
+            self.breadth_first(network, source);
+        }
+    }
+}
+
+/// Returns the positions of sources to search from.
+fn sources(count: usize, sources: Sources) -> Vec<usize> {
+    let mut positions: Vec<usize> = (0..count).collect();
+
+    if let Sources::Sample {
+        count: sampled,
+        seed,
+    } = sources
+        && sampled < count
+    {
+        Random::new(seed).shuffle(&mut positions);
+
+        positions.truncate(sampled);
+    }
+
+    positions
+}
+
+fn brandes<G, F>(
+    graph: G,
+    normalized: bool,
+    sampling: Sources,
+    weighted: bool,
+    weight: F,
+) -> Betweenness
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, edges) = Network::new(&graph, weight);
+
+    let count = network.count();
+
+    let mut nodes = vec![0.0; count];
+    let mut arcs = vec![0.0; edges.len()];
+
+    let mut dependencies = vec![0.0; count];
+
+    let mut search = Search::new(count);
+
+    let sources = sources(count, sampling);
+
+    for &source in &sources {
+        search.run(&network, source, weighted);
+
+        for &node in &search.order {
+            dependencies[node] = 0.0;
+        }
+
+        // accumulate dependencies from the farthest nodes back to the source
+        for &node in search.order.iter().rev() {
+            let share = (1.0 + dependencies[node]) / search.counts[node];
+
+            for &(other, position) in &search.predecessors[node] {
+                let credit = search.counts[other] * share;
+
+                arcs[network.arcs[position].edge] += credit;
+
+                dependencies[other] += credit;
+            }
+
+            if node != source {
+                nodes[node] += dependencies[node];
+            }
+        }
+    }
+
+    let total = count as f64;
+
+    // extrapolate samples to all sources
+    let extrapolation = if sources.is_empty() {
+        1.0
+    } else {
+        total / sources.len() as f64
+    };
+
+    // undirected paths are counted from both of their ends
+    let halving = if G::Kind::DIRECTED { 1.0 } else { 0.5 };
+
+    let node_scale = if normalized && count > 2 {
+        1.0 / ((total - 1.0) * (total - 2.0))
+    } else {
+        halving
+    };
+
+    let edge_scale = if normalized && count > 1 {
+        1.0 / (total * (total - 1.0))
+    } else {
+        halving
+    };
+
+    let mut node_result = vec![0.0; graph.node_bound()];
+
+    for (position, &index) in network.indices.iter().enumerate() {
+        node_result[index] = nodes[position] * node_scale * extrapolation;
+    }
+
+    let mut edge_result = vec![0.0; graph.edge_bound()];
+
+    for (position, &edge) in edges.iter().enumerate() {
+        edge_result[graph.edge_index(edge)] = arcs[position] * edge_scale * extrapolation;
+    }
+
+    Betweenness::new(node_result, edge_result)
+}
+
+/// Computes betweenness centrality of every node and edge in the given graph,
+/// treating all edges equally.
+///
+/// See [`betweenness_by`] for more information.
+pub fn betweenness<G>(graph: G, normalized: bool, sources: Sources) -> Betweenness
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+{
+    brandes(graph, normalized, sources, false, |_| 1.0)
+}
+
+/// Computes betweenness centrality of every node and edge in the given graph,
+/// with edge lengths given by `weight`.
+///
+/// The betweenness of a node (or an edge) is the sum, over all pairs of other nodes,
+/// of fractions of shortest paths between them going through the node (or the edge);
+/// parallel edges give distinct paths, and loops are ignored.
+/// Paths in undirected graphs are counted once per pair of nodes.
+///
+/// When `normalized`, node betweenness is divided by `(V - 1)(V - 2)` and edge betweenness
+/// by `V (V - 1)`. When sampling [`Sources`], results are extrapolated to all sources.
+///
+/// This is Brandes' algorithm, which runs a Dijkstra search from every source,
+/// taking `O(S (E + V) log V)` time for `S` sources. Lengths must be positive.
+pub fn betweenness_by<G, F>(graph: G, normalized: bool, sources: Sources, weight: F) -> Betweenness
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    brandes(graph, normalized, sources, true, weight)
+}
+
+/// Computes closeness or harmonic centrality, depending on `harmonic`.
+fn proximity<G, F>(graph: G, harmonic: bool, weighted: bool, weight: F) -> Vec<f64>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, _) = Network::new(&graph, weight);
+
+    let count = network.count();
+
+    let mut search = Search::new(count);
+
+    let mut result = vec![0.0; graph.node_bound()];
+
+    for (source, &index) in network.indices.iter().enumerate() {
+        search.run(&network, source, weighted);
+
+        let distances = search
+            .order
+            .iter()
+            .map(|&node| search.distances[node])
+            .filter(|&distance| distance > 0.0);
+
+        result[index] = if harmonic {
+            distances.map(|distance| distance.recip()).sum()
+        } else {
+            let total: f64 = distances.sum();
+
+            let reached = (search.order.len() - 1) as f64;
+
+            if total > 0.0 {
+                // scale by the fraction of nodes reached (Wasserman and Faust)
+                (reached / total) * (reached / (count - 1) as f64)
+            } else {
+                0.0
+            }
+        };
+    }
+
+    result
+}
+
+/// Computes closeness centrality of every node in the given graph, treating all edges equally.
+///
+/// See [`closeness_by`] for more information.
+pub fn closeness<G>(graph: G) -> Vec<f64>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    proximity(graph, false, false, |_| 1.0)
+}
+
+/// Computes closeness centrality of every node in the given graph,
+/// with edge lengths given by `weight`.
+///
+/// The closeness of a node is the number of other nodes it reaches divided by
+/// the total distance to them, scaled by the fraction of other nodes it reaches,
+/// so that graphs that are not (strongly) connected are handled gracefully.
+/// Distances are measured *from* nodes; use [`Reversed`] graphs to measure them *to* nodes.
+///
+/// Results are indexed by node indices, and node indices not present in the graph
+/// have zero closeness. This function takes `O(V (E + V) log V)` time.
+/// Lengths must be positive.
+///
+/// [`Reversed`]: graphs_core::adapters::Reversed
+pub fn closeness_by<G, F>(graph: G, weight: F) -> Vec<f64>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    proximity(graph, false, true, weight)
+}
+
+/// Computes harmonic centrality of every node in the given graph, treating all edges equally.
+///
+/// See [`harmonic_by`] for more information.
+pub fn harmonic<G>(graph: G) -> Vec<f64>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    proximity(graph, true, false, |_| 1.0)
+}
+
+/// Computes harmonic centrality of every node in the given graph,
+/// with edge lengths given by `weight`.
+///
+/// The harmonic centrality of a node is the sum of reciprocals of distances
+/// from it to other nodes, where unreachable nodes contribute nothing.
+///
+/// See [`closeness_by`] for more information.
+pub fn harmonic_by<G, F>(graph: G, weight: F) -> Vec<f64>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    proximity(graph, true, true, weight)
+}
+
+/// Represents Brandes' betweenness centrality algorithm, treating all edges equally.
+///
+/// See [`betweenness`] for more information.
+pub struct Brandes {
+    normalized: bool,
+    sources: Sources,
+}
+
+impl Brandes {
+    /// Constructs [`Self`].
+    pub const fn new(normalized: bool, sources: Sources) -> Self {
+        Self {
+            normalized,
+            sources,
+        }
+    }
+}
+
+impl<G> Algorithm<G> for Brandes
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+{
+    type Output = Betweenness;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        betweenness(graph, self.normalized, self.sources)
+    }
+}
+
+/// Represents Brandes' betweenness centrality algorithm, with edge lengths given by the closure.
+///
+/// See [`betweenness_by`] for more information.
+pub struct BrandesBy<F> {
+    normalized: bool,
+    sources: Sources,
+    weight: F,
+}
+
+impl<F> BrandesBy<F> {
+    /// Constructs [`Self`].
+    pub const fn new(normalized: bool, sources: Sources, weight: F) -> Self {
+        Self {
+            normalized,
+            sources,
+            weight,
+        }
+    }
+}
+
+impl<G, F> Algorithm<G> for BrandesBy<F>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    type Output = Betweenness;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        betweenness_by(graph, self.normalized, self.sources, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, UnGraph};
+
+    const EPSILON: f64 = 1e-9;
+
+    fn assert_close(values: &[f64], expected: &[f64]) {
+        assert_eq!(values.len(), expected.len());
+
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < EPSILON,
+                "{values:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn path() {
+        let graph = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 3)]);
+
+        let exact = betweenness(&graph, false, Sources::All);
+
+        assert_close(&exact.nodes, &[0.0, 2.0, 2.0, 0.0]);
+        assert_close(&exact.edges, &[3.0, 4.0, 3.0]);
+
+        let sampled = betweenness(&graph, false, Sources::Sample { count: 4, seed: 13 });
+
+        assert_close(&sampled.nodes, &exact.nodes);
+        assert_close(&sampled.edges, &exact.edges);
+    }
+
+    #[test]
+    fn parallel() {
+        let graph = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 1), (2, 2)]);
+
+        let betweenness = betweenness(&graph, false, Sources::All);
+
+        // shortest paths through the parallel edges are counted twice,
+        // both between 0 and 2 and between 1 and 3
+        assert_close(
+            &betweenness.nodes,
+            &[2.0 / 3.0, 2.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0],
+        );
+
+        assert_eq!(betweenness.edge_of(5), Some(0.0));
+    }
+
+    #[test]
+    fn directed() {
+        let graph = DiGraph::weighted(3, [(0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)]);
+
+        let unweighted = betweenness(&graph, false, Sources::All);
+        let weighted = betweenness_by(&graph, false, Sources::All, |&length| length);
+
+        assert_close(&unweighted.nodes, &[0.0, 0.0, 0.0]);
+        assert_close(&weighted.nodes, &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn proximity() {
+        let graph = UnGraph::unweighted(3, &[(0, 1), (1, 2)]);
+
+        assert_close(&closeness(&graph), &[2.0 / 3.0, 1.0, 2.0 / 3.0]);
+        assert_close(&harmonic(&graph), &[1.5, 2.0, 1.5]);
+
+        let graph = DiGraph::weighted(3, [(0, 1, 2.0)]);
+
+        // only half of the other nodes are reached from the first one
+        assert_close(&closeness_by(&graph, |&length| length), &[0.25, 0.0, 0.0]);
+        assert_close(&harmonic_by(&graph, |&length| length), &[0.5, 0.0, 0.0]);
+    }
+}
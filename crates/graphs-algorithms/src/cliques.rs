@@ -0,0 +1,474 @@
+//! Maximal and maximum cliques of undirected graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_bits::BitSet;
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    control::Flow,
+    control_flow,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    neighbors::Neighbors,
+};
+
+use crate::coloring::Adjacency;
+
+/// Represents cliques as lists of nodes.
+pub type Clique<N> = Vec<N>;
+
+/// Represents cliques of the given graph.
+pub type CliqueOf<G> = Clique<<G as Base>::NodeId>;
+
+/// Intersects two sorted lists of positions.
+fn intersect(one: &[usize], two: &[usize]) -> Vec<usize> {
+    let mut result = Vec::new();
+
+    let (mut i, mut j) = (0, 0);
+
+    while let (Some(&a), Some(&b)) = (one.get(i), two.get(j)) {
+        if a < b {
+            i += 1;
+        } else if a > b {
+            j += 1;
+        } else {
+            result.push(a);
+
+            i += 1;
+            j += 1;
+        }
+    }
+
+    result
+}
+
+/// Counts positions common to two sorted lists.
+fn common(one: &[usize], two: &[usize]) -> usize {
+    let mut count = 0;
+
+    let (mut i, mut j) = (0, 0);
+
+    while let (Some(&a), Some(&b)) = (one.get(i), two.get(j)) {
+        if a < b {
+            i += 1;
+        } else if a > b {
+            j += 1;
+        } else {
+            count += 1;
+
+            i += 1;
+            j += 1;
+        }
+    }
+
+    count
+}
+
+/// Represents frames of the Bron–Kerbosch search.
+struct Expansion {
+    /// The sorted positions that can extend the current clique.
+    candidates: Vec<usize>,
+
+    /// The sorted positions that were already used to extend the current clique.
+    excluded: Vec<usize>,
+
+    /// The candidates not adjacent to the pivot, which are yet to be branched on.
+    moves: Vec<usize>,
+}
+
+impl Expansion {
+    /// Constructs [`Self`], picking the pivot with the most neighbors among candidates.
+    fn new(adjacency: &Adjacency, candidates: Vec<usize>, excluded: Vec<usize>) -> Self {
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .copied()
+            .max_by_key(|&node| common(&candidates, &adjacency.neighbors[node]));
+
+        let moves = pivot.map_or_else(Vec::new, |pivot| {
+            let around = &adjacency.neighbors[pivot];
+
+            candidates
+                .iter()
+                .copied()
+                .filter(|node| around.binary_search(node).is_err())
+                .collect()
+        });
+
+        Self {
+            candidates,
+            excluded,
+            moves,
+        }
+    }
+}
+
+/// Enumerates maximal cliques of the given graph, calling `visitor` with each of them.
+///
+/// This is the Bron–Kerbosch algorithm with Tomita pivoting, where the outermost level
+/// visits nodes in the degeneracy order, which takes `O(d V 3^(d / 3))` time
+/// for graphs of degeneracy `d`. Cliques are found one by one, without storing them,
+/// and are reported in no particular order. Loops and parallel edges are ignored,
+/// and isolated nodes are reported as cliques of one node.
+///
+/// Breaking stops the enumeration and returns the value given, while pruning
+/// is the same as continuing, as every clique reported is maximal.
+pub fn maximal_cliques<G, V, F>(graph: G, mut visitor: V) -> F
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+    V: FnMut(&[G::NodeId]) -> F,
+    F: Flow,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    // positions are assigned in the order of node identifiers
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+
+    // the order in which nodes are removed by smallest-last ordering
+    let mut order = adjacency.smallest_last();
+
+    order.reverse();
+
+    let mut ranks = vec![0; adjacency.count()];
+
+    for (rank, &position) in order.iter().enumerate() {
+        ranks[position] = rank;
+    }
+
+    let mut clique = Vec::new();
+    let mut found = Vec::new();
+
+    let mut stack = Vec::new();
+
+    for &start in &order {
+        let around = &adjacency.neighbors[start];
+
+        // every node has at most degeneracy neighbors later in the order
+        let candidates = around
+            .iter()
+            .copied()
+            .filter(|&node| ranks[node] > ranks[start])
+            .collect();
+
+        let excluded = around
+            .iter()
+            .copied()
+            .filter(|&node| ranks[node] < ranks[start])
+            .collect();
+
+        clique.push(start);
+
+        stack.push(Expansion::new(&adjacency, candidates, excluded));
+
+        while let Some(expansion) = stack.last_mut() {
+            if expansion.candidates.is_empty() {
+                if expansion.excluded.is_empty() {
+                    found.clear();
+                    found.extend(clique.iter().map(|&position| nodes[position]));
+
+                    control_flow!(visitor(&found), {
+                        continue => {},
+                        prune => {},
+                    });
+                }
+
+                stack.pop();
+                clique.pop();
+
+                continue;
+            }
+
+            let Some(node) = expansion.moves.pop() else {
+                stack.pop();
+                clique.pop();
+
+                continue;
+            };
+
+            let around = &adjacency.neighbors[node];
+
+            let candidates = intersect(&expansion.candidates, around);
+            let excluded = intersect(&expansion.excluded, around);
+
+            // move the node from candidates to excluded ones
+            if let Ok(at) = expansion.candidates.binary_search(&node) {
+                expansion.candidates.remove(at);
+            }
+
+            if let Err(at) = expansion.excluded.binary_search(&node) {
+                expansion.excluded.insert(at, node);
+            }
+
+            clique.push(node);
+
+            stack.push(Expansion::new(&adjacency, candidates, excluded));
+        }
+    }
+
+    F::continuing()
+}
+
+/// Represents graphs with nodes renumbered into ranks and neighbors stored as bit sets.
+struct Bits {
+    neighbors: Vec<BitSet>,
+}
+
+impl Bits {
+    fn new(adjacency: &Adjacency, order: &[usize]) -> Self {
+        let count = adjacency.count();
+
+        let mut ranks = vec![0; count];
+
+        for (rank, &position) in order.iter().enumerate() {
+            ranks[position] = rank;
+        }
+
+        let neighbors = order
+            .iter()
+            .map(|&position| {
+                let mut set = BitSet::with_capacity(count);
+
+                set.extend(
+                    adjacency.neighbors[position]
+                        .iter()
+                        .map(|&other| ranks[other]),
+                );
+
+                set
+            })
+            .collect();
+
+        Self { neighbors }
+    }
+
+    /// Colors the given candidates greedily, returning them along with their colors,
+    /// in the order of increasing colors.
+    ///
+    /// Colors are numbered from one, so that every color bounds the size of cliques
+    /// among candidates up to and including the node.
+    fn color(&self, candidates: &BitSet) -> Vec<(usize, usize)> {
+        let mut uncolored = candidates.clone();
+        let mut available = BitSet::new();
+
+        let mut colored = Vec::with_capacity(candidates.len());
+
+        let mut color = 0;
+
+        while !uncolored.is_empty() {
+            color += 1;
+
+            available.clone_from(&uncolored);
+
+            while let Some(node) = available.iter().next() {
+                available.remove(node);
+                available.difference_with(&self.neighbors[node]);
+
+                uncolored.remove(node);
+
+                colored.push((node, color));
+            }
+        }
+
+        colored
+    }
+}
+
+/// Represents frames of the branch and bound search.
+struct Branch {
+    /// The ranks that can extend the current clique.
+    candidates: BitSet,
+
+    /// The candidates with their colors, in the order of increasing colors.
+    colored: Vec<(usize, usize)>,
+}
+
+/// Finds the maximum clique of the given graph, that is, the clique with the most nodes.
+///
+/// This is the branch and bound algorithm (Tomita's MCQ) that keeps candidate sets
+/// in bit sets and bounds the search using greedy colorings of candidates,
+/// starting with nodes in the smallest-last order. It runs in exponential time,
+/// so it is only suitable for small or sparse graphs. Loops and parallel edges are ignored.
+///
+/// The clique returned is empty if and only if the graph has no nodes.
+pub fn max_clique<G>(graph: G) -> CliqueOf<G>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    // positions are assigned in the order of node identifiers
+    let nodes: Vec<_> = graph.node_identifiers().collect();
+
+    let order = adjacency.smallest_last();
+
+    let bits = Bits::new(&adjacency, &order);
+
+    let mut best = Vec::new();
+    let mut clique = Vec::new();
+
+    let candidates: BitSet = (0..adjacency.count()).collect();
+
+    let colored = bits.color(&candidates);
+
+    let mut stack = vec![Branch {
+        candidates,
+        colored,
+    }];
+
+    while let Some(branch) = stack.last_mut() {
+        // candidates are visited from the largest colors, so the bound only decreases
+        let Some((node, _)) = branch
+            .colored
+            .pop()
+            .filter(|&(_, color)| clique.len() + color > best.len())
+        else {
+            stack.pop();
+            clique.pop();
+
+            continue;
+        };
+
+        let mut candidates = branch.candidates.clone();
+
+        candidates.intersect_with(&bits.neighbors[node]);
+
+        branch.candidates.remove(node);
+
+        clique.push(node);
+
+        if candidates.is_empty() {
+            if clique.len() > best.len() {
+                best.clone_from(&clique);
+            }
+
+            clique.pop();
+        } else {
+            let colored = bits.color(&candidates);
+
+            stack.push(Branch {
+                candidates,
+                colored,
+            });
+        }
+    }
+
+    best.into_iter().map(|rank| nodes[order[rank]]).collect()
+}
+
+/// Represents the maximal clique enumeration algorithm, collecting every clique found.
+///
+/// See [`maximal_cliques`] for more information.
+pub struct MaximalCliques;
+
+impl<G> Algorithm<G> for MaximalCliques
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Vec<CliqueOf<G>>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        let mut cliques = Vec::new();
+
+        maximal_cliques::<_, _, Option<()>>(graph, |clique| {
+            cliques.push(clique.to_vec());
+
+            None
+        });
+
+        cliques
+    }
+}
+
+/// Represents the maximum clique algorithm.
+///
+/// See [`max_clique`] for more information.
+pub struct MaxClique;
+
+impl<G> Algorithm<G> for MaxClique
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = CliqueOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        max_clique(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{Node, UnGraph};
+
+    fn graph() -> UnGraph {
+        UnGraph::unweighted(
+            8,
+            &[
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 3),
+                (5, 6),
+                (1, 0),
+                (7, 7),
+            ],
+        )
+    }
+
+    fn sorted(clique: &[Node]) -> Vec<usize> {
+        let mut clique: Vec<_> = clique.iter().map(|node| node.get()).collect();
+
+        clique.sort_unstable();
+
+        clique
+    }
+
+    #[test]
+    fn maximal() {
+        let mut cliques = Vec::new();
+
+        maximal_cliques::<_, _, Option<()>>(&graph(), |clique| {
+            cliques.push(sorted(clique));
+
+            None
+        });
+
+        cliques.sort_unstable();
+
+        assert_eq!(
+            cliques,
+            [vec![0, 1, 2, 3], vec![3, 4, 5], vec![5, 6], vec![7]]
+        );
+    }
+
+    #[test]
+    fn stop() {
+        let graph = graph();
+
+        let mut found = 0;
+
+        let size = maximal_cliques(&graph, |clique| {
+            found += 1;
+
+            Some(clique.len())
+        });
+
+        assert_eq!(found, 1);
+        assert!(size.is_some());
+    }
+
+    #[test]
+    fn maximum() {
+        assert_eq!(sorted(&max_clique(&graph())), [0, 1, 2, 3]);
+
+        assert!(max_clique(&UnGraph::unweighted(0, &[])).is_empty());
+    }
+}
@@ -0,0 +1,675 @@
+//! Vertex colorings of undirected graphs.
+
+use core::cmp::Reverse;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+use graphs_core::{
+    algorithm::Algorithm, base::Undirected, identifiers::NodeIdentifiers, indexed::NodeIndexed,
+    limit::Limited, neighbors::Neighbors,
+};
+
+use crate::compact::compact;
+
+/// Represents colorings, that is, assignments of colors to nodes
+/// such that adjacent nodes have different colors.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Coloring {
+    /// The color of every node, indexed by node indices.
+    ///
+    /// Colors are numbered from zero; node indices not present in the graph
+    /// have [`usize::LIMIT`] colors.
+    pub colors: Vec<usize>,
+
+    /// The number of colors used.
+    pub count: usize,
+}
+
+impl Coloring {
+    /// Constructs [`Self`].
+    pub const fn new(colors: Vec<usize>, count: usize) -> Self {
+        Self { colors, count }
+    }
+
+    /// Returns the color of the node with the given index, if it is present.
+    pub fn color_of(&self, index: usize) -> Option<usize> {
+        self.colors
+            .get(index)
+            .copied()
+            .filter(|color| !color.is_limit())
+    }
+}
+
+/// Represents orders in which greedy coloring colors nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Order {
+    /// The order of node identifiers.
+    #[default]
+    Identifiers,
+
+    /// Nodes of larger degrees first.
+    LargestFirst,
+
+    /// The reverse of the order in which nodes of the smallest degrees are removed,
+    /// which uses at most one more color than the degeneracy of the graph.
+    SmallestLast,
+}
+
+/// Represents graphs with nodes referred to by their positions and neighbors
+/// stored per node, without duplicates and loops.
+pub(crate) struct Adjacency {
+    pub(crate) positions: Vec<usize>,
+    pub(crate) neighbors: Vec<Vec<usize>>,
+}
+
+impl Adjacency {
+    pub(crate) fn new<G>(graph: &G) -> Self
+    where
+        G: NodeIdentifiers + NodeIndexed + Neighbors,
+    {
+        let (nodes, positions) = compact(graph);
+
+        let neighbors = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, &node)| {
+                let mut list: Vec<usize> = graph
+                    .neighbors(node)
+                    .map(|other| positions[graph.node_index(other)])
+                    .filter(|&other| other != position)
+                    .collect();
+
+                list.sort_unstable();
+                list.dedup();
+
+                list
+            })
+            .collect();
+
+        Self {
+            positions,
+            neighbors,
+        }
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    pub(crate) fn degree(&self, position: usize) -> usize {
+        self.neighbors[position].len()
+    }
+
+    /// Converts colors of positions into [`Coloring`].
+    fn finish(&self, colors: &[usize]) -> Coloring {
+        let mut result = vec![usize::LIMIT; self.positions.len()];
+
+        for (index, &position) in self.positions.iter().enumerate() {
+            if !position.is_limit() {
+                result[index] = colors[position];
+            }
+        }
+
+        let count = colors.iter().map(|&color| color + 1).max().unwrap_or(0);
+
+        Coloring::new(result, count)
+    }
+
+    /// Colors positions in the given order, each with the least color not used by its neighbors.
+    fn greedy(&self, order: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let count = self.count();
+
+        let mut colors = vec![usize::LIMIT; count];
+
+        // the position that last marked every color as used
+        let mut marks = vec![usize::LIMIT; count + 1];
+
+        for position in order {
+            for &other in &self.neighbors[position] {
+                let color = colors[other];
+
+                if !color.is_limit() {
+                    marks[color] = position;
+                }
+            }
+
+            let mut color = 0;
+
+            while marks[color] == position {
+                color += 1;
+            }
+
+            colors[position] = color;
+        }
+
+        colors
+    }
+
+    /// Returns the smallest-last order, via bucket queues of remaining degrees.
+    pub(crate) fn smallest_last(&self) -> Vec<usize> {
+        let count = self.count();
+
+        let mut degrees: Vec<usize> = (0..count).map(|position| self.degree(position)).collect();
+
+        let top = degrees.iter().copied().max().unwrap_or(0);
+
+        let mut buckets = vec![Vec::new(); top + 1];
+
+        for (position, &degree) in degrees.iter().enumerate() {
+            buckets[degree].push(position);
+        }
+
+        let mut removed = vec![false; count];
+
+        let mut order = Vec::with_capacity(count);
+
+        let mut lowest: usize = 0;
+
+        while order.len() < count {
+            // removing nodes decreases degrees by one, so the lowest bucket moves back by one at most
+            lowest = lowest.saturating_sub(1);
+
+            let position = loop {
+                match buckets[lowest].pop() {
+                    Some(position) if !removed[position] && degrees[position] == lowest => {
+                        break position;
+                    }
+                    Some(_) => {}
+                    None => lowest += 1,
+                }
+            };
+
+            removed[position] = true;
+
+            order.push(position);
+
+            for &other in &self.neighbors[position] {
+                if !removed[other] {
+                    degrees[other] -= 1;
+
+                    buckets[degrees[other]].push(other);
+                }
+            }
+        }
+
+        order.reverse();
+
+        order
+    }
+
+    /// Colors positions using DSatur, always picking the node with the most distinct colors
+    /// among its neighbors, preferring nodes of larger degrees.
+    fn dsatur(&self) -> Vec<usize> {
+        let count = self.count();
+
+        let mut colors = vec![usize::LIMIT; count];
+
+        // the sorted distinct colors of neighbors of every node
+        let mut seen = vec![Vec::new(); count];
+
+        let mut heap: BinaryHeap<_> = (0..count)
+            .map(|position| (0, self.degree(position), Reverse(position)))
+            .collect();
+
+        while let Some((saturation, _, Reverse(position))) = heap.pop() {
+            // skip outdated entries
+            if !colors[position].is_limit() || saturation != seen[position].len() {
+                continue;
+            }
+
+            let color = seen[position]
+                .iter()
+                .enumerate()
+                .find(|&(expected, &color)| expected != color)
+                .map_or(seen[position].len(), |(expected, _)| expected);
+
+            colors[position] = color;
+
+            for &other in &self.neighbors[position] {
+                if !colors[other].is_limit() {
+                    continue;
+                }
+
+                if let Err(at) = seen[other].binary_search(&color) {
+                    seen[other].insert(at, color);
+
+                    heap.push((seen[other].len(), self.degree(other), Reverse(other)));
+                }
+            }
+        }
+
+        colors
+    }
+
+    /// Finds some large clique greedily, giving the lower bound on the number of colors.
+    fn clique(&self) -> usize {
+        let mut best = usize::from(self.count() > 0);
+
+        let mut clique = Vec::new();
+        let mut candidates = Vec::new();
+
+        for position in 0..self.count() {
+            if self.degree(position) < best {
+                continue;
+            }
+
+            clique.clear();
+            clique.push(position);
+
+            candidates.clear();
+            candidates.extend(self.neighbors[position].iter().copied());
+            candidates.sort_by_key(|&other| Reverse(self.degree(other)));
+
+            for &candidate in &candidates {
+                if clique
+                    .iter()
+                    .all(|member| self.neighbors[candidate].binary_search(member).is_ok())
+                {
+                    clique.push(candidate);
+                }
+            }
+
+            best = best.max(clique.len());
+        }
+
+        best
+    }
+
+    /// Finds the optimal coloring via branch and bound, starting from the DSatur coloring.
+    ///
+    /// Nodes are picked in the DSatur order, and every node tries the colors used so far along
+    /// with one new color, as long as the total stays below the best number of colors found.
+    fn exact(&self) -> Vec<usize> {
+        let count = self.count();
+
+        let mut best = self.dsatur();
+
+        let mut upper = best.iter().map(|&color| color + 1).max().unwrap_or(0);
+
+        let lower = self.clique();
+
+        if upper <= lower {
+            return best;
+        }
+
+        let width = upper;
+
+        let mut state = State {
+            colors: vec![usize::LIMIT; count],
+            counts: vec![0; count * width],
+            saturations: vec![0; count],
+            width,
+        };
+
+        let mut stack: Vec<Frame> = Vec::new();
+
+        let mut used = 0;
+
+        let mut descend = true;
+
+        loop {
+            if descend {
+                if stack.len() == count {
+                    // found some better coloring, so look for the one using fewer colors
+                    best.copy_from_slice(&state.colors);
+
+                    upper = used;
+
+                    if upper <= lower {
+                        break;
+                    }
+                } else {
+                    let node = (0..count)
+                        .filter(|&position| state.colors[position].is_limit())
+                        .max_by_key(|&position| {
+                            (state.saturations[position], self.degree(position))
+                        })
+                        .expect("expected uncolored node");
+
+                    stack.push(Frame {
+                        node,
+                        color: usize::LIMIT,
+                        used,
+                    });
+                }
+            }
+
+            let Some(frame) = stack.last_mut() else {
+                break;
+            };
+
+            let start = if frame.color.is_limit() {
+                0
+            } else {
+                state.uncolor(self, frame.node, frame.color);
+
+                used = frame.used;
+
+                frame.color + 1
+            };
+
+            // new colors are introduced in order, and the total must stay below the best one
+            let end = (used + 1).min(upper - 1);
+
+            let next = (start..end).find(|&color| state.counts[frame.node * width + color] == 0);
+
+            if let Some(color) = next {
+                state.color(self, frame.node, color);
+
+                frame.color = color;
+
+                used = used.max(color + 1);
+
+                descend = true;
+            } else {
+                stack.pop();
+
+                descend = false;
+            }
+        }
+
+        best
+    }
+}
+
+/// Represents the state of the branch and bound search.
+struct State {
+    colors: Vec<usize>,
+    counts: Vec<usize>,
+    saturations: Vec<usize>,
+    width: usize,
+}
+
+impl State {
+    fn color(&mut self, adjacency: &Adjacency, node: usize, color: usize) {
+        self.colors[node] = color;
+
+        for &other in &adjacency.neighbors[node] {
+            let entry = &mut self.counts[other * self.width + color];
+
+            if *entry == 0 {
+                self.saturations[other] += 1;
+            }
+
+            *entry += 1;
+        }
+    }
+
+    fn uncolor(&mut self, adjacency: &Adjacency, node: usize, color: usize) {
+        self.colors[node] = usize::LIMIT;
+
+        for &other in &adjacency.neighbors[node] {
+            let entry = &mut self.counts[other * self.width + color];
+
+            *entry -= 1;
+
+            if *entry == 0 {
+                self.saturations[other] -= 1;
+            }
+        }
+    }
+}
+
+/// Represents frames of the branch and bound search.
+struct Frame {
+    node: usize,
+    color: usize,
+    used: usize,
+}
+
+/// Colors the given graph greedily, visiting nodes in the given `order`.
+///
+/// Every node gets the least color not used by its neighbors, which takes `O(V + E)` time
+/// after ordering; see [`Order`] for the available orders. Loops are ignored.
+pub fn greedy_coloring<G>(graph: G, order: Order) -> Coloring
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    let count = adjacency.count();
+
+    let colors = match order {
+        Order::Identifiers => adjacency.greedy(0..count),
+        Order::LargestFirst => {
+            let mut positions: Vec<usize> = (0..count).collect();
+
+            positions.sort_by_key(|&position| Reverse(adjacency.degree(position)));
+
+            adjacency.greedy(positions)
+        }
+        Order::SmallestLast => adjacency.greedy(adjacency.smallest_last()),
+    };
+
+    adjacency.finish(&colors)
+}
+
+/// Colors the given graph greedily, visiting the given `nodes` in order.
+///
+/// Nodes not yielded are visited afterwards, in the order of node identifiers,
+/// and nodes yielded more than once are only visited the first time.
+///
+/// See [`greedy_coloring`] for more information.
+pub fn greedy_coloring_with<G, I>(graph: G, nodes: I) -> Coloring
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    let count = adjacency.count();
+
+    let mut visited = vec![false; count];
+
+    let mut order = Vec::with_capacity(count);
+
+    for node in nodes {
+        let position = adjacency.positions[graph.node_index(node)];
+
+        if !visited[position] {
+            visited[position] = true;
+
+            order.push(position);
+        }
+    }
+
+    order.extend((0..count).filter(|&position| !visited[position]));
+
+    let colors = adjacency.greedy(order);
+
+    adjacency.finish(&colors)
+}
+
+/// Colors the given graph using the DSatur heuristic.
+///
+/// Every step colors the node with the most distinct colors among its neighbors (its saturation),
+/// breaking ties by degrees, with the least color not used by its neighbors.
+/// This function runs in `O((V + E) log V)` time. Loops are ignored.
+pub fn dsatur<G>(graph: G) -> Coloring
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    let colors = adjacency.dsatur();
+
+    adjacency.finish(&colors)
+}
+
+/// Finds the coloring of the given graph with the least number of colors,
+/// which is the chromatic number of the graph.
+///
+/// This is the exact branch and bound algorithm based on DSatur, which starts from
+/// the DSatur coloring and stops early once it matches the size of some clique found greedily.
+/// It runs in exponential time, so it is only suitable for small graphs. Loops are ignored.
+pub fn chromatic_coloring<G>(graph: G) -> Coloring
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let adjacency = Adjacency::new(&graph);
+
+    let colors = adjacency.exact();
+
+    adjacency.finish(&colors)
+}
+
+/// Represents greedy coloring algorithms.
+///
+/// See [`greedy_coloring`] for more information.
+pub struct Greedy {
+    order: Order,
+}
+
+impl Greedy {
+    /// Constructs [`Self`] with the given `order`.
+    pub const fn new(order: Order) -> Self {
+        Self { order }
+    }
+}
+
+impl<G> Algorithm<G> for Greedy
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Coloring;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        greedy_coloring(graph, self.order)
+    }
+}
+
+/// Represents the DSatur coloring algorithm.
+///
+/// See [`dsatur`] for more information.
+pub struct DSatur;
+
+impl<G> Algorithm<G> for DSatur
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Coloring;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        dsatur(graph)
+    }
+}
+
+/// Represents the exact branch and bound coloring algorithm.
+///
+/// See [`chromatic_coloring`] for more information.
+pub struct Chromatic;
+
+impl<G> Algorithm<G> for Chromatic
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Coloring;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        chromatic_coloring(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::UnGraph;
+
+    /// Constructs crown graphs, that is, complete bipartite graphs without perfect matchings,
+    /// where left nodes are even and right nodes are odd.
+    fn crown(size: usize) -> UnGraph {
+        let edges: Vec<_> = (0..size)
+            .flat_map(|left| {
+                (0..size)
+                    .filter(move |&right| right != left)
+                    .map(move |right| (2 * left, 2 * right + 1))
+            })
+            .collect();
+
+        UnGraph::unweighted(2 * size, &edges)
+    }
+
+    /// Constructs wheel graphs, that is, cycles with every node connected to the hub.
+    fn wheel(size: usize) -> UnGraph {
+        let edges: Vec<_> = (0..size)
+            .flat_map(|node| [(node, (node + 1) % size), (node, size)])
+            .collect();
+
+        UnGraph::unweighted(size + 1, &edges)
+    }
+
+    fn check(graph: &UnGraph, coloring: &Coloring) {
+        for node in graph.node_identifiers() {
+            let color = coloring.color_of(node.get()).unwrap();
+
+            assert!(color < coloring.count);
+
+            for other in graph.neighbors(node).filter(|&other| other != node) {
+                assert_ne!(coloring.color_of(other.get()), Some(color));
+            }
+        }
+    }
+
+    #[test]
+    fn greedy() {
+        let graph = crown(4);
+
+        let coloring = greedy_coloring(&graph, Order::Identifiers);
+
+        check(&graph, &coloring);
+
+        // the worst case of greedy coloring
+        assert_eq!(coloring.count, 4);
+
+        for order in [Order::LargestFirst, Order::SmallestLast] {
+            check(&graph, &greedy_coloring(&graph, order));
+        }
+
+        let coloring = greedy_coloring_with(&graph, (0..4).map(|left| NodeId::new(2 * left)));
+
+        check(&graph, &coloring);
+
+        assert_eq!(coloring.count, 2);
+    }
+
+    #[test]
+    fn saturation() {
+        let graph = crown(4);
+
+        let coloring = dsatur(&graph);
+
+        check(&graph, &coloring);
+
+        // bipartite graphs are always colored optimally
+        assert_eq!(coloring.count, 2);
+    }
+
+    #[test]
+    fn chromatic() {
+        for (size, count) in [(4, 3), (5, 4), (6, 3)] {
+            let graph = wheel(size);
+
+            let coloring = chromatic_coloring(&graph);
+
+            check(&graph, &coloring);
+
+            assert_eq!(coloring.count, count);
+        }
+
+        let graph = UnGraph::unweighted(3, &[(0, 0), (1, 2)]);
+
+        let coloring = chromatic_coloring(&graph);
+
+        check(&graph, &coloring);
+
+        assert_eq!(coloring.count, 2);
+        assert_eq!(coloring.color_of(3), None);
+    }
+}
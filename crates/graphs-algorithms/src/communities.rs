@@ -0,0 +1,1164 @@
+//! Community detection in undirected graphs, via modularity optimization and label propagation.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Undirected,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+
+use crate::{EDGE, compact::compact_with, random::Random};
+
+/// Represents partitions of nodes into communities.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Communities {
+    /// The community of every node, indexed by node indices.
+    ///
+    /// Communities are numbered from zero; node indices not present in the graph
+    /// have [`usize::LIMIT`] communities.
+    pub communities: Vec<usize>,
+
+    /// The number of communities.
+    pub count: usize,
+
+    /// The modularity of the partition.
+    pub modularity: f64,
+}
+
+impl Communities {
+    /// Constructs [`Self`].
+    pub const fn new(communities: Vec<usize>, count: usize, modularity: f64) -> Self {
+        Self {
+            communities,
+            count,
+            modularity,
+        }
+    }
+
+    /// Returns the community of the node with the given index, if it is present.
+    pub fn community_of(&self, index: usize) -> Option<usize> {
+        self.communities
+            .get(index)
+            .copied()
+            .filter(|community| !community.is_limit())
+    }
+}
+
+/// Represents options of modularity optimization.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Options {
+    /// The resolution, where larger values favor smaller communities.
+    pub resolution: f64,
+
+    /// The minimal modularity gain required to move nodes between communities.
+    pub tolerance: f64,
+
+    /// The seed of the random number generator, which determines the order of nodes.
+    pub seed: u64,
+}
+
+impl Options {
+    /// The default resolution, which gives the standard modularity.
+    pub const RESOLUTION: f64 = 1.0;
+
+    /// The default minimal modularity gain.
+    pub const TOLERANCE: f64 = 1e-7;
+
+    /// Constructs [`Self`].
+    pub const fn new(resolution: f64, tolerance: f64, seed: u64) -> Self {
+        Self {
+            resolution,
+            tolerance,
+            seed,
+        }
+    }
+
+    /// Constructs [`Self`] with the default resolution and tolerance, and the given `seed`.
+    pub const fn seeded(seed: u64) -> Self {
+        Self::new(Self::RESOLUTION, Self::TOLERANCE, seed)
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::seeded(0)
+    }
+}
+
+/// Represents weighted networks, with nodes referred to by their positions.
+///
+/// Self-loops are stored separately and count twice towards degrees.
+struct Network {
+    starts: Vec<usize>,
+    arcs: Vec<(usize, f64)>,
+    loops: Vec<f64>,
+    degrees: Vec<f64>,
+    total: f64,
+}
+
+impl Network {
+    fn new(count: usize, edges: &[(usize, usize, f64)]) -> Self {
+        let mut starts = vec![0; count + 1];
+
+        let mut loops = vec![0.0; count];
+        let mut degrees = vec![0.0; count];
+
+        let mut total = 0.0;
+
+        for &(one, two, weight) in edges {
+            total += weight;
+
+            degrees[one] += weight;
+            degrees[two] += weight;
+
+            if one == two {
+                loops[one] += weight;
+            } else {
+                starts[one + 1] += 1;
+                starts[two + 1] += 1;
+            }
+        }
+
+        for node in 0..count {
+            starts[node + 1] += starts[node];
+        }
+
+        let mut next = starts.clone();
+        let mut arcs = vec![(0, 0.0); starts[count]];
+
+        for &(one, two, weight) in edges {
+            if one != two {
+                arcs[next[one]] = (two, weight);
+                next[one] += 1;
+
+                arcs[next[two]] = (one, weight);
+                next[two] += 1;
+            }
+        }
+
+        Self {
+            starts,
+            arcs,
+            loops,
+            degrees,
+            total,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.degrees.len()
+    }
+
+    fn arcs(&self, node: usize) -> &[(usize, f64)] {
+        &self.arcs[self.starts[node]..self.starts[node + 1]]
+    }
+
+    /// Collapses every part of the given partition into the single node,
+    /// with edges inside of parts becoming self-loops.
+    fn aggregate(&self, parts: &[usize], count: usize) -> Self {
+        let mut edges = Vec::new();
+
+        for node in 0..self.count() {
+            let part = parts[node];
+
+            if self.loops[node] > 0.0 {
+                edges.push((part, part, self.loops[node]));
+            }
+
+            for &(other, weight) in self.arcs(node) {
+                if node < other {
+                    let other = parts[other];
+
+                    edges.push((part.min(other), part.max(other), weight));
+                }
+            }
+        }
+
+        edges.sort_unstable_by_key(|&(one, two, _)| (one, two));
+
+        // merge parallel edges
+        let mut merged: Vec<(usize, usize, f64)> = Vec::new();
+
+        for (one, two, weight) in edges {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (one, two) => last.2 += weight,
+                _ => merged.push((one, two, weight)),
+            }
+        }
+
+        Self::new(count, &merged)
+    }
+
+    /// Computes the modularity of the given partition.
+    fn modularity(&self, communities: &[usize], count: usize, resolution: f64) -> f64 {
+        if self.total <= 0.0 {
+            return 0.0;
+        }
+
+        let mut internal = vec![0.0; count];
+        let mut degrees = vec![0.0; count];
+
+        for node in 0..self.count() {
+            let community = communities[node];
+
+            internal[community] += self.loops[node];
+            degrees[community] += self.degrees[node];
+
+            for &(other, weight) in self.arcs(node) {
+                // every edge is seen from both of its endpoints
+                if communities[other] == community {
+                    internal[community] += weight / 2.0;
+                }
+            }
+        }
+
+        let scale = 2.0 * self.total;
+
+        internal
+            .iter()
+            .zip(&degrees)
+            .map(|(&inside, &degree)| {
+                inside / self.total - resolution * (degree / scale) * (degree / scale)
+            })
+            .sum()
+    }
+}
+
+/// Renumbers the given communities from zero, in the order of their first appearance,
+/// returning their number.
+fn renumber(communities: &mut [usize]) -> usize {
+    let mut numbers = vec![usize::LIMIT; communities.len()];
+
+    let mut count = 0;
+
+    for community in communities.iter_mut() {
+        if numbers[*community].is_limit() {
+            numbers[*community] = count;
+
+            count += 1;
+        }
+
+        *community = numbers[*community];
+    }
+
+    count
+}
+
+/// Represents sums of weights from single nodes to communities.
+struct Links {
+    weights: Vec<f64>,
+    touched: Vec<usize>,
+}
+
+impl Links {
+    fn new(count: usize) -> Self {
+        Self {
+            weights: vec![f64::NAN; count],
+            touched: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, community: usize, weight: f64) {
+        if self.weights[community].is_nan() {
+            self.weights[community] = 0.0;
+
+            self.touched.push(community);
+        }
+
+        self.weights[community] += weight;
+    }
+
+    fn weight(&self, community: usize) -> f64 {
+        let weight = self.weights[community];
+
+        if weight.is_nan() { 0.0 } else { weight }
+    }
+
+    fn clear(&mut self) {
+        for &community in &self.touched {
+            self.weights[community] = f64::NAN;
+        }
+
+        self.touched.clear();
+    }
+}
+
+/// Repeatedly moves nodes to the neighboring communities that increase modularity the most,
+/// until no such moves remain.
+fn move_nodes(network: &Network, communities: &mut [usize], options: Options, random: &mut Random) {
+    let count = network.count();
+
+    let scale = options.resolution / (2.0 * network.total);
+    let threshold = options.tolerance * network.total;
+
+    let mut totals = vec![0.0; count];
+
+    for node in 0..count {
+        totals[communities[node]] += network.degrees[node];
+    }
+
+    let mut order: Vec<usize> = (0..count).collect();
+
+    random.shuffle(&mut order);
+
+    let mut links = Links::new(count);
+
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for &node in &order {
+            let current = communities[node];
+            let degree = network.degrees[node];
+
+            for &(other, weight) in network.arcs(node) {
+                links.add(communities[other], weight);
+            }
+
+            // take the node out of its community first
+            totals[current] -= degree;
+
+            let stay = links.weight(current) - totals[current] * degree * scale;
+
+            let mut best = current;
+            let mut best_gain = stay;
+
+            for &community in &links.touched {
+                let gain = links.weight(community) - totals[community] * degree * scale;
+
+                if gain > best_gain {
+                    best = community;
+                    best_gain = gain;
+                }
+            }
+
+            if best_gain - stay <= threshold {
+                best = current;
+            }
+
+            totals[best] += degree;
+
+            if best != current {
+                communities[node] = best;
+
+                changed = true;
+            }
+
+            links.clear();
+        }
+    }
+}
+
+/// Splits the given communities into their connected components, returning their number.
+///
+/// This never decreases modularity, as there are no edges between components.
+fn split(network: &Network, communities: &mut [usize]) -> usize {
+    let count = network.count();
+
+    let mut components = vec![usize::LIMIT; count];
+    let mut stack = Vec::new();
+
+    let mut total = 0;
+
+    for root in 0..count {
+        if !components[root].is_limit() {
+            continue;
+        }
+
+        components[root] = total;
+
+        stack.push(root);
+
+        while let Some(node) = stack.pop() {
+            for &(other, _) in network.arcs(node) {
+                if components[other].is_limit() && communities[other] == communities[root] {
+                    components[other] = total;
+
+                    stack.push(other);
+                }
+            }
+        }
+
+        total += 1;
+    }
+
+    communities.copy_from_slice(&components);
+
+    total
+}
+
+/// Refines the given communities, merging nodes within each community into well-connected
+/// subcommunities, which are connected, as nodes only merge into subcommunities they are linked to.
+///
+/// Unlike the original Leiden refinement, which merges nodes randomly with probabilities
+/// growing exponentially with modularity gains, nodes merge greedily.
+fn refine(
+    network: &Network,
+    communities: &[usize],
+    options: Options,
+    random: &mut Random,
+) -> Vec<usize> {
+    let count = network.count();
+
+    let scale = options.resolution / (2.0 * network.total);
+    let threshold = options.tolerance * network.total;
+
+    let mut refined: Vec<usize> = (0..count).collect();
+    let mut singletons = vec![true; count];
+
+    let mut totals = network.degrees.clone();
+    let mut sizes = vec![0.0; count];
+
+    // the weights from refined communities to the rest of their communities
+    let mut external = vec![0.0; count];
+
+    for node in 0..count {
+        let community = communities[node];
+
+        sizes[community] += network.degrees[node];
+
+        for &(other, weight) in network.arcs(node) {
+            if communities[other] == community {
+                external[node] += weight;
+            }
+        }
+    }
+
+    let is_connected =
+        |external: f64, total: f64, size: f64| external >= total * (size - total) * scale;
+
+    let mut order: Vec<usize> = (0..count).collect();
+
+    random.shuffle(&mut order);
+
+    let mut links = Links::new(count);
+
+    for &node in &order {
+        let community = communities[node];
+        let degree = network.degrees[node];
+        let size = sizes[community];
+
+        if !singletons[node] || !is_connected(external[node], degree, size) {
+            continue;
+        }
+
+        for &(other, weight) in network.arcs(node) {
+            if communities[other] == community {
+                links.add(refined[other], weight);
+            }
+        }
+
+        let mut best = None;
+        let mut best_gain = threshold;
+
+        for &target in &links.touched {
+            if target == node || !is_connected(external[target], totals[target], size) {
+                continue;
+            }
+
+            let gain = links.weight(target) - totals[target] * degree * scale;
+
+            if gain > best_gain {
+                best = Some(target);
+                best_gain = gain;
+            }
+        }
+
+        if let Some(target) = best {
+            external[target] += external[node] - 2.0 * links.weight(target);
+
+            totals[target] += degree;
+            totals[node] = 0.0;
+
+            refined[node] = target;
+
+            // the community is named after its first node, which is in it
+            singletons[node] = false;
+            singletons[target] = false;
+        }
+
+        links.clear();
+    }
+
+    refined
+}
+
+/// Optimizes modularity of the given network, returning the community of every node.
+fn optimize(network: &Network, options: Options, refining: bool) -> Vec<usize> {
+    let mut random = Random::new(options.seed);
+
+    // the node of the current network every original node is collapsed into
+    let mut members: Vec<usize> = (0..network.count()).collect();
+
+    let mut communities: Vec<usize> = members.clone();
+
+    if network.total <= 0.0 {
+        return communities;
+    }
+
+    let mut aggregated: Option<Network> = None;
+
+    loop {
+        let level = aggregated.as_ref().unwrap_or(network);
+
+        let count = level.count();
+
+        move_nodes(level, &mut communities, options, &mut random);
+
+        // communities left disconnected by moving nodes are split, so that
+        // every community collapsed into the single node is connected
+        let parts = if refining {
+            split(level, &mut communities)
+        } else {
+            renumber(&mut communities)
+        };
+
+        if parts == count {
+            break;
+        }
+
+        let mut aggregation = communities.clone();
+        let mut next: Vec<usize> = (0..parts).collect();
+
+        if refining {
+            let mut refined = refine(level, &communities, options, &mut random);
+
+            let subparts = renumber(&mut refined);
+
+            // collapse the (connected) communities if the refinement did not merge anything
+            if subparts < count {
+                next = vec![0; subparts];
+
+                for node in 0..count {
+                    next[refined[node]] = communities[node];
+                }
+
+                aggregation = refined;
+            }
+        }
+
+        aggregated = Some(level.aggregate(&aggregation, next.len()));
+
+        for member in &mut members {
+            *member = aggregation[*member];
+        }
+
+        communities = next;
+    }
+
+    for member in &mut members {
+        *member = communities[*member];
+    }
+
+    members
+}
+
+/// Assigns labels to nodes, moving every node to the label most common among its neighbors
+/// until every node has one of the most common labels, breaking ties randomly.
+fn propagate(network: &Network, seed: u64) -> Vec<usize> {
+    let count = network.count();
+
+    let mut random = Random::new(seed);
+
+    let mut labels: Vec<usize> = (0..count).collect();
+    let mut order = labels.clone();
+
+    let mut links = Links::new(count);
+    let mut best = Vec::new();
+
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        random.shuffle(&mut order);
+
+        for &node in &order {
+            for &(other, weight) in network.arcs(node) {
+                links.add(labels[other], weight);
+            }
+
+            let most = links
+                .touched
+                .iter()
+                .map(|&label| links.weight(label))
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            best.clear();
+            best.extend(
+                links
+                    .touched
+                    .iter()
+                    .copied()
+                    .filter(|&label| links.weight(label) == most),
+            );
+
+            if !best.is_empty() && !best.contains(&labels[node]) {
+                labels[node] = best[random.below(best.len())];
+
+                changed = true;
+            }
+
+            links.clear();
+        }
+    }
+
+    labels
+}
+
+/// Builds the weighted network of the given graph, returning it along with node indices
+/// of its nodes; edges with non-positive weights are ignored.
+fn network<G, F>(graph: &G, mut weight: F) -> (Network, Vec<usize>)
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (indices, positions) = compact_with(graph, |node| graph.node_index(node));
+
+    let mut edges = Vec::new();
+
+    for edge in graph.edge_identifiers() {
+        let value = weight(graph.edge_value(edge).expect(EDGE));
+
+        // non-positive weights mean no edges
+        if value.is_nan() || value <= 0.0 {
+            continue;
+        }
+
+        let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+        edges.push((
+            positions[graph.node_index(one)],
+            positions[graph.node_index(two)],
+            value,
+        ));
+    }
+
+    (Network::new(indices.len(), &edges), indices)
+}
+
+/// Spreads the given communities of network nodes into [`Communities`] indexed by node indices.
+fn finish(
+    bound: usize,
+    network: &Network,
+    indices: &[usize],
+    mut communities: Vec<usize>,
+    resolution: f64,
+) -> Communities {
+    let count = renumber(&mut communities);
+
+    let modularity = network.modularity(&communities, count, resolution);
+
+    let mut result = vec![usize::LIMIT; bound];
+
+    for (&index, &community) in indices.iter().zip(&communities) {
+        result[index] = community;
+    }
+
+    Communities::new(result, count, modularity)
+}
+
+/// Computes the modularity of the given partition of the given graph into `communities`,
+/// indexed by node indices, treating all edges equally.
+///
+/// See [`modularity_by`] for more information.
+///
+/// # Panics
+///
+/// Panics if any node present in the graph does not have its community given.
+pub fn modularity<G>(graph: G, communities: &[usize], resolution: f64) -> f64
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    modularity_by(graph, communities, resolution, |_| 1.0)
+}
+
+/// Computes the modularity of the given partition of the given graph into `communities`,
+/// indexed by node indices, with edge weights given by `weight`.
+///
+/// Modularity is the fraction of the total weight of edges inside of communities,
+/// minus `resolution` times the fraction expected if edges were placed at random,
+/// preserving weighted degrees. Self-loops count twice towards degrees,
+/// and edges with non-positive weights are ignored. Graphs without edges have zero modularity.
+///
+/// # Panics
+///
+/// Panics if any node present in the graph does not have its community given.
+pub fn modularity_by<G, F>(graph: G, communities: &[usize], resolution: f64, weight: F) -> f64
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, indices) = network(&graph, weight);
+
+    let mut labels: Vec<usize> = indices.iter().map(|&index| communities[index]).collect();
+
+    // communities are arbitrary, so number them via sorting
+    let mut sorted = labels.clone();
+
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    for label in &mut labels {
+        *label = sorted
+            .binary_search(label)
+            .unwrap_or_else(|position| position);
+    }
+
+    network.modularity(&labels, sorted.len(), resolution)
+}
+
+/// Detects communities of the given graph using the Louvain method, treating all edges equally.
+///
+/// See [`louvain_by`] for more information.
+pub fn louvain<G>(graph: G, options: Options) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    louvain_by(graph, options, |_| 1.0)
+}
+
+/// Detects communities of the given graph using the Louvain method,
+/// with edge weights given by `weight`.
+///
+/// Nodes are repeatedly moved to the neighboring communities that increase modularity
+/// the most, after which communities are collapsed into single nodes and the process repeats,
+/// until modularity can not be increased anymore. Nodes are visited in the random order,
+/// determined by the seed, so results are reproducible.
+///
+/// Edges with non-positive weights are ignored. See [`modularity_by`] for more information.
+pub fn louvain_by<G, F>(graph: G, options: Options, weight: F) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, indices) = network(&graph, weight);
+
+    let communities = optimize(&network, options, false);
+
+    finish(
+        graph.node_bound(),
+        &network,
+        &indices,
+        communities,
+        options.resolution,
+    )
+}
+
+/// Detects communities of the given graph using the Leiden method, treating all edges equally.
+///
+/// See [`leiden_by`] for more information.
+pub fn leiden<G>(graph: G, options: Options) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    leiden_by(graph, options, |_| 1.0)
+}
+
+/// Detects communities of the given graph using the Leiden method,
+/// with edge weights given by `weight`.
+///
+/// This refines the Louvain method (see [`louvain_by`]): before collapsing communities,
+/// they are split into well-connected subcommunities, which are collapsed instead,
+/// while keeping their communities. This guarantees that communities are connected.
+///
+/// Nodes are merged into subcommunities greedily, rather than randomly.
+pub fn leiden_by<G, F>(graph: G, options: Options, weight: F) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, indices) = network(&graph, weight);
+
+    let communities = optimize(&network, options, true);
+
+    finish(
+        graph.node_bound(),
+        &network,
+        &indices,
+        communities,
+        options.resolution,
+    )
+}
+
+/// Detects communities of the given graph using asynchronous label propagation,
+/// treating all edges equally.
+///
+/// See [`label_propagation_by`] for more information.
+pub fn label_propagation<G>(graph: G, seed: u64) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    label_propagation_by(graph, seed, |_| 1.0)
+}
+
+/// Detects communities of the given graph using asynchronous label propagation,
+/// with edge weights given by `weight`.
+///
+/// Every node starts with its own label, and then nodes, in the random order, adopt
+/// the label with the largest total weight among their neighbors, until every node has such
+/// label. Ties are broken randomly, and both the order and ties are determined by the seed.
+///
+/// Self-loops and edges with non-positive weights are ignored. The modularity
+/// of the result is computed with the default resolution.
+pub fn label_propagation_by<G, F>(graph: G, seed: u64, weight: F) -> Communities
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let (network, indices) = network(&graph, weight);
+
+    let labels = propagate(&network, seed);
+
+    finish(
+        graph.node_bound(),
+        &network,
+        &indices,
+        labels,
+        Options::RESOLUTION,
+    )
+}
+
+/// Represents the Louvain method, treating all edges equally.
+///
+/// See [`louvain`] for more information.
+pub struct Louvain {
+    options: Options,
+}
+
+impl Louvain {
+    /// Constructs [`Self`] with the given `options`.
+    pub const fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<G> Algorithm<G> for Louvain
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        louvain(graph, self.options)
+    }
+}
+
+/// Represents the Louvain method, with edge weights given by the closure.
+///
+/// See [`louvain_by`] for more information.
+pub struct LouvainBy<F> {
+    options: Options,
+    weight: F,
+}
+
+impl<F> LouvainBy<F> {
+    /// Constructs [`Self`] with the given `options` and `weight` function.
+    pub const fn new(options: Options, weight: F) -> Self {
+        Self { options, weight }
+    }
+}
+
+impl<G, F> Algorithm<G> for LouvainBy<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        louvain_by(graph, self.options, &mut self.weight)
+    }
+}
+
+/// Represents the Leiden method, treating all edges equally.
+///
+/// See [`leiden`] for more information.
+pub struct Leiden {
+    options: Options,
+}
+
+impl Leiden {
+    /// Constructs [`Self`] with the given `options`.
+    pub const fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<G> Algorithm<G> for Leiden
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        leiden(graph, self.options)
+    }
+}
+
+/// Represents the Leiden method, with edge weights given by the closure.
+///
+/// See [`leiden_by`] for more information.
+pub struct LeidenBy<F> {
+    options: Options,
+    weight: F,
+}
+
+impl<F> LeidenBy<F> {
+    /// Constructs [`Self`] with the given `options` and `weight` function.
+    pub const fn new(options: Options, weight: F) -> Self {
+        Self { options, weight }
+    }
+}
+
+impl<G, F> Algorithm<G> for LeidenBy<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        leiden_by(graph, self.options, &mut self.weight)
+    }
+}
+
+/// Represents asynchronous label propagation, treating all edges equally.
+///
+/// See [`label_propagation`] for more information.
+pub struct LabelPropagation {
+    seed: u64,
+}
+
+impl LabelPropagation {
+    /// Constructs [`Self`] with the given `seed`.
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl<G> Algorithm<G> for LabelPropagation
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        label_propagation(graph, self.seed)
+    }
+}
+
+/// Represents asynchronous label propagation, with edge weights given by the closure.
+///
+/// See [`label_propagation_by`] for more information.
+pub struct LabelPropagationBy<F> {
+    seed: u64,
+    weight: F,
+}
+
+impl<F> LabelPropagationBy<F> {
+    /// Constructs [`Self`] with the given `seed` and `weight` function.
+    pub const fn new(seed: u64, weight: F) -> Self {
+        Self { seed, weight }
+    }
+}
+
+impl<G, F> Algorithm<G> for LabelPropagationBy<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    type Output = Communities;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        label_propagation_by(graph, self.seed, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::count::NodeCount;
+
+    use crate::testing::UnGraph;
+
+    /// Checks whether every community is connected via edges with positive weights.
+    fn connected(graph: &UnGraph<u32>, communities: &[usize]) -> bool {
+        let count = graph.node_count();
+
+        let mut visited = vec![false; count];
+        let mut roots = Vec::new();
+
+        for root in 0..count {
+            if visited[root] {
+                continue;
+            }
+
+            // every community has to be reached from its first node
+            let community = communities[root];
+
+            if roots.contains(&community) {
+                return false;
+            }
+
+            roots.push(community);
+
+            visited[root] = true;
+
+            let mut stack = vec![root];
+
+            while let Some(node) = stack.pop() {
+                for edge in graph.edge_identifiers() {
+                    let (one, two) = graph.endpoints(edge).unwrap();
+
+                    let other = if one.get() == node {
+                        two.get()
+                    } else if two.get() == node {
+                        one.get()
+                    } else {
+                        continue;
+                    };
+
+                    if *graph.edge_value(edge).unwrap() > 0
+                        && !visited[other]
+                        && communities[other] == community
+                    {
+                        visited[other] = true;
+
+                        stack.push(other);
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn weight(value: &u32) -> f64 {
+        f64::from(*value)
+    }
+
+    fn triangles() -> UnGraph<u32> {
+        UnGraph::weighted(
+            6,
+            [
+                (0, 1, 1),
+                (1, 2, 1),
+                (2, 0, 1),
+                (3, 4, 1),
+                (4, 5, 1),
+                (5, 3, 1),
+                (2, 3, 1),
+            ],
+        )
+    }
+
+    #[test]
+    fn modularity() {
+        let graph = triangles();
+
+        // both communities have three of seven edges inside and half of the degrees
+        let expected = 2.0 * (3.0 / 7.0 - 0.25);
+
+        let value = modularity_by(&graph, &[4, 4, 4, 1, 1, 1], Options::RESOLUTION, weight);
+
+        assert!((value - expected).abs() < 1e-9);
+
+        let empty = UnGraph::unweighted(2, &[]);
+
+        assert!(super::modularity(&empty, &[0, 1], 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bridged_triangles() {
+        let graph = triangles();
+
+        for seed in 0..8 {
+            let options = Options::seeded(seed);
+
+            for communities in [
+                louvain_by(&graph, options, weight),
+                leiden_by(&graph, options, weight),
+            ] {
+                assert_eq!(communities.count, 2);
+
+                assert_eq!(communities.communities[0], communities.communities[2]);
+                assert_eq!(communities.communities[3], communities.communities[5]);
+                assert_ne!(communities.communities[0], communities.communities[3]);
+
+                assert!((communities.modularity - 2.0 * (3.0 / 7.0 - 0.25)).abs() < 1e-9);
+
+                assert_eq!(communities.community_of(6), None);
+            }
+        }
+    }
+
+    #[test]
+    fn leiden_connected() {
+        let graph = UnGraph::weighted(
+            7,
+            [
+                (4, 5, 3),
+                (4, 6, 3),
+                (3, 5, 2),
+                (5, 3, 0),
+                (0, 4, 1),
+                (2, 4, 1),
+                (1, 4, 3),
+                (1, 1, 0),
+            ],
+        );
+
+        let communities = leiden_by(&graph, Options::new(2.0, Options::TOLERANCE, 6554), weight);
+
+        assert!(connected(&graph, &communities.communities));
+    }
+
+    #[test]
+    fn leiden_connected_random() {
+        let mut random = Random::new(13);
+
+        for _ in 0..200 {
+            let edges: Vec<_> = (0..10)
+                .map(|_| {
+                    let weight = random.below(4) as u32;
+
+                    (random.below(8), random.below(8), weight)
+                })
+                .collect();
+
+            let graph = UnGraph::weighted(8, edges);
+
+            for resolution in [0.5, 1.0, 2.0, 4.0] {
+                let options = Options::new(resolution, Options::TOLERANCE, random.next());
+
+                let communities = leiden_by(&graph, options, weight);
+
+                assert!(connected(&graph, &communities.communities));
+            }
+        }
+    }
+
+    #[test]
+    fn label_propagation() {
+        let graph = UnGraph::unweighted(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+
+        let communities = super::label_propagation(&graph, 7);
+
+        assert_eq!(communities.count, 2);
+
+        assert_eq!(communities.communities[0], communities.communities[1]);
+        assert_eq!(communities.communities[0], communities.communities[2]);
+        assert_eq!(communities.communities[3], communities.communities[4]);
+        assert_eq!(communities.communities[3], communities.communities[5]);
+        assert_ne!(communities.communities[0], communities.communities[3]);
+    }
+}
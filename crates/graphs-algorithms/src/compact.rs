@@ -0,0 +1,39 @@
+//! Dense numberings of nodes.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{identifiers::NodeIdentifiers, indexed::NodeIndexed, limit::Limited};
+
+/// Numbers the nodes of the given graph densely, in the order of node identifiers.
+///
+/// Returns the value of every node given by `value`, indexed by node positions,
+/// along with the position of every node, indexed by node indices.
+/// Node indices not present in the graph have [`usize::LIMIT`] positions.
+pub(crate) fn compact_with<G, T, F>(graph: &G, mut value: F) -> (Vec<T>, Vec<usize>)
+where
+    G: NodeIdentifiers + NodeIndexed,
+    F: FnMut(G::NodeId) -> T,
+{
+    let mut values = Vec::new();
+    let mut positions = vec![usize::LIMIT; graph.node_bound()];
+
+    for node in graph.node_identifiers() {
+        positions[graph.node_index(node)] = values.len();
+
+        values.push(value(node));
+    }
+
+    (values, positions)
+}
+
+/// Numbers the nodes of the given graph densely, in the order of node identifiers.
+///
+/// Returns the nodes, indexed by their positions, along with their positions,
+/// indexed by node indices. See [`compact_with`] for more information.
+pub(crate) fn compact<G>(graph: &G) -> (Vec<G::NodeId>, Vec<usize>)
+where
+    G: NodeIdentifiers + NodeIndexed,
+{
+    compact_with(graph, |node| node)
+}
@@ -0,0 +1,239 @@
+//! Condensations of directed graphs.
+//!
+//! The condensation of a graph is the graph of its strongly connected components,
+//! where every component is contracted into one node. Condensations are always acyclic.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use graphs_core::{
+    base::{Base, Directed},
+    build::Build,
+    create::Create,
+    edges::DirectedEdges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    neighbors::Neighbors,
+    recoverable::Recoverable,
+};
+
+use crate::{EDGE, strongly_connected::tarjan};
+
+pub use crate::build::{BuildError, BuildErrorOf};
+
+/// Represents results of [`condensation`] and [`condensation_with`].
+pub type Output<C> = Result<C, BuildErrorOf<C>>;
+
+/// The condensation with nodes added, their identifiers, and `(source, target, edge)` triples.
+type Parts<G, C> = (C, Vec<<C as Base>::NodeId>, Vec<(usize, usize, <G as Base>::EdgeId)>);
+
+fn build_nodes<G, C>(graph: &G) -> Result<Parts<G, C>, BuildErrorOf<C>>
+where
+    G: NodeIdentifiers + Neighbors + DirectedEdges + NodeIndexed + Endpoints,
+    C: Create + Build<NodeValue = Vec<G::NodeId>> + Directed,
+{
+    let components = tarjan(graph);
+
+    let count = components.len();
+
+    let mut condensation = C::empty();
+
+    let mut nodes = Vec::with_capacity(count);
+
+    // components are in reverse topological order, so we add them in reverse
+
+    for component in components.components.into_iter().rev() {
+        let node = condensation
+            .add_node(component)
+            .map_err(Recoverable::into_error)
+            .map_err(BuildError::Node)?;
+
+        nodes.push(node);
+    }
+
+    let position = |index: usize| count - 1 - components.indices[index];
+
+    let mut edges = Vec::new();
+
+    for node in graph.node_identifiers() {
+        let source = position(graph.node_index(node));
+
+        for edge in graph.outgoing_edges(node) {
+            let (_, target) = graph.endpoints(edge).expect(EDGE);
+
+            let target = position(graph.node_index(target));
+
+            // edges within components are dropped, which makes the condensation acyclic
+            if source != target {
+                edges.push((source, target, edge));
+            }
+        }
+    }
+
+    Ok((condensation, nodes, edges))
+}
+
+/// Builds the condensation of the given graph, keeping every edge between components.
+///
+/// Every node of the condensation holds the member nodes of its component,
+/// and every edge holds the identifier of the original edge.
+/// Nodes are added in topological order, and edges within components are dropped.
+///
+/// # Errors
+///
+/// Returns [`BuildError`] if adding some node or edge to the condensation fails.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn condensation<G, C>(graph: G) -> Output<C>
+where
+    G: NodeIdentifiers + Neighbors + DirectedEdges + NodeIndexed + Endpoints,
+    C: Create + Build<NodeValue = Vec<G::NodeId>, EdgeValue = G::EdgeId> + Directed,
+{
+    let (mut condensation, nodes, edges) = build_nodes::<G, C>(&graph)?;
+
+    for (source, target, edge) in edges {
+        condensation
+            .add_edge_connecting(nodes[source], nodes[target], edge)
+            .map_err(Recoverable::into_error)
+            .map_err(BuildError::Edge)?;
+    }
+
+    Ok(condensation)
+}
+
+/// Builds the condensation of the given graph, aggregating parallel edges between components.
+///
+/// Every node of the condensation holds the member nodes of its component,
+/// and for every pair of connected components, one edge is added with the value
+/// returned by the `aggregate` function, which is given all original edges between them.
+/// Nodes are added in topological order, and edges within components are dropped.
+///
+/// # Errors
+///
+/// Returns [`BuildError`] if adding some node or edge to the condensation fails.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn condensation_with<G, C, F>(graph: G, mut aggregate: F) -> Output<C>
+where
+    G: NodeIdentifiers + Neighbors + DirectedEdges + NodeIndexed + Endpoints,
+    C: Create + Build<NodeValue = Vec<G::NodeId>> + Directed,
+    F: FnMut(&[G::EdgeId]) -> C::EdgeValue,
+{
+    let (mut condensation, nodes, mut edges) = build_nodes::<G, C>(&graph)?;
+
+    edges.sort_by_key(|&(source, target, _)| (source, target));
+
+    let mut parallel = Vec::new();
+
+    for group in edges.chunk_by(|one, two| (one.0, one.1) == (two.0, two.1)) {
+        let (source, target, _) = group[0];
+
+        parallel.clear();
+        parallel.extend(group.iter().map(|&(_, _, edge)| edge));
+
+        let value = aggregate(&parallel);
+
+        condensation
+            .add_edge_connecting(nodes[source], nodes[target], value)
+            .map_err(Recoverable::into_error)
+            .map_err(BuildError::Edge)?;
+    }
+
+    Ok(condensation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::{count::EdgeCount, data::DataRef, identifiers::EdgeIdentifiers};
+
+    use crate::testing::{DiGraph, Edge, Node};
+
+    fn graph() -> DiGraph<u32> {
+        DiGraph::weighted(
+            6,
+            [
+                (0, 1, 1),
+                (1, 0, 2),
+                (1, 2, 3),
+                (0, 2, 4),
+                (2, 3, 5),
+                (3, 2, 6),
+                (5, 0, 7),
+                (5, 1, 8),
+            ],
+        )
+    }
+
+    fn members(condensation: &DiGraph<impl Sized, Vec<Node>>, node: Node) -> Vec<usize> {
+        let mut members: Vec<_> = condensation
+            .node_value(node)
+            .unwrap()
+            .iter()
+            .map(|member| member.get())
+            .collect();
+
+        members.sort_unstable();
+
+        members
+    }
+
+    #[test]
+    fn condense() {
+        let graph = graph();
+
+        let condensation: DiGraph<Edge, Vec<Node>> = condensation(&graph).unwrap();
+
+        let nodes: Vec<_> = condensation.node_identifiers().collect();
+
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(condensation.edge_count(), 4);
+
+        for edge in condensation.edge_identifiers() {
+            let (source, target) = condensation.endpoints(edge).unwrap();
+
+            // nodes are added in topological order
+            assert!(source.get() < target.get());
+
+            let original = *condensation.edge_value(edge).unwrap();
+
+            let (one, two) = graph.endpoints(original).unwrap();
+
+            assert!(members(&condensation, source).contains(&one.get()));
+            assert!(members(&condensation, target).contains(&two.get()));
+        }
+
+        assert_eq!(members(&condensation, nodes[0]).len(), 1);
+        assert!(nodes.iter().any(|&node| members(&condensation, node) == [0, 1]));
+        assert!(nodes.iter().any(|&node| members(&condensation, node) == [2, 3]));
+    }
+
+    #[test]
+    fn aggregate() {
+        let graph = graph();
+
+        let condensation: DiGraph<u32, Vec<Node>> = condensation_with(&graph, |edges: &[Edge]| {
+            edges
+                .iter()
+                .map(|&edge| graph.edge_value(edge).unwrap())
+                .sum()
+        })
+        .unwrap();
+
+        let mut values: Vec<_> = condensation
+            .edge_identifiers()
+            .map(|edge| *condensation.edge_value(edge).unwrap())
+            .collect();
+
+        values.sort_unstable();
+
+        // 5 goes to the component of 0 and 1 via two edges, which go to 2 and 3 via two others
+        assert_eq!(values, [7, 15]);
+    }
+}
@@ -0,0 +1,897 @@
+//! Elementary circuits of directed graphs and cycle bases of undirected graphs.
+
+use core::cmp::Reverse;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::collections::{BinaryHeap, VecDeque};
+
+use graphs_bits::BitSet;
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Directed, Undirected},
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+
+use crate::{EDGE, compact::compact, heap::Entry, weight::Weight};
+
+/// Represents cycles, that is, edges in order.
+///
+/// In directed graphs, the target of every edge is the source of the next one,
+/// and the target of the last edge is the source of the first one. In undirected graphs,
+/// every edge shares one endpoint with the next one, and the last edge shares
+/// the other endpoint with the first one.
+pub type Cycle<E> = Vec<E>;
+
+/// Represents [`Cycle`] of the graph `G`.
+pub type CycleOf<G> = Cycle<<G as Base>::EdgeId>;
+
+/// Represents frames of circuit searches.
+struct Frame {
+    node: usize,
+    cursor: usize,
+    found: bool,
+}
+
+impl Frame {
+    const fn new(node: usize, cursor: usize) -> Self {
+        Self {
+            node,
+            cursor,
+            found: false,
+        }
+    }
+}
+
+/// Represents iterators over elementary circuits of directed graphs.
+///
+/// See [`elementary_circuits`] for more information.
+pub struct Circuits<E> {
+    edges: Vec<E>,
+    starts: Vec<usize>,
+    arcs: Vec<(usize, usize)>,
+    reverse_starts: Vec<usize>,
+    reverse_arcs: Vec<usize>,
+    root: usize,
+    reached: Vec<usize>,
+    members: Vec<usize>,
+    blocked: Vec<bool>,
+    blocks: Vec<Vec<usize>>,
+    stack: Vec<Frame>,
+    path: Vec<usize>,
+}
+
+/// Represents [`Circuits`] of the graph `G`.
+pub type CircuitsOf<G> = Circuits<<G as Base>::EdgeId>;
+
+impl<E: Copy> Circuits<E> {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: Base<EdgeId = E> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+    {
+        let (nodes, positions) = compact(graph);
+
+        let count = nodes.len();
+
+        let mut edges = Vec::new();
+        let mut ends = Vec::new();
+
+        let mut starts = vec![0; count + 1];
+        let mut reverse_starts = vec![0; count + 1];
+
+        for edge in graph.edge_identifiers() {
+            let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+            let source = positions[graph.node_index(one)];
+            let target = positions[graph.node_index(two)];
+
+            edges.push(edge);
+            ends.push((source, target));
+
+            starts[source + 1] += 1;
+            reverse_starts[target + 1] += 1;
+        }
+
+        for node in 0..count {
+            starts[node + 1] += starts[node];
+            reverse_starts[node + 1] += reverse_starts[node];
+        }
+
+        let mut next = starts.clone();
+        let mut reverse_next = reverse_starts.clone();
+
+        let mut arcs = vec![(0, 0); edges.len()];
+        let mut reverse_arcs = vec![0; edges.len()];
+
+        for (number, &(source, target)) in ends.iter().enumerate() {
+            arcs[next[source]] = (number, target);
+            next[source] += 1;
+
+            reverse_arcs[reverse_next[target]] = source;
+            reverse_next[target] += 1;
+        }
+
+        Self {
+            edges,
+            starts,
+            arcs,
+            reverse_starts,
+            reverse_arcs,
+            root: usize::LIMIT,
+            reached: vec![usize::LIMIT; count],
+            members: vec![usize::LIMIT; count],
+            blocked: vec![false; count],
+            blocks: vec![Vec::new(); count],
+            stack: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    fn is_member(&self, node: usize) -> bool {
+        self.members[node] == self.root
+    }
+
+    /// Starts searching for circuits through the current root, restricted to the strongly
+    /// connected component containing it among the nodes not preceding it.
+    fn prepare(&mut self) {
+        let root = self.root;
+
+        let mut queue = VecDeque::new();
+
+        self.reached[root] = root;
+
+        queue.push_back(root);
+
+        while let Some(node) = queue.pop_front() {
+            for index in self.starts[node]..self.starts[node + 1] {
+                let (_, other) = self.arcs[index];
+
+                if other > root && self.reached[other] != root {
+                    self.reached[other] = root;
+
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        // the nodes reachable from the root that can reach it back
+        self.members[root] = root;
+
+        queue.push_back(root);
+
+        while let Some(node) = queue.pop_front() {
+            self.blocked[node] = false;
+            self.blocks[node].clear();
+
+            for index in self.reverse_starts[node]..self.reverse_starts[node + 1] {
+                let other = self.reverse_arcs[index];
+
+                if self.reached[other] == root && self.members[other] != root {
+                    self.members[other] = root;
+
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        self.blocked[root] = true;
+
+        self.stack.push(Frame::new(root, self.starts[root]));
+    }
+
+    /// Unblocks the given node, along with the nodes blocked until it is unblocked.
+    fn unblock(&mut self, node: usize) {
+        let mut pending = vec![node];
+
+        while let Some(node) = pending.pop() {
+            if self.blocked[node] {
+                self.blocked[node] = false;
+
+                pending.append(&mut self.blocks[node]);
+            }
+        }
+    }
+
+    /// Collects the current path, closed by the given edge.
+    fn circuit(&self, number: usize) -> Cycle<E> {
+        self.path
+            .iter()
+            .chain([&number])
+            .map(|&number| self.edges[number])
+            .collect()
+    }
+}
+
+impl<E: Copy> Iterator for Circuits<E> {
+    type Item = Cycle<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                self.root = if self.root.is_limit() {
+                    0
+                } else {
+                    self.root + 1
+                };
+
+                let count = self.members.len();
+
+                if self.root >= count {
+                    self.root = count;
+
+                    return None;
+                }
+
+                self.prepare();
+
+                continue;
+            };
+
+            let node = frame.node;
+
+            if frame.cursor < self.starts[node + 1] {
+                let (number, other) = self.arcs[frame.cursor];
+
+                frame.cursor += 1;
+
+                if other == self.root {
+                    frame.found = true;
+
+                    return Some(self.circuit(number));
+                }
+
+                if self.is_member(other) && !self.blocked[other] {
+                    self.blocked[other] = true;
+
+                    self.path.push(number);
+
+                    self.stack.push(Frame::new(other, self.starts[other]));
+                }
+
+                continue;
+            }
+
+            let found = frame.found;
+
+            self.stack.pop();
+
+            if found {
+                self.unblock(node);
+            } else {
+                // keep the node blocked until some of its successors get unblocked
+                for index in self.starts[node]..self.starts[node + 1] {
+                    let (_, other) = self.arcs[index];
+
+                    if self.is_member(other) && !self.blocks[other].contains(&node) {
+                        self.blocks[other].push(node);
+                    }
+                }
+            }
+
+            if let Some(parent) = self.stack.last_mut() {
+                parent.found |= found;
+
+                self.path.pop();
+            }
+        }
+    }
+}
+
+/// Enumerates elementary circuits of the given directed graph, that is,
+/// cycles that do not repeat nodes, using Johnson's algorithm.
+///
+/// Circuits are found lazily, so callers can stop early; enumerating all of them takes
+/// `O((V + E)(V + C))` time, where `C` is the number of circuits.
+/// Every circuit starts at its first node in the order of node identifiers,
+/// and self-loops are circuits of single edges. Circuits going through the same nodes
+/// via different parallel edges are different.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn elementary_circuits<G>(graph: G) -> CircuitsOf<G>
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    Circuits::new(&graph)
+}
+
+/// Represents spanning forests of undirected graphs, built via breadth-first search,
+/// along with the edges outside of them.
+struct Forest<E> {
+    edges: Vec<(E, usize, usize)>,
+    starts: Vec<usize>,
+    arcs: Vec<(usize, usize)>,
+    parents: Vec<usize>,
+    parent_edges: Vec<usize>,
+    depths: Vec<usize>,
+    chords: Vec<usize>,
+    coordinates: Vec<usize>,
+}
+
+impl<E: Copy> Forest<E> {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: Base<EdgeId = E> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+    {
+        let (nodes, positions) = compact(graph);
+
+        let count = nodes.len();
+
+        let mut edges = Vec::new();
+        let mut starts = vec![0; count + 1];
+
+        for edge in graph.edge_identifiers() {
+            let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+            let one = positions[graph.node_index(one)];
+            let two = positions[graph.node_index(two)];
+
+            edges.push((edge, one, two));
+
+            starts[one + 1] += 1;
+            starts[two + 1] += 1;
+        }
+
+        for node in 0..count {
+            starts[node + 1] += starts[node];
+        }
+
+        let mut next = starts.clone();
+        let mut arcs = vec![(0, 0); starts[count]];
+
+        for (number, &(_, one, two)) in edges.iter().enumerate() {
+            arcs[next[one]] = (number, two);
+            next[one] += 1;
+
+            arcs[next[two]] = (number, one);
+            next[two] += 1;
+        }
+
+        let mut parents = vec![usize::LIMIT; count];
+        let mut parent_edges = vec![usize::LIMIT; count];
+        let mut depths = vec![0; count];
+
+        let mut visited = vec![false; count];
+        let mut tree = vec![false; edges.len()];
+
+        let mut queue = VecDeque::new();
+
+        for root in 0..count {
+            if visited[root] {
+                continue;
+            }
+
+            visited[root] = true;
+
+            queue.push_back(root);
+
+            while let Some(node) = queue.pop_front() {
+                for &(number, other) in &arcs[starts[node]..starts[node + 1]] {
+                    if !visited[other] {
+                        visited[other] = true;
+
+                        parents[other] = node;
+                        parent_edges[other] = number;
+                        depths[other] = depths[node] + 1;
+
+                        tree[number] = true;
+
+                        queue.push_back(other);
+                    }
+                }
+            }
+        }
+
+        // edges outside of the forest, each of which closes exactly one fundamental cycle
+        let chords: Vec<usize> = (0..edges.len()).filter(|&number| !tree[number]).collect();
+
+        let mut coordinates = vec![usize::LIMIT; edges.len()];
+
+        for (coordinate, &number) in chords.iter().enumerate() {
+            coordinates[number] = coordinate;
+        }
+
+        Self {
+            edges,
+            starts,
+            arcs,
+            parents,
+            parent_edges,
+            depths,
+            chords,
+            coordinates,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.parents.len()
+    }
+
+    fn arcs(&self, node: usize) -> &[(usize, usize)] {
+        &self.arcs[self.starts[node]..self.starts[node + 1]]
+    }
+
+    /// Returns the fundamental cycle closed by the given chord, starting with it.
+    fn fundamental(&self, chord: usize) -> Cycle<E> {
+        let (edge, one, two) = self.edges[chord];
+
+        let mut cycle = vec![edge];
+
+        let mut down = Vec::new();
+
+        let (mut up, mut other) = (two, one);
+
+        // climb from both endpoints to their lowest common ancestor
+        while up != other {
+            if self.depths[up] >= self.depths[other] {
+                cycle.push(self.edges[self.parent_edges[up]].0);
+
+                up = self.parents[up];
+            } else {
+                down.push(self.edges[self.parent_edges[other]].0);
+
+                other = self.parents[other];
+            }
+        }
+
+        cycle.extend(down.into_iter().rev());
+
+        cycle
+    }
+
+    /// Converts the given cycle into its vector over chords.
+    fn vector(&self, cycle: &[usize]) -> BitSet {
+        let mut vector = BitSet::with_capacity(self.chords.len());
+
+        for &number in cycle {
+            let coordinate = self.coordinates[number];
+
+            if !coordinate.is_limit() {
+                vector.insert(coordinate);
+            }
+        }
+
+        vector
+    }
+}
+
+/// Finds the fundamental cycle basis of the given undirected graph.
+///
+/// The spanning forest is built via breadth-first search from nodes in the order
+/// of their identifiers, and then every edge outside of it, in the order of edge identifiers,
+/// closes the cycle with the forest path between its endpoints. Every cycle starts
+/// with such edge, and self-loops are cycles of single edges.
+///
+/// There are `E - V + C` cycles, where `C` is the number of connected components,
+/// and every cycle of the graph is the symmetric difference of some of them.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn fundamental_cycle_basis<G>(graph: G) -> Vec<CycleOf<G>>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    let forest = Forest::new(&graph);
+
+    forest
+        .chords
+        .iter()
+        .map(|&chord| forest.fundamental(chord))
+        .collect()
+}
+
+/// Finds the shortest cycle having odd intersection with the given witness,
+/// returning its edge numbers in order.
+///
+/// This searches for shortest paths from every node to its copy in the graph
+/// of two layers, where edges in the witness cross the layers. Such paths are closed walks
+/// of odd intersection, from which simple cycles of odd intersection are extracted.
+fn shortest_odd<E: Copy, W: Weight>(
+    forest: &Forest<E>,
+    weights: &[W],
+    witness: &BitSet,
+) -> Vec<usize> {
+    let count = forest.count();
+
+    let crosses = |number: usize| {
+        let coordinate = forest.coordinates[number];
+
+        !coordinate.is_limit() && witness.contains(coordinate)
+    };
+
+    let mut distances = vec![W::MAX; 2 * count];
+    let mut settled = vec![false; 2 * count];
+    let mut predecessors = vec![(usize::LIMIT, usize::LIMIT); 2 * count];
+
+    let mut touched = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    let mut best: Option<(W, Vec<(usize, usize)>)> = None;
+
+    let mut sources = vec![false; count];
+
+    // odd cycles go through some edge of the witness, so start from their endpoints
+    for coordinate in witness {
+        let (_, source, _) = forest.edges[forest.chords[coordinate]];
+
+        if sources[source] {
+            continue;
+        }
+
+        sources[source] = true;
+
+        for state in touched.drain(..) {
+            distances[state] = W::MAX;
+            settled[state] = false;
+        }
+
+        heap.clear();
+
+        let (start, goal) = (2 * source, 2 * source + 1);
+
+        distances[start] = W::ZERO;
+        touched.push(start);
+
+        heap.push(Reverse(Entry::new(W::ZERO, start)));
+
+        while let Some(Reverse(Entry {
+            key: distance,
+            value: state,
+        })) = heap.pop()
+        {
+            if settled[state] {
+                continue;
+            }
+
+            // no shorter cycle can be found from this source anymore
+            if let Some((length, _)) = &best
+                && distance.compare(length).is_ge()
+            {
+                break;
+            }
+
+            settled[state] = true;
+
+            if state == goal {
+                let mut walk = Vec::new();
+                let mut current = goal;
+
+                while current != start {
+                    let (previous, number) = predecessors[current];
+
+                    walk.push((current / 2, number));
+
+                    current = previous;
+                }
+
+                walk.reverse();
+
+                best = Some((distance, walk));
+
+                break;
+            }
+
+            let (node, layer) = (state / 2, state % 2);
+
+            for &(number, other) in forest.arcs(node) {
+                let next = 2 * other + (layer ^ usize::from(crosses(number)));
+
+                if settled[next] {
+                    continue;
+                }
+
+                let candidate = distance + weights[number];
+
+                if candidate.compare(&distances[next]).is_lt() {
+                    if distances[next].compare(&W::MAX).is_eq() {
+                        touched.push(next);
+                    }
+
+                    distances[next] = candidate;
+                    predecessors[next] = (state, number);
+
+                    heap.push(Reverse(Entry::new(candidate, next)));
+                }
+            }
+        }
+    }
+
+    let Some((_, walk)) = best else {
+        return Vec::new();
+    };
+
+    // remove closed subwalks of even intersection until the walk does not repeat nodes
+    let start = walk.last().map_or(usize::LIMIT, |&(node, _)| node);
+
+    let mut positions = vec![usize::LIMIT; count];
+
+    let mut nodes = vec![start];
+    let mut numbers = Vec::new();
+
+    positions[start] = 0;
+
+    for (node, number) in walk {
+        let position = positions[node];
+
+        if position.is_limit() {
+            positions[node] = nodes.len();
+
+            nodes.push(node);
+            numbers.push(number);
+
+            continue;
+        }
+
+        numbers.push(number);
+
+        let odd = numbers[position..]
+            .iter()
+            .filter(|&&number| crosses(number))
+            .count()
+            % 2
+            == 1;
+
+        if odd {
+            return numbers.split_off(position);
+        }
+
+        for &node in &nodes[position + 1..] {
+            positions[node] = usize::LIMIT;
+        }
+
+        nodes.truncate(position + 1);
+        numbers.truncate(position);
+    }
+
+    numbers
+}
+
+/// Finds the minimum cycle basis of the given undirected graph,
+/// with edge weights given by `weight`, using de Pina's algorithm.
+///
+/// The minimum cycle basis is the cycle basis of the least total weight;
+/// weights are expected to be non-negative. Cycles are found one by one, each being
+/// the shortest cycle independent of the previous ones, which is ensured by keeping
+/// witnesses orthogonal to them. Cycles are simple, and self-loops are cycles of single edges.
+///
+/// There are `E - V + C` cycles, where `C` is the number of connected components,
+/// and this takes `O(E^2 V log V)` time in the worst case.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn minimum_cycle_basis<G, W, F>(graph: G, mut weight: F) -> Vec<CycleOf<G>>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let forest = Forest::new(&graph);
+
+    let weights: Vec<W> = forest
+        .edges
+        .iter()
+        .map(|&(edge, _, _)| weight(graph.edge_value(edge).expect(EDGE)))
+        .collect();
+
+    let dimension = forest.chords.len();
+
+    let mut witnesses: Vec<BitSet> = (0..dimension)
+        .map(|coordinate| {
+            let mut witness = BitSet::with_capacity(dimension);
+
+            witness.insert(coordinate);
+
+            witness
+        })
+        .collect();
+
+    let mut basis = Vec::with_capacity(dimension);
+
+    for index in 0..dimension {
+        let (done, rest) = witnesses.split_at_mut(index + 1);
+
+        let witness = &done[index];
+
+        let cycle = shortest_odd(&forest, &weights, witness);
+
+        let vector = forest.vector(&cycle);
+
+        // keep the remaining witnesses orthogonal to the new cycle
+        for other in rest {
+            if other.intersection_len(&vector) % 2 == 1 {
+                other.symmetric_difference_with(witness);
+            }
+        }
+
+        basis.push(
+            cycle
+                .into_iter()
+                .map(|number| forest.edges[number].0)
+                .collect(),
+        );
+    }
+
+    basis
+}
+
+/// Represents Johnson's algorithm for enumerating elementary circuits.
+///
+/// See [`elementary_circuits`] for more information.
+pub struct Johnson;
+
+impl<G> Algorithm<G> for Johnson
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = CircuitsOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        elementary_circuits(graph)
+    }
+}
+
+/// Represents the algorithm finding fundamental cycle bases.
+///
+/// See [`fundamental_cycle_basis`] for more information.
+pub struct FundamentalCycleBasis;
+
+impl<G> Algorithm<G> for FundamentalCycleBasis
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = Vec<CycleOf<G>>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        fundamental_cycle_basis(graph)
+    }
+}
+
+/// Represents de Pina's algorithm for finding minimum cycle bases.
+///
+/// See [`minimum_cycle_basis`] for more information.
+pub struct DePina<F> {
+    weight: F,
+}
+
+impl<F> DePina<F> {
+    /// Constructs [`Self`] with the given `weight` function.
+    pub const fn new(weight: F) -> Self {
+        Self { weight }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for DePina<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Vec<CycleOf<G>>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        minimum_cycle_basis(graph, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, Edge, UnGraph};
+
+    fn indices(cycle: &[Edge]) -> Vec<usize> {
+        cycle.iter().map(|edge| edge.get()).collect()
+    }
+
+    /// Checks that the given cycle is closed and does not repeat nodes.
+    fn check<E, V>(graph: &UnGraph<E, V>, cycle: &[Edge]) {
+        let mut degrees = vec![0; graph.node_bound()];
+
+        for (index, &edge) in cycle.iter().enumerate() {
+            let next = cycle[(index + 1) % cycle.len()];
+
+            let (one, two) = graph.endpoints(edge).unwrap();
+            let (three, four) = graph.endpoints(next).unwrap();
+
+            assert!(one == three || one == four || two == three || two == four);
+
+            degrees[one.get()] += 1;
+            degrees[two.get()] += 1;
+        }
+
+        assert!(degrees.iter().all(|&degree| degree == 0 || degree == 2));
+    }
+
+    #[test]
+    fn circuits() {
+        let graph = DiGraph::unweighted(4, &[(0, 1), (1, 0), (1, 2), (2, 0), (2, 2), (0, 1)]);
+
+        let mut circuits: Vec<_> = elementary_circuits(&graph)
+            .map(|circuit| indices(&circuit))
+            .collect();
+
+        circuits.sort_unstable();
+
+        assert_eq!(
+            circuits,
+            [
+                vec![0, 1],
+                vec![0, 2, 3],
+                vec![4],
+                vec![5, 1],
+                vec![5, 2, 3]
+            ]
+        );
+
+        assert_eq!(
+            elementary_circuits(&DiGraph::unweighted(3, &[(0, 1), (1, 2)])).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn fundamental() {
+        let graph = UnGraph::unweighted(5, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 1)]);
+
+        let basis = fundamental_cycle_basis(&graph);
+
+        assert_eq!(basis.len(), 3);
+
+        for cycle in &basis {
+            check(&graph, cycle);
+        }
+
+        assert!(basis.iter().any(|cycle| indices(cycle) == [5]));
+    }
+
+    #[test]
+    fn minimum() {
+        let graph = UnGraph::weighted(
+            5,
+            [
+                (0, 1, 1),
+                (1, 2, 1),
+                (2, 3, 1),
+                (3, 0, 1),
+                (0, 2, 5),
+                (3, 4, 2),
+            ],
+        );
+
+        let basis = minimum_cycle_basis(&graph, |&weight: &u32| weight);
+
+        assert_eq!(basis.len(), 2);
+
+        for cycle in &basis {
+            check(&graph, cycle);
+        }
+
+        // the square along with either triangle
+        let total: u32 = basis
+            .iter()
+            .flatten()
+            .map(|&edge| *graph.edge_value(edge).unwrap())
+            .sum();
+
+        assert_eq!(total, 11);
+
+        let mut square = indices(&basis[0]);
+
+        square.sort_unstable();
+
+        assert_eq!(square, [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,560 @@
+//! Dominators of directed graphs, such as control-flow graphs.
+//!
+//! Node `a` *dominates* node `b` if every path from the entry node to `b` goes through `a`.
+//! Post-dominators are dominators of [`Reversed`] graphs, with exit nodes used as entries.
+//!
+//! [`Reversed`]: graphs_core::adapters::Reversed
+
+use core::mem::take;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm, base::Base, indexed::NodeIndexed, limit::Limited,
+    neighbors::DirectedNeighbors,
+};
+
+/// Represents dominator trees, rooted at entry nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Dominators<N> {
+    /// The nodes reachable from the entry node in the pre-order of the dominator tree.
+    ///
+    /// The entry node comes first, and every subtree occupies the contiguous range.
+    pub order: Vec<N>,
+
+    /// The position of every node in the order, indexed by node indices.
+    ///
+    /// Node indices not present in the graph or unreachable from the entry node
+    /// are mapped to [`usize::LIMIT`].
+    pub positions: Vec<usize>,
+
+    /// The position of the immediate dominator of every node, indexed by positions.
+    ///
+    /// The entry node has no immediate dominator, so it is mapped to [`usize::LIMIT`].
+    pub parents: Vec<usize>,
+
+    /// The position right after the subtree of every node, indexed by positions.
+    pub ends: Vec<usize>,
+}
+
+impl<N> Dominators<N> {
+    /// Constructs [`Self`].
+    pub const fn new(
+        order: Vec<N>,
+        positions: Vec<usize>,
+        parents: Vec<usize>,
+        ends: Vec<usize>,
+    ) -> Self {
+        Self {
+            order,
+            positions,
+            parents,
+            ends,
+        }
+    }
+
+    /// Returns the number of nodes reachable from the entry node.
+    pub const fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Checks whether there are no nodes in the tree.
+    pub const fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns the position of the node with the given index, if it is reachable.
+    pub fn position_of(&self, index: usize) -> Option<usize> {
+        self.positions
+            .get(index)
+            .copied()
+            .filter(|position| !position.is_limit())
+    }
+
+    /// Checks whether the node with the given index is reachable from the entry node.
+    pub fn is_reachable(&self, index: usize) -> bool {
+        self.position_of(index).is_some()
+    }
+
+    /// Checks whether the node with index `one` dominates the node with index `two`.
+    ///
+    /// Every reachable node dominates itself, while unreachable nodes
+    /// neither dominate nor are dominated by any nodes.
+    pub fn dominates(&self, one: usize, two: usize) -> bool {
+        let (Some(one), Some(two)) = (self.position_of(one), self.position_of(two)) else {
+            return false;
+        };
+
+        one <= two && two < self.ends[one]
+    }
+
+    /// Checks whether the node with index `one` *strictly* dominates
+    /// the node with index `two`, that is, dominates it and is different from it.
+    pub fn strictly_dominates(&self, one: usize, two: usize) -> bool {
+        one != two && self.dominates(one, two)
+    }
+
+    /// Returns the nodes dominated by the node with the given index,
+    /// in the pre-order of the dominator tree, starting with the node itself.
+    ///
+    /// The slice returned is empty if the node is not reachable.
+    pub fn dominated_by(&self, index: usize) -> &[N] {
+        self.position_of(index)
+            .map_or(&[], |position| &self.order[position..self.ends[position]])
+    }
+}
+
+impl<N: Copy> Dominators<N> {
+    /// Returns the entry node, if there are any nodes in the tree.
+    pub fn entry(&self) -> Option<N> {
+        self.order.first().copied()
+    }
+
+    /// Returns the immediate dominator of the node with the given index, if any.
+    ///
+    /// The entry node and nodes unreachable from it have no immediate dominators.
+    pub fn idom_of(&self, index: usize) -> Option<N> {
+        let parent = self.parents[self.position_of(index)?];
+
+        self.order.get(parent).copied()
+    }
+
+    /// Returns the iterator over the dominators of the node with the given index,
+    /// from the node itself up to the entry node.
+    ///
+    /// The iterator is empty if the node is not reachable.
+    pub fn dominators_of(&self, index: usize) -> Dominating<'_, N> {
+        Dominating::new(self, self.position_of(index).unwrap_or(usize::LIMIT))
+    }
+
+    /// Returns the iterator over the children of the node with the given index
+    /// in the dominator tree, that is, the nodes it immediately dominates.
+    ///
+    /// The iterator is empty if the node is not reachable.
+    pub fn children_of(&self, index: usize) -> Children<'_, N> {
+        let (next, end) = self
+            .position_of(index)
+            .map_or((0, 0), |position| (position + 1, self.ends[position]));
+
+        Children::new(self, next, end)
+    }
+}
+
+/// Represents [`Dominators`] of the graph `G`.
+pub type DominatorsOf<G> = Dominators<<G as Base>::NodeId>;
+
+/// Represents iterators over dominators of nodes, going up the dominator tree.
+pub struct Dominating<'d, N> {
+    dominators: &'d Dominators<N>,
+    position: usize,
+}
+
+impl<'d, N> Dominating<'d, N> {
+    const fn new(dominators: &'d Dominators<N>, position: usize) -> Self {
+        Self {
+            dominators,
+            position,
+        }
+    }
+}
+
+impl<N: Copy> Iterator for Dominating<'_, N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.dominators.order.get(self.position).copied()?;
+
+        self.position = self.dominators.parents[self.position];
+
+        Some(node)
+    }
+}
+
+/// Represents iterators over children of nodes in dominator trees.
+pub struct Children<'d, N> {
+    dominators: &'d Dominators<N>,
+    next: usize,
+    end: usize,
+}
+
+impl<'d, N> Children<'d, N> {
+    const fn new(dominators: &'d Dominators<N>, next: usize, end: usize) -> Self {
+        Self {
+            dominators,
+            next,
+            end,
+        }
+    }
+}
+
+impl<N: Copy> Iterator for Children<'_, N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+
+        let node = self.dominators.order[self.next];
+
+        // skip the subtree of the child to get to the next one
+        self.next = self.dominators.ends[self.next];
+
+        Some(node)
+    }
+}
+
+/// Represents link-eval forests with path compression, used by the Lengauer–Tarjan algorithm.
+struct Forest {
+    ancestors: Vec<usize>,
+    labels: Vec<usize>,
+    path: Vec<usize>,
+}
+
+impl Forest {
+    fn new(count: usize) -> Self {
+        Self {
+            ancestors: vec![usize::LIMIT; count],
+            labels: (0..count).collect(),
+            path: Vec::new(),
+        }
+    }
+
+    fn link(&mut self, parent: usize, node: usize) {
+        self.ancestors[node] = parent;
+    }
+
+    /// Returns the node with the least semidominator on the path from the root (exclusive)
+    /// to the given node, compressing the path along the way.
+    fn eval(&mut self, node: usize, semis: &[usize]) -> usize {
+        if self.ancestors[node].is_limit() {
+            return node;
+        }
+
+        let mut current = node;
+
+        while !self.ancestors[self.ancestors[current]].is_limit() {
+            self.path.push(current);
+
+            current = self.ancestors[current];
+        }
+
+        // compress from the top, so that ancestors are always compressed first
+        while let Some(current) = self.path.pop() {
+            let ancestor = self.ancestors[current];
+
+            if semis[self.labels[ancestor]] < semis[self.labels[current]] {
+                self.labels[current] = self.labels[ancestor];
+            }
+
+            self.ancestors[current] = self.ancestors[ancestor];
+        }
+
+        self.labels[node]
+    }
+}
+
+/// Finds the dominator tree of the given graph, rooted at the given `entry` node.
+///
+/// This is the Lengauer–Tarjan algorithm with simple path compression, which runs in
+/// `O(E log V)` time. Only the nodes reachable from the entry node are included in the tree.
+///
+/// Post-dominators can be found by applying this function to [`Reversed`] graphs.
+///
+/// This function uses explicit stacks instead of recursion, so it can handle deep graphs.
+///
+/// [`Reversed`]: graphs_core::adapters::Reversed
+pub fn dominators<G>(graph: G, entry: G::NodeId) -> DominatorsOf<G>
+where
+    G: NodeIndexed + DirectedNeighbors,
+{
+    let bound = graph.node_bound();
+
+    // number nodes in the depth-first pre-order
+    let mut numbers = vec![usize::LIMIT; bound];
+
+    let mut nodes = vec![entry];
+    let mut parents = vec![usize::LIMIT];
+
+    numbers[graph.node_index(entry)] = 0;
+
+    let mut frames = vec![(0, graph.outgoing_neighbors(entry))];
+
+    while let Some((number, neighbors)) = frames.last_mut() {
+        let number = *number;
+
+        let Some(neighbor) = neighbors.next() else {
+            frames.pop();
+
+            continue;
+        };
+
+        let index = graph.node_index(neighbor);
+
+        if numbers[index].is_limit() {
+            let next = nodes.len();
+
+            numbers[index] = next;
+
+            nodes.push(neighbor);
+            parents.push(number);
+
+            frames.push((next, graph.outgoing_neighbors(neighbor)));
+        }
+    }
+
+    let count = nodes.len();
+
+    let mut semis: Vec<usize> = (0..count).collect();
+    let mut idoms = vec![usize::LIMIT; count];
+
+    let mut buckets = vec![Vec::new(); count];
+
+    let mut forest = Forest::new(count);
+
+    for node in (1..count).rev() {
+        for predecessor in graph.incoming_neighbors(nodes[node]) {
+            let other = numbers[graph.node_index(predecessor)];
+
+            // predecessors unreachable from the entry node do not matter
+            if other.is_limit() {
+                continue;
+            }
+
+            let least = forest.eval(other, &semis);
+
+            semis[node] = semis[node].min(semis[least]);
+        }
+
+        buckets[semis[node]].push(node);
+
+        let parent = parents[node];
+
+        forest.link(parent, node);
+
+        for other in take(&mut buckets[parent]) {
+            let least = forest.eval(other, &semis);
+
+            idoms[other] = if semis[least] < semis[other] {
+                least
+            } else {
+                parent
+            };
+        }
+    }
+
+    // immediate dominators of nodes are deferred to the ones of their ancestors
+    for node in 1..count {
+        if idoms[node] != semis[node] {
+            idoms[node] = idoms[idoms[node]];
+        }
+    }
+
+    // lay the dominator tree out in its pre-order, via children grouped by numbers
+    let mut starts = vec![0; count + 1];
+
+    for &idom in &idoms[1..] {
+        starts[idom + 1] += 1;
+    }
+
+    for number in 0..count {
+        starts[number + 1] += starts[number];
+    }
+
+    let mut filled = starts.clone();
+    let mut children = vec![0; count.saturating_sub(1)];
+
+    for (node, &idom) in idoms.iter().enumerate().skip(1) {
+        children[filled[idom]] = node;
+
+        filled[idom] += 1;
+    }
+
+    let mut places = vec![usize::LIMIT; count];
+
+    let mut order = Vec::with_capacity(count);
+    let mut stack = vec![0];
+
+    while let Some(node) = stack.pop() {
+        places[node] = order.len();
+
+        order.push(node);
+
+        stack.extend(children[starts[node]..starts[node + 1]].iter().rev());
+    }
+
+    let mut positions = vec![usize::LIMIT; bound];
+    let mut tree = vec![usize::LIMIT; count];
+
+    for (position, &node) in order.iter().enumerate() {
+        positions[graph.node_index(nodes[node])] = position;
+
+        if let Some(&idom) = idoms.get(node).filter(|idom| !idom.is_limit()) {
+            tree[position] = places[idom];
+        }
+    }
+
+    let mut ends: Vec<usize> = (1..=count).collect();
+
+    // children come after their parents, so subtrees are complete when visited backwards
+    for position in (1..count).rev() {
+        let parent = tree[position];
+
+        ends[parent] = ends[parent].max(ends[position]);
+    }
+
+    let order = order.into_iter().map(|node| nodes[node]).collect();
+
+    Dominators::new(order, positions, tree, ends)
+}
+
+/// Finds dominance frontiers of nodes in the given graph, using its `dominators`.
+///
+/// The dominance frontier of node `a` contains nodes `b` such that `a` dominates
+/// some predecessor of `b`, but does not strictly dominate `b` itself;
+/// these are the nodes where SSA construction places phi functions.
+///
+/// Frontiers are indexed by node indices and are empty for unreachable nodes.
+/// This is the algorithm by Cooper, Harvey and Kennedy, which runs in `O(E + F)` time,
+/// where `F` is the total size of frontiers.
+pub fn frontiers<G>(graph: G, dominators: &DominatorsOf<G>) -> Vec<Vec<G::NodeId>>
+where
+    G: NodeIndexed + DirectedNeighbors,
+{
+    let mut frontiers = vec![Vec::new(); graph.node_bound()];
+
+    for (position, &node) in dominators.order.iter().enumerate() {
+        let idom = dominators.parents[position];
+
+        for predecessor in graph.incoming_neighbors(node) {
+            let Some(mut runner) = dominators.position_of(graph.node_index(predecessor)) else {
+                continue;
+            };
+
+            // walk up from the predecessor until reaching the immediate dominator of the node
+            while runner != idom {
+                let frontier: &mut Vec<_> =
+                    &mut frontiers[graph.node_index(dominators.order[runner])];
+
+                // nodes are handled one at a time, so duplicates can only come last
+                if frontier.last() != Some(&node) {
+                    frontier.push(node);
+                }
+
+                runner = dominators.parents[runner];
+            }
+        }
+    }
+
+    frontiers
+}
+
+/// Represents the Lengauer–Tarjan dominator algorithm.
+///
+/// See [`dominators`] for more information.
+pub struct LengauerTarjan<N> {
+    entry: N,
+}
+
+impl<N> LengauerTarjan<N> {
+    /// Constructs [`Self`] with the given `entry` node.
+    pub const fn new(entry: N) -> Self {
+        Self { entry }
+    }
+}
+
+impl<G> Algorithm<G> for LengauerTarjan<G::NodeId>
+where
+    G: NodeIndexed + DirectedNeighbors,
+{
+    type Output = DominatorsOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        dominators(graph, self.entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::{adapters::Reversed, id::NodeId};
+
+    use crate::testing::DiGraph;
+
+    fn graph() -> DiGraph {
+        DiGraph::unweighted(6, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 1), (5, 3)])
+    }
+
+    fn indices(nodes: &[NodeId<usize>]) -> Vec<usize> {
+        let mut indices: Vec<_> = nodes.iter().map(|node| node.get()).collect();
+
+        indices.sort_unstable();
+
+        indices
+    }
+
+    #[test]
+    fn tree() {
+        let graph = graph();
+
+        let dominators = dominators(&graph, NodeId::new(0));
+
+        assert_eq!(dominators.len(), 5);
+        assert_eq!(dominators.entry(), Some(NodeId::new(0)));
+
+        let idoms: Vec<_> = (0..6)
+            .map(|index| dominators.idom_of(index).map(|node| node.get()))
+            .collect();
+
+        assert_eq!(idoms, [None, Some(0), Some(0), Some(0), Some(3), None]);
+
+        assert!(dominators.dominates(3, 4));
+        assert!(dominators.dominates(4, 4));
+        assert!(!dominators.strictly_dominates(4, 4));
+        assert!(!dominators.dominates(1, 3));
+        assert!(!dominators.dominates(5, 5));
+        assert!(!dominators.is_reachable(5));
+
+        assert_eq!(indices(dominators.dominated_by(3)), [3, 4]);
+        assert_eq!(indices(dominators.dominated_by(0)), [0, 1, 2, 3, 4]);
+
+        let chain: Vec<_> = dominators.dominators_of(4).map(|node| node.get()).collect();
+
+        assert_eq!(chain, [4, 3, 0]);
+
+        let children: Vec<_> = dominators.children_of(0).collect();
+
+        assert_eq!(indices(&children), [1, 2, 3]);
+    }
+
+    #[test]
+    fn frontier() {
+        let graph = graph();
+
+        let dominators = dominators(&graph, NodeId::new(0));
+
+        let frontiers: Vec<_> = frontiers(&graph, &dominators)
+            .iter()
+            .map(|frontier| indices(frontier))
+            .collect();
+
+        let empty = Vec::new();
+
+        assert_eq!(
+            frontiers,
+            [empty.clone(), vec![3], vec![3], vec![1], vec![1], empty]
+        );
+    }
+
+    #[test]
+    fn post_dominators() {
+        let graph = DiGraph::unweighted(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+
+        let dominators = dominators(Reversed::new(&graph), NodeId::new(3));
+
+        assert_eq!(dominators.idom_of(0), Some(NodeId::new(3)));
+        assert_eq!(dominators.idom_of(1), Some(NodeId::new(3)));
+    }
+}
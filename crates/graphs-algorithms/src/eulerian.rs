@@ -0,0 +1,439 @@
+//! Eulerian circuits and paths of directed and undirected multigraphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    kinds::Kind,
+    limit::Limited,
+};
+use graphs_union_find::vec::UnionFind;
+use thiserror::Error;
+
+use crate::{EDGE, compact::compact};
+
+/// Represents trails, that is, walks using every edge at most once.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Trail<N, E> {
+    /// The nodes of the trail, in order.
+    ///
+    /// There is one more node than there are edges, unless there are no edges,
+    /// in which case there are no nodes either.
+    pub nodes: Vec<N>,
+
+    /// The edges of the trail, in order.
+    ///
+    /// Every edge goes from the node at the same position to the node following it.
+    pub edges: Vec<E>,
+}
+
+impl<N, E> Trail<N, E> {
+    /// Constructs [`Self`].
+    pub const fn new(nodes: Vec<N>, edges: Vec<E>) -> Self {
+        Self { nodes, edges }
+    }
+
+    /// Returns the number of edges in the trail.
+    pub const fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Checks whether the trail has no edges.
+    pub const fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+/// Represents [`Trail`] of the graph `G`.
+pub type TrailOf<G> = Trail<<G as Base>::NodeId, <G as Base>::EdgeId>;
+
+/// Represents errors returned when graphs do not have Eulerian circuits or paths.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum NotEulerian<N> {
+    /// Some edges can not be reached from the others, even ignoring their directions.
+    #[error("edges are disconnected, as nodes `{one}` and `{two}` are not connected")]
+    Disconnected {
+        /// The node with edges in one connected component.
+        one: N,
+
+        /// The node with edges in the other connected component.
+        two: N,
+    },
+
+    /// Too many nodes of undirected graphs have odd degrees; self-loops count twice.
+    ///
+    /// Circuits require none of them, while paths allow two, which are their endpoints.
+    #[error("graph contains {} nodes of odd degree", .nodes.len())]
+    OddDegrees {
+        /// All nodes of odd degree.
+        nodes: Vec<N>,
+    },
+
+    /// Too many nodes of directed graphs have different in-degrees and out-degrees.
+    ///
+    /// Circuits require none of them, while paths allow one node with out-degree exceeding
+    /// its in-degree by one, which is the start, and one node with in-degree exceeding
+    /// its out-degree by one, which is the end.
+    #[error("graph contains {} nodes of unequal in-degree and out-degree", .nodes.len())]
+    Unbalanced {
+        /// All nodes of unequal in-degree and out-degree.
+        nodes: Vec<N>,
+    },
+}
+
+/// Represents [`NotEulerian`] errors of the graph `G`.
+pub type NotEulerianIn<G> = NotEulerian<<G as Base>::NodeId>;
+
+/// Represents results of [`eulerian_circuit`] and [`eulerian_path`].
+pub type Output<G> = Result<TrailOf<G>, NotEulerianIn<G>>;
+
+/// Finds Eulerian trails of the given graph, which are circuits unless `open`.
+fn trail<G>(graph: G, open: bool) -> Output<G>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    let (nodes, positions) = compact(&graph);
+
+    let count = nodes.len();
+
+    let mut edges = Vec::new();
+
+    // arcs going out of every node, along with the edges they belong to
+    let mut starts = vec![0; count + 1];
+
+    let mut outgoing = vec![0usize; count];
+    let mut incoming = vec![0usize; count];
+
+    let mut union_find = UnionFind::new(count);
+
+    for edge in graph.edge_identifiers() {
+        let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+        let source = positions[graph.node_index(one)];
+        let target = positions[graph.node_index(two)];
+
+        edges.push((edge, source, target));
+
+        outgoing[source] += 1;
+        incoming[target] += 1;
+
+        starts[source + 1] += 1;
+
+        if !G::Kind::DIRECTED {
+            starts[target + 1] += 1;
+        }
+
+        union_find.union(source, target);
+    }
+
+    if G::Kind::DIRECTED {
+        let unbalanced: Vec<usize> = (0..count)
+            .filter(|&node| outgoing[node] != incoming[node])
+            .collect();
+
+        let allowed = match *unbalanced.as_slice() {
+            [] => true,
+            [one, two] if open => {
+                let surplus = |node: usize| outgoing[node] as isize - incoming[node] as isize;
+
+                surplus(one) + surplus(two) == 0 && surplus(one).abs() == 1
+            }
+            _ => false,
+        };
+
+        if !allowed {
+            let nodes = unbalanced.into_iter().map(|node| nodes[node]).collect();
+
+            return Err(NotEulerian::Unbalanced { nodes });
+        }
+    } else {
+        // every edge adds one to the degrees of both of its endpoints, loops included
+        let odd: Vec<usize> = (0..count)
+            .filter(|&node| (outgoing[node] + incoming[node]) % 2 == 1)
+            .collect();
+
+        if !(odd.is_empty() || open && odd.len() == 2) {
+            let nodes = odd.into_iter().map(|node| nodes[node]).collect();
+
+            return Err(NotEulerian::OddDegrees { nodes });
+        }
+    }
+
+    let degree = |node: usize| outgoing[node] + incoming[node];
+
+    let Some(first) = (0..count).find(|&node| degree(node) > 0) else {
+        return Ok(Trail::new(Vec::new(), Vec::new()));
+    };
+
+    for node in first + 1..count {
+        if degree(node) > 0 && !union_find.same_set_mut(first, node) {
+            return Err(NotEulerian::Disconnected {
+                one: nodes[first],
+                two: nodes[node],
+            });
+        }
+    }
+
+    // paths start at the node with surplus of outgoing edges or at either node of odd degree
+    let start = if G::Kind::DIRECTED {
+        (0..count).find(|&node| outgoing[node] > incoming[node])
+    } else {
+        (0..count).find(|&node| degree(node) % 2 == 1)
+    }
+    .unwrap_or(first);
+
+    for node in 0..count {
+        starts[node + 1] += starts[node];
+    }
+
+    let mut next = starts.clone();
+    let mut arcs = vec![(0, 0); starts[count]];
+
+    for (number, &(_, source, target)) in edges.iter().enumerate() {
+        arcs[next[source]] = (number, target);
+        next[source] += 1;
+
+        if !G::Kind::DIRECTED {
+            arcs[next[target]] = (number, source);
+            next[target] += 1;
+        }
+    }
+
+    let ends = next;
+
+    // Hierholzer's algorithm, splicing circuits in as the stack unwinds
+    let mut used = vec![false; edges.len()];
+    let mut pointers = starts;
+
+    let mut stack = vec![(start, usize::LIMIT)];
+
+    let mut trail_nodes = Vec::with_capacity(edges.len() + 1);
+    let mut trail_edges = Vec::with_capacity(edges.len());
+
+    while let Some(&(node, via)) = stack.last() {
+        let end = ends[node];
+
+        while pointers[node] < end && used[arcs[pointers[node]].0] {
+            pointers[node] += 1;
+        }
+
+        if pointers[node] < end {
+            let (number, other) = arcs[pointers[node]];
+
+            used[number] = true;
+
+            pointers[node] += 1;
+
+            stack.push((other, number));
+        } else {
+            stack.pop();
+
+            trail_nodes.push(nodes[node]);
+
+            if !via.is_limit() {
+                trail_edges.push(edges[via].0);
+            }
+        }
+    }
+
+    trail_nodes.reverse();
+    trail_edges.reverse();
+
+    Ok(Trail::new(trail_nodes, trail_edges))
+}
+
+/// Finds some Eulerian circuit of the given graph, that is, the closed trail
+/// using every edge exactly once, via Hierholzer's algorithm.
+///
+/// Both directed and undirected graphs are supported, including multigraphs and pseudographs,
+/// that is, parallel edges and self-loops. The circuit starts at the first node
+/// (in the order of node identifiers) with edges, and nodes without edges are ignored.
+/// Graphs without edges have empty circuits.
+///
+/// This takes `O(V + E)` time.
+///
+/// # Errors
+///
+/// Returns [`NotEulerian`] describing why no circuit exists: either some nodes have
+/// odd degrees or unequal in-degrees and out-degrees, or edges are disconnected.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn eulerian_circuit<G>(graph: G) -> Output<G>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    trail(graph, false)
+}
+
+/// Finds some Eulerian path of the given graph, that is, the trail
+/// using every edge exactly once, via Hierholzer's algorithm.
+///
+/// If the graph has Eulerian circuits, one of them is returned,
+/// as in [`eulerian_circuit`]. Otherwise, the path starts at the node with out-degree
+/// exceeding its in-degree in directed graphs, or at the first node (in the order
+/// of node identifiers) of odd degree in undirected ones.
+///
+/// This takes `O(V + E)` time.
+///
+/// # Errors
+///
+/// Returns [`NotEulerian`] describing why no path exists: either too many nodes have
+/// odd degrees or unequal in-degrees and out-degrees, or edges are disconnected.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn eulerian_path<G>(graph: G) -> Output<G>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    trail(graph, true)
+}
+
+/// Represents Hierholzer's algorithm for finding Eulerian circuits.
+///
+/// See [`eulerian_circuit`] for more information.
+pub struct EulerianCircuit;
+
+impl<G> Algorithm<G> for EulerianCircuit
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = Output<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        eulerian_circuit(graph)
+    }
+}
+
+/// Represents Hierholzer's algorithm for finding Eulerian paths.
+///
+/// See [`eulerian_path`] for more information.
+pub struct EulerianPath;
+
+impl<G> Algorithm<G> for EulerianPath
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = Output<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        eulerian_path(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, Edge, Node, UnGraph};
+
+    /// Checks that the given trail is Eulerian, returning the indices of its nodes.
+    fn check<G>(graph: &G, trail: &Trail<Node, Edge>) -> Vec<usize>
+    where
+        G: Base<NodeId = Node, EdgeId = Edge> + EdgeIdentifiers + Endpoints,
+    {
+        assert_eq!(trail.nodes.len(), trail.len() + 1);
+
+        let mut edges: Vec<_> = trail.edges.iter().map(|edge| edge.get()).collect();
+
+        edges.sort_unstable();
+
+        let expected: Vec<_> = graph.edge_identifiers().map(|edge| edge.get()).collect();
+
+        assert_eq!(edges, expected);
+
+        for (index, &edge) in trail.edges.iter().enumerate() {
+            let (source, target) = graph.endpoints(edge).unwrap();
+
+            let (from, to) = (trail.nodes[index], trail.nodes[index + 1]);
+
+            if G::Kind::DIRECTED {
+                assert_eq!((source, target), (from, to));
+            } else {
+                assert!((source, target) == (from, to) || (source, target) == (to, from));
+            }
+        }
+
+        trail.nodes.iter().map(|node| node.get()).collect()
+    }
+
+    #[test]
+    fn directed_circuit() {
+        let graph = DiGraph::unweighted(4, &[(0, 1), (1, 2), (2, 0), (0, 1), (1, 0), (0, 0)]);
+
+        let circuit = eulerian_circuit(&graph).unwrap();
+
+        let nodes = check(&graph, &circuit);
+
+        assert_eq!(nodes.first(), Some(&0));
+        assert_eq!(nodes.last(), Some(&0));
+
+        assert_eq!(eulerian_path(&graph), Ok(circuit));
+    }
+
+    #[test]
+    fn undirected_path() {
+        let graph = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 0), (2, 3), (1, 1)]);
+
+        let path = eulerian_path(&graph).unwrap();
+
+        let nodes = check(&graph, &path);
+
+        assert_eq!(nodes.first(), Some(&2));
+        assert_eq!(nodes.last(), Some(&3));
+
+        let Err(NotEulerian::OddDegrees { nodes }) = eulerian_circuit(&graph) else {
+            panic!("expected odd degrees");
+        };
+
+        let nodes: Vec<_> = nodes.iter().map(|node| node.get()).collect();
+
+        assert_eq!(nodes, [2, 3]);
+    }
+
+    #[test]
+    fn unbalanced() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (0, 2)]);
+
+        let Err(NotEulerian::Unbalanced { nodes }) = eulerian_path(&graph) else {
+            panic!("expected unbalanced nodes");
+        };
+
+        let nodes: Vec<_> = nodes.iter().map(|node| node.get()).collect();
+
+        assert_eq!(nodes, [0, 1, 2]);
+
+        let path = eulerian_path(&DiGraph::unweighted(3, &[(1, 2), (0, 1)])).unwrap();
+
+        let nodes: Vec<_> = path.nodes.iter().map(|node| node.get()).collect();
+
+        assert_eq!(nodes, [0, 1, 2]);
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = UnGraph::unweighted(5, &[(0, 1), (1, 0), (2, 3), (3, 2)]);
+
+        let Err(NotEulerian::Disconnected { one, two }) = eulerian_circuit(&graph) else {
+            panic!("expected disconnected edges");
+        };
+
+        assert_eq!((one.get(), two.get()), (0, 2));
+    }
+
+    #[test]
+    fn empty() {
+        let graph = UnGraph::unweighted(3, &[]);
+
+        assert!(eulerian_circuit(&graph).unwrap().is_empty());
+        assert!(eulerian_path(&graph).unwrap().nodes.is_empty());
+    }
+}
@@ -0,0 +1,447 @@
+//! Feedback arc sets and feedback vertex sets of directed graphs.
+
+use core::cmp::Reverse;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Directed},
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+};
+
+use crate::{EDGE, compact::compact};
+
+/// Represents directed graphs with nodes referred to by their positions,
+/// and both outgoing and incoming arcs stored per node; self-loops are stored separately.
+struct Network<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<(E, usize, usize)>,
+    starts: Vec<usize>,
+    targets: Vec<usize>,
+    reverse_starts: Vec<usize>,
+    sources: Vec<usize>,
+    loops: Vec<bool>,
+}
+
+impl<N: Copy, E: Copy> Network<N, E> {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: Base<NodeId = N, EdgeId = E>
+            + NodeIdentifiers
+            + NodeIndexed
+            + EdgeIdentifiers
+            + Endpoints,
+    {
+        let (nodes, positions) = compact(graph);
+
+        let count = nodes.len();
+
+        let mut edges = Vec::new();
+        let mut loops = vec![false; count];
+
+        let mut starts = vec![0; count + 1];
+        let mut reverse_starts = vec![0; count + 1];
+
+        for edge in graph.edge_identifiers() {
+            let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+            let source = positions[graph.node_index(one)];
+            let target = positions[graph.node_index(two)];
+
+            edges.push((edge, source, target));
+
+            if source == target {
+                loops[source] = true;
+            } else {
+                starts[source + 1] += 1;
+                reverse_starts[target + 1] += 1;
+            }
+        }
+
+        for node in 0..count {
+            starts[node + 1] += starts[node];
+            reverse_starts[node + 1] += reverse_starts[node];
+        }
+
+        let mut next = starts.clone();
+        let mut reverse_next = reverse_starts.clone();
+
+        let mut targets = vec![0; starts[count]];
+        let mut sources = vec![0; reverse_starts[count]];
+
+        for &(_, source, target) in &edges {
+            if source != target {
+                targets[next[source]] = target;
+                next[source] += 1;
+
+                sources[reverse_next[target]] = source;
+                reverse_next[target] += 1;
+            }
+        }
+
+        Self {
+            nodes,
+            edges,
+            starts,
+            targets,
+            reverse_starts,
+            sources,
+            loops,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn targets(&self, node: usize) -> &[usize] {
+        &self.targets[self.starts[node]..self.starts[node + 1]]
+    }
+
+    fn sources(&self, node: usize) -> &[usize] {
+        &self.sources[self.reverse_starts[node]..self.reverse_starts[node + 1]]
+    }
+}
+
+/// Represents how nodes are removed during elimination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Removal {
+    /// The node has no outgoing edges left.
+    Sink,
+
+    /// The node has no incoming edges left.
+    Source,
+
+    /// The node was chosen by its key.
+    Chosen,
+}
+
+/// Removes nodes one by one, preferring sinks, then sources, and then nodes
+/// of the largest keys, computed from their remaining out-degrees and in-degrees,
+/// with ties broken in favor of earlier nodes.
+///
+/// The `forced` nodes are removed as chosen before anything else.
+fn eliminate<N, E, K, F, V>(network: &Network<N, E>, forced: &[usize], mut key: F, mut visit: V)
+where
+    N: Copy,
+    E: Copy,
+    K: Ord,
+    F: FnMut(usize, usize) -> K,
+    V: FnMut(usize, Removal),
+{
+    let count = network.count();
+
+    let mut outgoing: Vec<usize> = (0..count).map(|node| network.targets(node).len()).collect();
+
+    let mut incoming: Vec<usize> = (0..count).map(|node| network.sources(node).len()).collect();
+
+    let mut removed = vec![false; count];
+
+    let mut sinks: Vec<usize> = (0..count)
+        .rev()
+        .filter(|&node| outgoing[node] == 0)
+        .collect();
+    let mut sources: Vec<usize> = (0..count)
+        .rev()
+        .filter(|&node| incoming[node] == 0)
+        .collect();
+
+    let mut heap: BinaryHeap<_> = (0..count)
+        .map(|node| (key(outgoing[node], incoming[node]), Reverse(node)))
+        .collect();
+
+    let mut pending = forced.iter().copied();
+
+    loop {
+        let (node, removal) = if let Some(node) = pending.next() {
+            (node, Removal::Chosen)
+        } else if let Some(node) = sinks.pop() {
+            (node, Removal::Sink)
+        } else if let Some(node) = sources.pop() {
+            (node, Removal::Source)
+        } else if let Some((value, Reverse(node))) = heap.pop() {
+            // skip outdated entries
+            if removed[node] || value != key(outgoing[node], incoming[node]) {
+                continue;
+            }
+
+            (node, Removal::Chosen)
+        } else {
+            break;
+        };
+
+        if removed[node] {
+            continue;
+        }
+
+        removed[node] = true;
+
+        visit(node, removal);
+
+        for &other in network.targets(node) {
+            if removed[other] {
+                continue;
+            }
+
+            incoming[other] -= 1;
+
+            if incoming[other] == 0 {
+                sources.push(other);
+            }
+
+            heap.push((key(outgoing[other], incoming[other]), Reverse(other)));
+        }
+
+        for &other in network.sources(node) {
+            if removed[other] {
+                continue;
+            }
+
+            outgoing[other] -= 1;
+
+            if outgoing[other] == 0 {
+                sinks.push(other);
+            }
+
+            heap.push((key(outgoing[other], incoming[other]), Reverse(other)));
+        }
+    }
+}
+
+/// Finds some small feedback arc set of the given graph, that is, edges removing which
+/// makes the graph acyclic, using the heuristic of Eades, Lin and Smyth.
+///
+/// Nodes are ordered by repeatedly removing sinks, which are placed at the end, sources,
+/// which are placed at the start, and otherwise nodes maximizing the difference between
+/// their out-degrees and in-degrees, which are placed at the start too. Edges going backwards
+/// in this order form the feedback arc set, along with self-loops. In connected graphs
+/// without cycles of one or two edges, there are at most `E / 2 - V / 6` such edges.
+///
+/// Edges are returned in the order of their identifiers. This takes `O((V + E) log V)` time.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn feedback_arc_set<G>(graph: G) -> Vec<G::EdgeId>
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    let network = Network::new(&graph);
+
+    let count = network.count();
+
+    let mut first = Vec::with_capacity(count);
+    let mut last = Vec::new();
+
+    eliminate(
+        &network,
+        &[],
+        |outgoing, incoming| outgoing as isize - incoming as isize,
+        |node, removal| match removal {
+            Removal::Sink => last.push(node),
+            Removal::Source | Removal::Chosen => first.push(node),
+        },
+    );
+
+    // sinks are removed from the end, so they come in reverse
+    first.extend(last.into_iter().rev());
+
+    let mut positions = vec![0; count];
+
+    for (position, &node) in first.iter().enumerate() {
+        positions[node] = position;
+    }
+
+    network
+        .edges
+        .iter()
+        .filter(|&&(_, source, target)| positions[source] >= positions[target])
+        .map(|&(edge, _, _)| edge)
+        .collect()
+}
+
+/// Finds some small feedback vertex set of the given graph, that is, nodes removing which
+/// (along with their edges) makes the graph acyclic, using the greedy heuristic.
+///
+/// Nodes with self-loops are always included. Then nodes that can not be on any cycles
+/// anymore, that is, sinks and sources, are removed repeatedly, and otherwise the node
+/// maximizing the product of its out-degree and in-degree is included and removed.
+///
+/// Nodes are returned in the order of their identifiers. This takes `O((V + E) log V)` time.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn feedback_vertex_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    let network = Network::new(&graph);
+
+    let count = network.count();
+
+    let forced: Vec<usize> = (0..count).filter(|&node| network.loops[node]).collect();
+
+    let mut chosen = vec![false; count];
+
+    eliminate(
+        &network,
+        &forced,
+        |outgoing, incoming| (outgoing * incoming, outgoing + incoming),
+        |node, removal| {
+            if removal == Removal::Chosen {
+                chosen[node] = true;
+            }
+        },
+    );
+
+    (0..count)
+        .filter(|&node| chosen[node])
+        .map(|node| network.nodes[node])
+        .collect()
+}
+
+/// Represents the Eades-Lin-Smyth heuristic for finding feedback arc sets.
+///
+/// See [`feedback_arc_set`] for more information.
+pub struct EadesLinSmyth;
+
+impl<G> Algorithm<G> for EadesLinSmyth
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = Vec<G::EdgeId>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        feedback_arc_set(graph)
+    }
+}
+
+/// Represents the greedy heuristic for finding feedback vertex sets.
+///
+/// See [`feedback_vertex_set`] for more information.
+pub struct FeedbackVertexSet;
+
+impl<G> Algorithm<G> for FeedbackVertexSet
+where
+    G: Directed + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    type Output = Vec<G::NodeId>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        feedback_vertex_set(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::cycles;
+
+    use crate::{random::Random, testing::DiGraph};
+
+    /// Builds the graph with the given number of nodes and random edges, loops included.
+    fn random(random: &mut Random, count: usize, size: usize) -> Vec<(usize, usize)> {
+        (0..size)
+            .map(|_| (random.below(count), random.below(count)))
+            .collect()
+    }
+
+    /// Checks that removing the feedback arc set of the given graph leaves it acyclic.
+    fn check_arcs(count: usize, edges: &[(usize, usize)]) -> usize {
+        let graph = DiGraph::unweighted(count, edges);
+
+        let removed: Vec<_> = feedback_arc_set(&graph)
+            .iter()
+            .map(|edge| edge.get())
+            .collect();
+
+        let kept: Vec<_> = edges
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !removed.contains(index))
+            .map(|(_, &edge)| edge)
+            .collect();
+
+        assert!(cycles::find(&DiGraph::unweighted(count, &kept)).is_none());
+
+        removed.len()
+    }
+
+    /// Checks that removing the feedback vertex set of the given graph leaves it acyclic.
+    fn check_nodes(count: usize, edges: &[(usize, usize)]) -> usize {
+        let graph = DiGraph::unweighted(count, edges);
+
+        let removed: Vec<_> = feedback_vertex_set(&graph)
+            .iter()
+            .map(|node| node.get())
+            .collect();
+
+        let kept: Vec<_> = edges
+            .iter()
+            .filter(|(source, target)| !removed.contains(source) && !removed.contains(target))
+            .copied()
+            .collect();
+
+        assert!(cycles::find(&DiGraph::unweighted(count, &kept)).is_none());
+
+        removed.len()
+    }
+
+    #[test]
+    fn acyclic() {
+        let edges = [(0, 1), (1, 2), (0, 2), (2, 3)];
+
+        assert_eq!(check_arcs(4, &edges), 0);
+        assert_eq!(check_nodes(4, &edges), 0);
+    }
+
+    #[test]
+    fn small() {
+        // two triangles sharing the node 2, with the self-loop at 4
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 4)];
+
+        let graph = DiGraph::unweighted(5, &edges);
+
+        let arcs: Vec<_> = feedback_arc_set(&graph)
+            .iter()
+            .map(|edge| edge.get())
+            .collect();
+
+        assert!(arcs.contains(&6));
+        assert!(arcs.is_sorted());
+
+        let nodes: Vec<_> = feedback_vertex_set(&graph)
+            .iter()
+            .map(|node| node.get())
+            .collect();
+
+        // the loop forces the node 4, after which only the first triangle is left
+        assert_eq!(nodes.len(), 2);
+        assert!(nodes.contains(&4));
+        assert!(nodes.is_sorted());
+
+        check_arcs(5, &edges);
+        check_nodes(5, &edges);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut generator = Random::new(5);
+
+        for _ in 0..200 {
+            let edges = random(&mut generator, 8, 16);
+
+            check_arcs(8, &edges);
+            check_nodes(8, &edges);
+        }
+    }
+}
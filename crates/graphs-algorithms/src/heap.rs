@@ -0,0 +1,42 @@
+//! Entries of binary heaps keyed by weights.
+
+use core::cmp::Ordering;
+
+use crate::weight::Weight;
+
+/// Represents entries of binary heaps, ordered by their keys only.
+///
+/// Heaps pop entries with the largest keys first; wrap entries in [`Reverse`]
+/// to pop the ones with the smallest keys first instead.
+///
+/// [`Reverse`]: core::cmp::Reverse
+pub(crate) struct Entry<K, T> {
+    pub(crate) key: K,
+    pub(crate) value: T,
+}
+
+impl<K, T> Entry<K, T> {
+    pub(crate) const fn new(key: K, value: T) -> Self {
+        Self { key, value }
+    }
+}
+
+impl<K: Weight, T> PartialEq for Entry<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<K: Weight, T> Eq for Entry<K, T> {}
+
+impl<K: Weight, T> PartialOrd for Entry<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Weight, T> Ord for Entry<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.compare(&other.key)
+    }
+}
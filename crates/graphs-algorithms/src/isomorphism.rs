@@ -0,0 +1,848 @@
+//! Isomorphisms, subgraph isomorphisms and monomorphisms of graphs.
+
+use core::cmp::Reverse;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    data::{Data, DataRef},
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    kinds::Kind,
+    limit::Limited,
+};
+
+use crate::{EDGE, NODE, compact::compact};
+
+/// Represents kinds of mappings from pattern nodes to target nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Mode {
+    /// Bijections preserving both edges and non-edges.
+    #[default]
+    Isomorphism,
+
+    /// Injections preserving both edges and non-edges,
+    /// that is, isomorphisms onto induced subgraphs of the target.
+    SubgraphIsomorphism,
+
+    /// Injections preserving edges, that is, isomorphisms onto (not necessarily induced)
+    /// subgraphs of the target.
+    Monomorphism,
+}
+
+impl Mode {
+    /// Checks whether the mode requires non-edges to be preserved.
+    pub const fn is_induced(self) -> bool {
+        matches!(self, Self::Isomorphism | Self::SubgraphIsomorphism)
+    }
+}
+
+/// Represents mappings, that is, pairs of pattern nodes and target nodes they are mapped to.
+pub type Mapping<N, M> = Vec<(N, M)>;
+
+/// Represents [`Mapping`] of the pattern graph `P` into the target graph `T`.
+pub type MappingOf<P, T> = Mapping<<P as Base>::NodeId, <T as Base>::NodeId>;
+
+/// Represents equivalence functions accepting everything.
+pub type Always<A, B> = fn(&A, &B) -> bool;
+
+/// Represents iterators over mappings ignoring node and edge values.
+pub type AnyMappings<P, T> = Mappings<
+    P,
+    T,
+    Always<<P as Data>::NodeValue, <T as Data>::NodeValue>,
+    Always<<P as Data>::EdgeValue, <T as Data>::EdgeValue>,
+>;
+
+/// Represents graphs with nodes referred to by their positions and edges stored per node.
+struct Structure<N, E> {
+    nodes: Vec<N>,
+    outgoing: Vec<Vec<(usize, E)>>,
+    incoming: Vec<Vec<(usize, E)>>,
+    size: usize,
+}
+
+impl<N, E: Copy> Structure<N, E> {
+    /// Returns the number of nodes.
+    fn count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edge endpoints at the given node.
+    fn degree(&self, node: usize) -> usize {
+        self.outgoing[node].len() + self.incoming[node].len()
+    }
+
+    /// Returns all edges of the given node, in either direction.
+    fn arcs(&self, node: usize) -> impl Iterator<Item = &(usize, E)> {
+        self.outgoing[node].iter().chain(&self.incoming[node])
+    }
+}
+
+/// Builds the [`Structure`] of the given graph.
+///
+/// Undirected edges are stored as outgoing ones at both endpoints (loops are stored once),
+/// while directed edges are stored as outgoing at sources and incoming at targets.
+fn structure<G>(graph: &G) -> Structure<G::NodeId, G::EdgeId>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints,
+{
+    let (nodes, positions) = compact(graph);
+
+    let count = nodes.len();
+
+    let mut outgoing = vec![Vec::new(); count];
+    let mut incoming = vec![Vec::new(); count];
+
+    let mut size = 0;
+
+    for edge in graph.edge_identifiers() {
+        let (source, target) = graph.endpoints(edge).expect(EDGE);
+
+        let source = positions[graph.node_index(source)];
+        let target = positions[graph.node_index(target)];
+
+        outgoing[source].push((target, edge));
+
+        if G::Kind::DIRECTED {
+            incoming[target].push((source, edge));
+        } else if source != target {
+            outgoing[target].push((source, edge));
+        }
+
+        size += 1;
+    }
+
+    Structure {
+        nodes,
+        outgoing,
+        incoming,
+        size,
+    }
+}
+
+/// Computes the order in which pattern nodes are matched.
+///
+/// Every connected component is traversed breadth-first starting from its node
+/// of the largest degree; within every level, the node with the most edges to the nodes
+/// ordered so far is picked next, preferring nodes of larger degrees.
+fn order<N, E: Copy>(pattern: &Structure<N, E>) -> Vec<usize> {
+    let count = pattern.count();
+
+    let mut roots: Vec<usize> = (0..count).collect();
+
+    roots.sort_by_key(|&node| Reverse(pattern.degree(node)));
+
+    let mut order = Vec::with_capacity(count);
+
+    let mut ordered = vec![false; count];
+    let mut connections = vec![0; count];
+    let mut levels = vec![usize::LIMIT; count];
+
+    let mut level = 0;
+
+    let mut current = Vec::new();
+    let mut next = Vec::new();
+
+    let mut heap = BinaryHeap::new();
+
+    for root in roots {
+        if !levels[root].is_limit() {
+            continue;
+        }
+
+        levels[root] = level;
+
+        current.push(root);
+
+        while !current.is_empty() {
+            heap.extend(
+                current
+                    .drain(..)
+                    .map(|node| (connections[node], pattern.degree(node), Reverse(node))),
+            );
+
+            while let Some((connected, _, Reverse(node))) = heap.pop() {
+                // skip outdated entries
+                if ordered[node] || connected != connections[node] {
+                    continue;
+                }
+
+                ordered[node] = true;
+
+                order.push(node);
+
+                for &(other, _) in pattern.arcs(node) {
+                    if other == node || ordered[other] {
+                        continue;
+                    }
+
+                    connections[other] += 1;
+
+                    if levels[other] == level {
+                        heap.push((connections[other], pattern.degree(other), Reverse(other)));
+                    } else if levels[other].is_limit() {
+                        levels[other] = level + 1;
+
+                        next.push(other);
+                    }
+                }
+            }
+
+            level += 1;
+
+            core::mem::swap(&mut current, &mut next);
+        }
+    }
+
+    order
+}
+
+/// Represents frames of the search, one per pattern node in the matching order.
+struct Frame {
+    node: usize,
+    candidates: Option<Vec<usize>>,
+    cursor: usize,
+    assigned: usize,
+}
+
+/// Represents iterators over mappings found by the VF2++ algorithm.
+///
+/// See [`vf2pp_by`] for more information.
+pub struct Mappings<P: Base, T: Base, N, E> {
+    pattern_graph: P,
+    target_graph: T,
+    pattern: Structure<P::NodeId, P::EdgeId>,
+    target: Structure<T::NodeId, T::EdgeId>,
+    mode: Mode,
+    nodes: N,
+    edges: E,
+    order: Vec<usize>,
+    pattern_cores: Vec<usize>,
+    target_cores: Vec<usize>,
+    pattern_touches: Vec<usize>,
+    target_touches: Vec<usize>,
+    pattern_buffer: Vec<(usize, P::EdgeId)>,
+    target_buffer: Vec<(usize, T::EdgeId)>,
+    marks: Vec<usize>,
+    tick: usize,
+    stack: Vec<Frame>,
+    empty: bool,
+}
+
+impl<P, T, N, E> Mappings<P, T, N, E>
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Base<Kind = P::Kind> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    N: FnMut(&P::NodeValue, &T::NodeValue) -> bool,
+    E: FnMut(&P::EdgeValue, &T::EdgeValue) -> bool,
+{
+    fn new(pattern_graph: P, target_graph: T, mode: Mode, nodes: N, edges: E) -> Self {
+        let pattern = structure(&pattern_graph);
+        let target = structure(&target_graph);
+
+        let pattern_count = pattern.count();
+        let target_count = target.count();
+
+        let possible = if mode == Mode::Isomorphism {
+            pattern_count == target_count && pattern.size == target.size
+        } else {
+            pattern_count <= target_count && pattern.size <= target.size
+        };
+
+        let order = order(&pattern);
+
+        let mut mappings = Self {
+            pattern_graph,
+            target_graph,
+            pattern,
+            target,
+            mode,
+            nodes,
+            edges,
+            order,
+            pattern_cores: vec![usize::LIMIT; pattern_count],
+            target_cores: vec![usize::LIMIT; target_count],
+            pattern_touches: vec![0; pattern_count],
+            target_touches: vec![0; target_count],
+            pattern_buffer: Vec::new(),
+            target_buffer: Vec::new(),
+            marks: vec![0; target_count],
+            tick: 0,
+            stack: Vec::new(),
+            empty: possible && pattern_count == 0,
+        };
+
+        if possible && pattern_count > 0 {
+            let frame = mappings.frame(0);
+
+            mappings.stack.push(frame);
+        }
+
+        mappings
+    }
+
+    /// Creates the frame for the pattern node at the given position of the matching order.
+    ///
+    /// If the node has some already mapped neighbor, the candidates are the unmapped neighbors
+    /// of its image (in the corresponding direction), picking the neighbor with the fewest ones.
+    /// Otherwise, every unmapped target node is a candidate.
+    fn frame(&mut self, position: usize) -> Frame {
+        let node = self.order[position];
+
+        let mut best: Option<&Vec<(usize, T::EdgeId)>> = None;
+
+        let directed = P::Kind::DIRECTED;
+
+        for &(other, _) in &self.pattern.outgoing[node] {
+            let image = self.pattern_cores[other];
+
+            if other != node && !image.is_limit() {
+                let list = if directed {
+                    &self.target.incoming[image]
+                } else {
+                    &self.target.outgoing[image]
+                };
+
+                if best.is_none_or(|best| list.len() < best.len()) {
+                    best = Some(list);
+                }
+            }
+        }
+
+        for &(other, _) in &self.pattern.incoming[node] {
+            let image = self.pattern_cores[other];
+
+            if other != node && !image.is_limit() {
+                let list = &self.target.outgoing[image];
+
+                if best.is_none_or(|best| list.len() < best.len()) {
+                    best = Some(list);
+                }
+            }
+        }
+
+        let candidates = best.map(|list| {
+            self.tick += 1;
+
+            let mut candidates = Vec::new();
+
+            for &(other, _) in list {
+                if self.target_cores[other].is_limit() && self.marks[other] != self.tick {
+                    self.marks[other] = self.tick;
+
+                    candidates.push(other);
+                }
+            }
+
+            candidates
+        });
+
+        Frame {
+            node,
+            candidates,
+            cursor: 0,
+            assigned: usize::LIMIT,
+        }
+    }
+
+    /// Compares counts according to the mode.
+    fn fits(&self, pattern: usize, target: usize) -> bool {
+        if self.mode == Mode::Isomorphism {
+            pattern == target
+        } else {
+            pattern <= target
+        }
+    }
+
+    /// Checks whether the pattern node can be mapped to the target node.
+    fn feasible(&mut self, node: usize, other: usize) -> bool {
+        if !self.fits(
+            self.pattern.outgoing[node].len(),
+            self.target.outgoing[other].len(),
+        ) || !self.fits(
+            self.pattern.incoming[node].len(),
+            self.target.incoming[other].len(),
+        ) {
+            return false;
+        }
+
+        // look ahead at edges to unmapped nodes, which are either adjacent to mapped nodes or not
+
+        let (pattern_terminal, pattern_rest) = terminal(
+            &self.pattern,
+            &self.pattern_cores,
+            &self.pattern_touches,
+            node,
+        );
+
+        let (target_terminal, target_rest) = terminal(
+            &self.target,
+            &self.target_cores,
+            &self.target_touches,
+            other,
+        );
+
+        let possible = if self.mode == Mode::Isomorphism {
+            pattern_terminal == target_terminal && pattern_rest == target_rest
+        } else {
+            pattern_terminal <= target_terminal
+                && pattern_terminal + pattern_rest <= target_terminal + target_rest
+        };
+
+        if !possible || !self.consistent(node, other, true) || !self.consistent(node, other, false)
+        {
+            return false;
+        }
+
+        let pattern_value = self
+            .pattern_graph
+            .node_value(self.pattern.nodes[node])
+            .expect(NODE);
+
+        let target_value = self
+            .target_graph
+            .node_value(self.target.nodes[other])
+            .expect(NODE);
+
+        (self.nodes)(pattern_value, target_value)
+    }
+
+    /// Checks whether the edges between the given nodes and the mapped ones
+    /// (along with loops) correspond to each other, in the given direction.
+    fn consistent(&mut self, node: usize, other: usize, outgoing: bool) -> bool {
+        let (pattern_arcs, target_arcs) = if outgoing {
+            (&self.pattern.outgoing[node], &self.target.outgoing[other])
+        } else {
+            (&self.pattern.incoming[node], &self.target.incoming[other])
+        };
+
+        // target edges are labeled with preimages of their other endpoints
+
+        self.pattern_buffer.clear();
+
+        for &(next, edge) in pattern_arcs {
+            if next == node || !self.pattern_cores[next].is_limit() {
+                self.pattern_buffer.push((next, edge));
+            }
+        }
+
+        self.target_buffer.clear();
+
+        for &(next, edge) in target_arcs {
+            let preimage = if next == other {
+                node
+            } else {
+                self.target_cores[next]
+            };
+
+            if !preimage.is_limit() {
+                self.target_buffer.push((preimage, edge));
+            }
+        }
+
+        let induced = self.mode.is_induced();
+
+        if induced && self.pattern_buffer.len() != self.target_buffer.len()
+            || self.pattern_buffer.len() > self.target_buffer.len()
+        {
+            return false;
+        }
+
+        self.pattern_buffer.sort_unstable_by_key(|&(next, _)| next);
+        self.target_buffer.sort_unstable_by_key(|&(next, _)| next);
+
+        let mut target_start = 0;
+        let mut pattern_start = 0;
+
+        while pattern_start < self.pattern_buffer.len() {
+            let next = self.pattern_buffer[pattern_start].0;
+
+            let mut pattern_end = pattern_start;
+
+            while pattern_end < self.pattern_buffer.len()
+                && self.pattern_buffer[pattern_end].0 == next
+            {
+                pattern_end += 1;
+            }
+
+            while target_start < self.target_buffer.len()
+                && self.target_buffer[target_start].0 < next
+            {
+                // target edges to nodes not adjacent in the pattern
+                if induced {
+                    return false;
+                }
+
+                target_start += 1;
+            }
+
+            let mut target_end = target_start;
+
+            while target_end < self.target_buffer.len() && self.target_buffer[target_end].0 == next
+            {
+                target_end += 1;
+            }
+
+            let pattern_count = pattern_end - pattern_start;
+            let target_count = target_end - target_start;
+
+            if induced && pattern_count != target_count || pattern_count > target_count {
+                return false;
+            }
+
+            for &(_, pattern_edge) in &self.pattern_buffer[pattern_start..pattern_end] {
+                let pattern_value = self.pattern_graph.edge_value(pattern_edge).expect(EDGE);
+
+                let found =
+                    self.target_buffer[target_start..target_end]
+                        .iter()
+                        .any(|&(_, target_edge)| {
+                            let target_value =
+                                self.target_graph.edge_value(target_edge).expect(EDGE);
+
+                            (self.edges)(pattern_value, target_value)
+                        });
+
+                if !found {
+                    return false;
+                }
+            }
+
+            pattern_start = pattern_end;
+            target_start = target_end;
+        }
+
+        true
+    }
+
+    /// Maps the pattern node to the target node.
+    fn map(&mut self, node: usize, other: usize) {
+        self.pattern_cores[node] = other;
+        self.target_cores[other] = node;
+
+        for &(next, _) in self.pattern.arcs(node) {
+            self.pattern_touches[next] += 1;
+        }
+
+        for &(next, _) in self.target.arcs(other) {
+            self.target_touches[next] += 1;
+        }
+    }
+
+    /// Undoes mapping the pattern node to the target node.
+    fn unmap(&mut self, node: usize, other: usize) {
+        self.pattern_cores[node] = usize::LIMIT;
+        self.target_cores[other] = usize::LIMIT;
+
+        for &(next, _) in self.pattern.arcs(node) {
+            self.pattern_touches[next] -= 1;
+        }
+
+        for &(next, _) in self.target.arcs(other) {
+            self.target_touches[next] -= 1;
+        }
+    }
+
+    /// Advances the given frame to its next feasible candidate.
+    fn advance(&mut self, frame: &mut Frame) -> Option<usize> {
+        loop {
+            let other = match &frame.candidates {
+                Some(candidates) => *candidates.get(frame.cursor)?,
+                None if frame.cursor < self.target.count() => frame.cursor,
+                None => return None,
+            };
+
+            frame.cursor += 1;
+
+            if self.target_cores[other].is_limit() && self.feasible(frame.node, other) {
+                return Some(other);
+            }
+        }
+    }
+
+    /// Collects the current (complete) mapping.
+    fn mapping(&self) -> MappingOf<P, T> {
+        self.pattern_cores
+            .iter()
+            .enumerate()
+            .map(|(node, &other)| (self.pattern.nodes[node], self.target.nodes[other]))
+            .collect()
+    }
+}
+
+/// Counts edges from the given node to unmapped nodes that are adjacent to mapped nodes
+/// and to the rest of unmapped nodes, respectively.
+fn terminal<N, E: Copy>(
+    structure: &Structure<N, E>,
+    cores: &[usize],
+    touches: &[usize],
+    node: usize,
+) -> (usize, usize) {
+    let mut terminal = 0;
+    let mut rest = 0;
+
+    for &(other, _) in structure.arcs(node) {
+        if other == node || !cores[other].is_limit() {
+            continue;
+        }
+
+        if touches[other] > 0 {
+            terminal += 1;
+        } else {
+            rest += 1;
+        }
+    }
+
+    (terminal, rest)
+}
+
+impl<P, T, N, E> Iterator for Mappings<P, T, N, E>
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Base<Kind = P::Kind> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    N: FnMut(&P::NodeValue, &T::NodeValue) -> bool,
+    E: FnMut(&P::EdgeValue, &T::EdgeValue) -> bool,
+{
+    type Item = MappingOf<P, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.empty {
+            self.empty = false;
+
+            return Some(Vec::new());
+        }
+
+        while let Some(mut frame) = self.stack.pop() {
+            if !frame.assigned.is_limit() {
+                self.unmap(frame.node, frame.assigned);
+
+                frame.assigned = usize::LIMIT;
+            }
+
+            let Some(other) = self.advance(&mut frame) else {
+                // every candidate was tried, so backtrack
+                continue;
+            };
+
+            self.map(frame.node, other);
+
+            frame.assigned = other;
+
+            self.stack.push(frame);
+
+            let depth = self.stack.len();
+
+            if depth == self.order.len() {
+                return Some(self.mapping());
+            }
+
+            let frame = self.frame(depth);
+
+            self.stack.push(frame);
+        }
+
+        None
+    }
+}
+
+/// Finds mappings of the `pattern` graph into the `target` graph using the VF2++ algorithm.
+///
+/// Pattern nodes are matched one by one in the order computed via breadth-first search,
+/// which places nodes connected to many already matched ones first. Every candidate target
+/// node is checked to have fitting degrees, equivalent values and edges corresponding to
+/// the ones of the pattern node (in both directions for directed graphs), and is pruned
+/// by looking ahead at the counts of edges to unmatched nodes.
+///
+/// The `nodes` and `edges` functions determine whether pattern node and edge values
+/// are equivalent to target ones. Among parallel edges, every pattern edge is required
+/// to be equivalent to some of the corresponding target edges, and the counts of parallel edges
+/// are compared according to the `mode`.
+///
+/// Mappings are found lazily, so callers can stop at the first one; every mapping lists
+/// pattern nodes in the order of their identifiers, along with their images.
+///
+/// # Panics
+///
+/// Panics if either graph yields edges that are missing their endpoints or values,
+/// or nodes that are missing their values.
+pub fn vf2pp_by<P, T, N, E>(
+    pattern: P,
+    target: T,
+    mode: Mode,
+    nodes: N,
+    edges: E,
+) -> Mappings<P, T, N, E>
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Base<Kind = P::Kind> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    N: FnMut(&P::NodeValue, &T::NodeValue) -> bool,
+    E: FnMut(&P::EdgeValue, &T::EdgeValue) -> bool,
+{
+    Mappings::new(pattern, target, mode, nodes, edges)
+}
+
+/// Finds mappings of the `pattern` graph into the `target` graph using the VF2++ algorithm,
+/// ignoring node and edge values.
+///
+/// See [`vf2pp_by`] for more information.
+///
+/// # Panics
+///
+/// Panics if either graph yields edges that are missing their endpoints.
+pub fn vf2pp<P, T>(pattern: P, target: T, mode: Mode) -> AnyMappings<P, T>
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Base<Kind = P::Kind> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    vf2pp_by(pattern, target, mode, |_, _| true, |_, _| true)
+}
+
+/// Checks whether the given graphs are isomorphic, ignoring node and edge values.
+///
+/// See [`vf2pp_by`] for more information.
+///
+/// # Panics
+///
+/// Panics if either graph yields edges that are missing their endpoints.
+pub fn is_isomorphic<P, T>(one: P, two: T) -> bool
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Base<Kind = P::Kind> + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    vf2pp(one, two, Mode::Isomorphism).next().is_some()
+}
+
+/// Represents the VF2++ algorithm, mapping patterns into the given target graph.
+///
+/// See [`vf2pp`] for more information.
+pub struct Vf2pp<T> {
+    target: T,
+    mode: Mode,
+}
+
+impl<T> Vf2pp<T> {
+    /// Constructs [`Self`] with the given `target` graph and `mode`.
+    pub const fn new(target: T, mode: Mode) -> Self {
+        Self { target, mode }
+    }
+}
+
+impl<P, T> Algorithm<P> for Vf2pp<T>
+where
+    P: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    T: Copy
+        + Base<Kind = P::Kind>
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + Endpoints
+        + DataRef,
+{
+    type Output = AnyMappings<P, T>;
+
+    fn perform(&mut self, graph: P) -> Self::Output {
+        vf2pp(graph, self.target, self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::neighbors::Neighbors;
+
+    use crate::testing::{DiGraph, UnGraph};
+
+    const SQUARE: [(usize, usize); 5] = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)];
+
+    #[test]
+    fn isomorphic() {
+        let one = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        let two = UnGraph::unweighted(4, &[(2, 0), (0, 3), (3, 1), (1, 2)]);
+        let three = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+
+        assert!(is_isomorphic(&one, &two));
+        assert!(!is_isomorphic(&one, &three));
+
+        let mapping = vf2pp(&one, &two, Mode::Isomorphism).next().unwrap();
+
+        for (index, &(node, image)) in mapping.iter().enumerate() {
+            assert_eq!(node.get(), index);
+
+            let next = mapping[(index + 1) % 4].1;
+
+            assert!(two.neighbors(image).any(|other| other == next));
+        }
+    }
+
+    #[test]
+    fn modes() {
+        let path = UnGraph::unweighted(3, &[(0, 1), (1, 2)]);
+        let square = UnGraph::unweighted(4, &SQUARE);
+
+        assert_eq!(vf2pp(&path, &square, Mode::Isomorphism).count(), 0);
+        assert_eq!(vf2pp(&path, &square, Mode::SubgraphIsomorphism).count(), 4);
+        assert_eq!(vf2pp(&path, &square, Mode::Monomorphism).count(), 16);
+
+        let triangle = UnGraph::unweighted(3, &[(0, 1), (1, 2), (2, 0)]);
+
+        assert_eq!(vf2pp(&triangle, &square, Mode::Monomorphism).count(), 12);
+    }
+
+    #[test]
+    fn parallel() {
+        let single = UnGraph::unweighted(2, &[(0, 1)]);
+        let double = UnGraph::unweighted(2, &[(0, 1), (1, 0)]);
+
+        assert!(!is_isomorphic(&single, &double));
+
+        assert_eq!(vf2pp(&single, &double, Mode::Monomorphism).count(), 2);
+        assert_eq!(vf2pp(&double, &single, Mode::Monomorphism).count(), 0);
+    }
+
+    #[test]
+    fn directed() {
+        let forward = DiGraph::unweighted(3, &[(0, 1), (1, 2)]);
+        let backward = DiGraph::unweighted(3, &[(2, 1), (1, 0)]);
+
+        let out = DiGraph::unweighted(3, &[(0, 1), (0, 2)]);
+        let into = DiGraph::unweighted(3, &[(1, 0), (2, 0)]);
+
+        assert!(is_isomorphic(&forward, &backward));
+        assert!(!is_isomorphic(&out, &into));
+    }
+
+    #[test]
+    fn values() {
+        let colored = |colors: &[u8]| {
+            let mut graph = UnGraph::new();
+
+            for &color in colors {
+                graph.node(color);
+            }
+
+            for &(one, two) in &[(0, 1), (1, 2), (2, 0)] {
+                graph.edge(one, two, ());
+            }
+
+            graph
+        };
+
+        let one = colored(&[1, 1, 2]);
+        let two = colored(&[2, 1, 1]);
+
+        let mappings: Vec<_> = vf2pp_by(&one, &two, Mode::Isomorphism, u8::eq, |(), ()| true)
+            .map(|mapping| mapping[2].1.get())
+            .collect();
+
+        assert_eq!(mappings, [0, 0]);
+    }
+}
@@ -1 +1,51 @@
+//! Various graph algorithms.
+
+#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+pub mod arborescence;
+pub mod assignment;
+pub mod biconnected;
+pub mod bipartite;
+pub mod build;
+pub mod centrality;
+pub mod cliques;
+pub mod coloring;
+pub mod communities;
+pub mod condensation;
+pub mod cycles;
+pub mod dominators;
+pub mod eulerian;
+pub mod feedback;
+pub mod isomorphism;
+pub mod loop_nesting;
+pub mod matching;
+pub mod max_flow;
+pub mod min_cost_flow;
+pub mod min_cut;
 pub mod min_spanning_tree;
+pub mod page_rank;
+pub mod power_iteration;
+pub mod strongly_connected;
+pub mod topological;
+pub mod weight;
+pub mod weisfeiler_lehman;
+
+mod compact;
+mod heap;
+mod random;
+
+#[cfg(test)]
+mod testing;
+
+pub use weight::Weight;
+
+/// The message used when edges yielded by graphs are missing their endpoints or values.
+pub const EDGE: &str = "expected edge to be present in the graph";
+
+/// The message used when nodes yielded by graphs are missing their values.
+pub const NODE: &str = "expected node to be present in the graph";
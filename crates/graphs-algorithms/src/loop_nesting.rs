@@ -0,0 +1,428 @@
+//! Loop nesting forests of directed graphs, such as control-flow graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    indexed::NodeIndexed,
+    limit::Limited,
+    neighbors::DirectedNeighbors,
+    recursive::{Discover, Finish, dfs},
+    time::{Time, Timed},
+    visit::Visit,
+};
+use graphs_union_find::vec::UnionFind;
+
+/// Represents loops, that is, strongly connected regions entered through their headers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Loop<N> {
+    /// The header of the loop, which is the first node of the loop reached from the entry node.
+    pub header: N,
+
+    /// The nodes of the loop in depth-first pre-order, starting with the header.
+    ///
+    /// This includes the nodes of all nested loops.
+    pub body: Vec<N>,
+
+    /// The index of the loop directly containing this one, if any.
+    pub parent: Option<usize>,
+
+    /// The number of loops containing this one, including itself.
+    pub depth: usize,
+
+    /// Whether the loop is irreducible, that is, can be entered through nodes
+    /// other than its header, bypassing it.
+    pub irreducible: bool,
+}
+
+impl<N> Loop<N> {
+    /// Constructs [`Self`].
+    pub const fn new(
+        header: N,
+        body: Vec<N>,
+        parent: Option<usize>,
+        depth: usize,
+        irreducible: bool,
+    ) -> Self {
+        Self {
+            header,
+            body,
+            parent,
+            depth,
+            irreducible,
+        }
+    }
+
+    /// Checks whether the loop is outermost, meaning it is not contained in any other loop.
+    pub const fn is_outermost(&self) -> bool {
+        self.parent.is_none()
+    }
+}
+
+/// Represents loop nesting forests, where loops are children of the loops containing them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct LoopForest<N> {
+    /// The loops, ordered such that nested loops come before the loops containing them.
+    pub loops: Vec<Loop<N>>,
+
+    /// The index of the innermost loop containing every node, indexed by node indices.
+    ///
+    /// Node indices not present in the graph, unreachable from the entry node
+    /// or not contained in any loops are mapped to [`usize::LIMIT`].
+    pub innermost: Vec<usize>,
+}
+
+impl<N> LoopForest<N> {
+    /// Constructs [`Self`].
+    pub const fn new(loops: Vec<Loop<N>>, innermost: Vec<usize>) -> Self {
+        Self { loops, innermost }
+    }
+
+    /// Returns the number of loops.
+    pub const fn len(&self) -> usize {
+        self.loops.len()
+    }
+
+    /// Checks whether there are no loops, meaning the reachable part of the graph is acyclic.
+    pub const fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+
+    /// Returns the index of the innermost loop containing the node with the given index, if any.
+    pub fn index_of(&self, index: usize) -> Option<usize> {
+        self.innermost
+            .get(index)
+            .copied()
+            .filter(|innermost| !innermost.is_limit())
+    }
+
+    /// Returns the innermost loop containing the node with the given index, if any.
+    pub fn loop_of(&self, index: usize) -> Option<&Loop<N>> {
+        self.index_of(index).map(|innermost| &self.loops[innermost])
+    }
+
+    /// Returns the number of loops containing the node with the given index.
+    pub fn depth_of(&self, index: usize) -> usize {
+        self.loop_of(index).map_or(0, |innermost| innermost.depth)
+    }
+}
+
+/// Represents [`Loop`] of the graph `G`.
+pub type LoopOf<G> = Loop<<G as Base>::NodeId>;
+
+/// Represents [`LoopForest`] of the graph `G`.
+pub type LoopForestOf<G> = LoopForest<<G as Base>::NodeId>;
+
+/// Finds the loop nesting forest of the given graph, starting from the given `entry` node.
+///
+/// This is Havlak's algorithm, which handles both reducible and irreducible loops
+/// in almost linear time. Ancestry in the depth-first search tree is checked using
+/// discovery and finish [`Time`]s of nodes reported by [`dfs`].
+///
+/// Only the nodes reachable from the entry node are included in loops.
+pub fn havlak<G>(graph: G, entry: G::NodeId) -> LoopForestOf<G>
+where
+    G: Visit + NodeIndexed + DirectedNeighbors,
+{
+    let bound = graph.node_bound();
+
+    // number nodes in the depth-first pre-order
+    let mut numbers = vec![usize::LIMIT; bound];
+
+    let mut nodes = Vec::new();
+
+    let mut discovered = Vec::new();
+    let mut finished = Vec::new();
+
+    dfs::<_, _, _, Option<()>>(&graph, [entry], |event| {
+        match event {
+            Discover(Timed { value, time }) => {
+                numbers[graph.node_index(value)] = nodes.len();
+
+                nodes.push(value);
+
+                discovered.push(time);
+                finished.push(Time::start());
+            }
+            Finish(Timed { value, time }) => {
+                finished[numbers[graph.node_index(value)]] = time;
+            }
+            _ => {}
+        }
+
+        None
+    });
+
+    let count = nodes.len();
+
+    let is_ancestor = |one: usize, two: usize| {
+        discovered[one] <= discovered[two] && finished[two] <= finished[one]
+    };
+
+    // split predecessors into the ones along back edges and the rest
+    let mut backward = vec![Vec::new(); count];
+    let mut forward = vec![Vec::new(); count];
+
+    for (number, &node) in nodes.iter().enumerate() {
+        for predecessor in graph.incoming_neighbors(node) {
+            let other = numbers[graph.node_index(predecessor)];
+
+            if other.is_limit() {
+                continue;
+            }
+
+            if is_ancestor(number, other) {
+                backward[number].push(other);
+            } else {
+                forward[number].push(other);
+            }
+        }
+    }
+
+    // nodes collapsed into the loops of their outermost headers processed so far,
+    // along with the header of every set, identified by its representative
+    let mut union_find = UnionFind::new(count);
+    let mut outermost: Vec<usize> = (0..count).collect();
+
+    let mut headers = vec![usize::LIMIT; count];
+    let mut indices = vec![usize::LIMIT; count];
+
+    let mut irreducible = Vec::new();
+    let mut heads = Vec::new();
+
+    // the header that last added every node to its loop
+    let mut marks = vec![usize::LIMIT; count];
+
+    let mut members = Vec::new();
+    let mut stack = Vec::new();
+    let mut bypasses = Vec::new();
+
+    // process nodes in reverse pre-order, so that inner loops are collapsed first
+    for header in (0..count).rev() {
+        let mut looping = false;
+        let mut bypassed = false;
+
+        members.clear();
+
+        for &other in &backward[header] {
+            if other == header {
+                looping = true;
+
+                continue;
+            }
+
+            let member = outermost[union_find.find_mut(other)];
+
+            if marks[member] != header {
+                marks[member] = header;
+
+                members.push(member);
+            }
+        }
+
+        stack.extend_from_slice(&members);
+
+        while let Some(member) = stack.pop() {
+            bypasses.clear();
+
+            for &other in &forward[member] {
+                let other = outermost[union_find.find_mut(other)];
+
+                if !is_ancestor(header, other) {
+                    // the loop is entered bypassing its header
+                    bypassed = true;
+
+                    bypasses.push(other);
+                } else if other != header && marks[other] != header {
+                    marks[other] = header;
+
+                    members.push(other);
+                    stack.push(other);
+                }
+            }
+
+            forward[header].extend_from_slice(&bypasses);
+        }
+
+        if members.is_empty() && !looping {
+            continue;
+        }
+
+        indices[header] = heads.len();
+
+        heads.push(header);
+        irreducible.push(bypassed);
+
+        for &member in &members {
+            headers[member] = header;
+
+            union_find.union(member, header);
+        }
+
+        outermost[union_find.find_mut(header)] = header;
+    }
+
+    let total = heads.len();
+
+    // headers of containing loops are processed later, so their indices are greater
+    let parents: Vec<Option<usize>> = heads
+        .iter()
+        .map(|&head| Some(headers[head]).filter(|outer| !outer.is_limit()))
+        .map(|outer| outer.map(|outer| indices[outer]))
+        .collect();
+
+    let mut depths = vec![1; total];
+
+    for index in (0..total).rev() {
+        if let Some(parent) = parents[index] {
+            depths[index] = depths[parent] + 1;
+        }
+    }
+
+    let mut bodies = vec![Vec::new(); total];
+    let mut innermost = vec![usize::LIMIT; bound];
+
+    for (number, &node) in nodes.iter().enumerate() {
+        let mut current = if indices[number].is_limit() {
+            Some(headers[number])
+                .filter(|header| !header.is_limit())
+                .map(|header| indices[header])
+        } else {
+            Some(indices[number])
+        };
+
+        if let Some(index) = current {
+            innermost[graph.node_index(node)] = index;
+        }
+
+        while let Some(index) = current {
+            bodies[index].push(node);
+
+            current = parents[index];
+        }
+    }
+
+    let loops = bodies
+        .into_iter()
+        .enumerate()
+        .map(|(index, body)| {
+            Loop::new(
+                nodes[heads[index]],
+                body,
+                parents[index],
+                depths[index],
+                irreducible[index],
+            )
+        })
+        .collect();
+
+    LoopForest::new(loops, innermost)
+}
+
+/// Represents Havlak's loop nesting algorithm.
+///
+/// See [`havlak`] for more information.
+pub struct Havlak<N> {
+    entry: N,
+}
+
+impl<N> Havlak<N> {
+    /// Constructs [`Self`] with the given `entry` node.
+    pub const fn new(entry: N) -> Self {
+        Self { entry }
+    }
+}
+
+impl<G> Algorithm<G> for Havlak<G::NodeId>
+where
+    G: Visit + NodeIndexed + DirectedNeighbors,
+{
+    type Output = LoopForestOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        havlak(graph, self.entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::DiGraph;
+
+    fn body(forest: &LoopForest<NodeId<usize>>, index: usize) -> Vec<usize> {
+        let mut body: Vec<_> = forest
+            .loop_of(index)
+            .unwrap()
+            .body
+            .iter()
+            .map(|node| node.get())
+            .collect();
+
+        body.sort_unstable();
+
+        body
+    }
+
+    #[test]
+    fn forest() {
+        let graph = DiGraph::unweighted(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 2),
+                (3, 1),
+                (3, 4),
+                (4, 4),
+                (0, 5),
+                (0, 6),
+                (5, 6),
+                (6, 5),
+                (7, 7),
+            ],
+        );
+
+        let forest = havlak(&graph, NodeId::new(0));
+
+        assert_eq!(forest.len(), 4);
+
+        let depths: Vec<_> = (0..8).map(|index| forest.depth_of(index)).collect();
+
+        assert_eq!(depths, [0, 1, 2, 2, 1, 1, 1, 0]);
+
+        assert_eq!(body(&forest, 2), [2, 3]);
+        assert_eq!(body(&forest, 1), [1, 2, 3]);
+        assert_eq!(body(&forest, 4), [4]);
+        assert_eq!(body(&forest, 5), [5, 6]);
+
+        let inner = forest.loop_of(3).unwrap();
+        let outer = forest.index_of(1).unwrap();
+
+        assert_eq!(inner.header, NodeId::new(2));
+        assert_eq!(inner.parent, Some(outer));
+        assert!(!inner.irreducible);
+
+        // nested loops come before the loops containing them
+        assert!(forest.index_of(2).unwrap() < outer);
+        assert!(forest.loops[outer].is_outermost());
+
+        assert!(forest.loop_of(5).unwrap().irreducible);
+        assert!(!forest.loop_of(4).unwrap().irreducible);
+
+        assert_eq!(forest.index_of(0), None);
+        assert_eq!(forest.index_of(7), None);
+    }
+
+    #[test]
+    fn acyclic() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (0, 2), (1, 2)]);
+
+        assert!(havlak(&graph, NodeId::new(0)).is_empty());
+    }
+}
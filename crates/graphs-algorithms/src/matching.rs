@@ -0,0 +1,1636 @@
+//! Maximum matchings of undirected graphs.
+
+use core::ops::Div;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    data::DataRef,
+    edges::Edges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+use thiserror::Error;
+
+use crate::{EDGE, bipartite::bipartition, compact::compact, weight::Weight};
+
+/// Represents matchings, that is, sets of edges without common endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Matching<N, E> {
+    /// The matched edges.
+    pub edges: Vec<E>,
+
+    /// The node matched to every node, indexed by node indices.
+    ///
+    /// Unmatched nodes (along with node indices not present in the graph) have no mates.
+    pub mates: Vec<Option<N>>,
+}
+
+impl<N, E> Matching<N, E> {
+    /// Constructs [`Self`].
+    pub const fn new(edges: Vec<E>, mates: Vec<Option<N>>) -> Self {
+        Self { edges, mates }
+    }
+
+    /// Returns the number of matched edges.
+    pub const fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Checks whether no edges are matched.
+    pub const fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+impl<N: Copy, E> Matching<N, E> {
+    /// Returns the mate of the node with the given index, if it is matched.
+    pub fn mate_of(&self, index: usize) -> Option<N> {
+        self.mates.get(index).copied().flatten()
+    }
+}
+
+/// Represents [`Matching`] of the graph `G`.
+pub type MatchingOf<G> = Matching<<G as Base>::NodeId, <G as Base>::EdgeId>;
+
+/// Represents maximum matchings of bipartite graphs along with minimum vertex covers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BipartiteMatching<N, E> {
+    /// The maximum matching.
+    pub matching: Matching<N, E>,
+
+    /// The minimum vertex cover, which has as many nodes as the matching has edges.
+    pub cover: Vec<N>,
+}
+
+impl<N, E> BipartiteMatching<N, E> {
+    /// Constructs [`Self`].
+    pub const fn new(matching: Matching<N, E>, cover: Vec<N>) -> Self {
+        Self { matching, cover }
+    }
+}
+
+/// Represents [`BipartiteMatching`] of the graph `G`.
+pub type BipartiteMatchingOf<G> = BipartiteMatching<<G as Base>::NodeId, <G as Base>::EdgeId>;
+
+/// Represents errors returned when graphs are not bipartite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Error)]
+#[error("graph is not bipartite")]
+pub struct NotBipartite;
+
+/// Finds the maximum matching of the given bipartite graph using the Hopcroft-Karp algorithm,
+/// detecting its partition first.
+///
+/// See [`hopcroft_karp_with`] for more information.
+///
+/// # Errors
+///
+/// Returns [`NotBipartite`] if the graph is not bipartite.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn hopcroft_karp<G>(graph: G) -> Result<BipartiteMatchingOf<G>, NotBipartite>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    let bipartition = bipartition(&graph).map_err(|_| NotBipartite)?;
+
+    Ok(hopcroft_karp_with(&graph, |node| {
+        bipartition.is_left(graph.node_index(node))
+    }))
+}
+
+/// Finds the maximum matching of the given bipartite graph using the Hopcroft-Karp algorithm,
+/// where the `left` function determines which side of the partition every node is on.
+///
+/// Each phase finds the shortest augmenting paths from all free left nodes via breadth-first
+/// search, and then augments along maximal sets of disjoint ones via depth-first searches.
+/// There are at most `O(sqrt(V))` phases, giving the `O(E sqrt(V))` running time.
+///
+/// The minimum vertex cover is then found via König's theorem: taking the nodes reachable
+/// from free left nodes via alternating paths, the cover consists of the left nodes
+/// that are *not* reachable along with the right nodes that are.
+///
+/// Edges connecting nodes on the same side are ignored.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn hopcroft_karp_with<G, F>(graph: G, mut left: F) -> BipartiteMatchingOf<G>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+    F: FnMut(G::NodeId) -> bool,
+{
+    let bound = graph.node_bound();
+
+    // left nodes are referred to by their positions, and right nodes by their indices
+
+    let mut lefts = Vec::new();
+
+    let mut offsets = vec![0];
+    let mut arcs = Vec::new();
+
+    let mut sides = vec![false; bound];
+
+    for node in graph.node_identifiers() {
+        sides[graph.node_index(node)] = left(node);
+    }
+
+    for node in graph.node_identifiers() {
+        if !sides[graph.node_index(node)] {
+            continue;
+        }
+
+        for edge in graph.edges(node) {
+            let other = graph.opposite(edge, node).expect(EDGE);
+
+            let other_index = graph.node_index(other);
+
+            if !sides[other_index] {
+                arcs.push((other_index, edge));
+            }
+        }
+
+        lefts.push(node);
+        offsets.push(arcs.len());
+    }
+
+    let count = lefts.len();
+
+    let mut left_mates = vec![usize::LIMIT; count];
+    let mut right_mates = vec![usize::LIMIT; bound];
+
+    let mut distances = vec![usize::LIMIT; count];
+    let mut current = vec![0; count];
+
+    let mut queue = VecDeque::new();
+    let mut stack = Vec::new();
+
+    loop {
+        // compute layers of left nodes, starting from the free ones
+
+        queue.clear();
+
+        for position in 0..count {
+            if left_mates[position].is_limit() {
+                distances[position] = 0;
+
+                queue.push_back(position);
+            } else {
+                distances[position] = usize::LIMIT;
+            }
+        }
+
+        let mut found = false;
+
+        while let Some(position) = queue.pop_front() {
+            for &(right, _) in &arcs[offsets[position]..offsets[position + 1]] {
+                let next = right_mates[right];
+
+                if next.is_limit() {
+                    found = true;
+                } else if distances[next].is_limit() {
+                    distances[next] = distances[position] + 1;
+
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !found {
+            break;
+        }
+
+        // augment along disjoint shortest paths
+
+        current.copy_from_slice(&offsets[..count]);
+
+        for start in 0..count {
+            if !left_mates[start].is_limit() {
+                continue;
+            }
+
+            stack.clear();
+            stack.push(start);
+
+            while let Some(&position) = stack.last() {
+                let end = offsets[position + 1];
+
+                let mut augmented = false;
+
+                while current[position] < end {
+                    let (right, _) = arcs[current[position]];
+
+                    let next = right_mates[right];
+
+                    if next.is_limit() {
+                        augmented = true;
+
+                        break;
+                    }
+
+                    if distances[next] == distances[position] + 1 {
+                        break;
+                    }
+
+                    current[position] += 1;
+                }
+
+                if augmented {
+                    // every node on the stack takes the right node it is currently pointing to
+
+                    for &position in &stack {
+                        let (right, _) = arcs[current[position]];
+
+                        left_mates[position] = current[position];
+                        right_mates[right] = position;
+
+                        // keep augmenting paths of the phase disjoint
+                        distances[position] = usize::LIMIT;
+                    }
+
+                    break;
+                }
+
+                if current[position] < end {
+                    let (right, _) = arcs[current[position]];
+
+                    stack.push(right_mates[right]);
+
+                    continue;
+                }
+
+                // dead end, so remove the node from the layers and retreat
+
+                distances[position] = usize::LIMIT;
+
+                stack.pop();
+
+                if let Some(&parent) = stack.last() {
+                    current[parent] += 1;
+                }
+            }
+        }
+    }
+
+    // find nodes reachable from free left nodes via alternating paths
+
+    let mut reached_left = vec![false; count];
+    let mut reached_right = vec![false; bound];
+
+    stack.clear();
+
+    for start in 0..count {
+        if left_mates[start].is_limit() {
+            reached_left[start] = true;
+
+            stack.push(start);
+        }
+    }
+
+    while let Some(position) = stack.pop() {
+        for &(right, _) in &arcs[offsets[position]..offsets[position + 1]] {
+            if reached_right[right] {
+                continue;
+            }
+
+            reached_right[right] = true;
+
+            let next = right_mates[right];
+
+            if !next.is_limit() && !reached_left[next] {
+                reached_left[next] = true;
+
+                stack.push(next);
+            }
+        }
+    }
+
+    let mut edges = Vec::new();
+    let mut mates = vec![None; bound];
+    let mut cover = Vec::new();
+
+    for (position, &node) in lefts.iter().enumerate() {
+        let arc = left_mates[position];
+
+        if !arc.is_limit() {
+            let (right, edge) = arcs[arc];
+
+            edges.push(edge);
+
+            mates[graph.node_index(node)] = Some(graph.node_id(right));
+            mates[right] = Some(node);
+        }
+
+        if !reached_left[position] {
+            cover.push(node);
+        }
+    }
+
+    for (right, &reached) in reached_right.iter().enumerate() {
+        if reached {
+            cover.push(graph.node_id(right));
+        }
+    }
+
+    BipartiteMatching::new(Matching::new(edges, mates), cover)
+}
+
+/// Represents the Hopcroft-Karp bipartite matching algorithm.
+///
+/// See [`hopcroft_karp`] for more information.
+pub struct HopcroftKarp;
+
+impl<G> Algorithm<G> for HopcroftKarp
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    type Output = Result<BipartiteMatchingOf<G>, NotBipartite>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        hopcroft_karp(graph)
+    }
+}
+
+/// Collects matched pairs of node positions into [`Matching`].
+fn collect<G>(
+    graph: &G,
+    nodes: &[G::NodeId],
+    pairs: Vec<(usize, usize, G::EdgeId)>,
+) -> MatchingOf<G>
+where
+    G: NodeIndexed,
+{
+    let mut edges = Vec::with_capacity(pairs.len());
+    let mut mates = vec![None; graph.node_bound()];
+
+    for (one, two, edge) in pairs {
+        edges.push(edge);
+
+        mates[graph.node_index(nodes[one])] = Some(nodes[two]);
+        mates[graph.node_index(nodes[two])] = Some(nodes[one]);
+    }
+
+    Matching::new(edges, mates)
+}
+
+const UNLABELED: u8 = 0;
+const EVEN: u8 = 1;
+const ODD: u8 = 2;
+
+/// Represents alternating forests grown by Edmonds' algorithm from single roots.
+struct Forest<E> {
+    offsets: Vec<usize>,
+    arcs: Vec<(usize, E)>,
+    mates: Vec<Option<(usize, E)>>,
+    previous: Vec<Option<(usize, E)>>,
+    labels: Vec<u8>,
+    parents: Vec<usize>,
+    marks: Vec<usize>,
+    tick: usize,
+    queue: VecDeque<usize>,
+    touched: Vec<usize>,
+}
+
+impl<E: Copy> Forest<E> {
+    /// Finds the base of the blossom containing the given node.
+    fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut node = node;
+
+        while self.parents[node] != root {
+            let next = self.parents[node];
+
+            self.parents[node] = root;
+
+            node = next;
+        }
+
+        root
+    }
+
+    /// Labels the given node, remembering to reset it once the search is over.
+    fn label(&mut self, node: usize, label: u8) {
+        if self.labels[node] == UNLABELED {
+            self.touched.push(node);
+        }
+
+        self.labels[node] = label;
+    }
+
+    /// Finds the base of the blossom formed by the edge connecting two even nodes,
+    /// by walking up from both of them towards the root in turns.
+    fn ancestor(&mut self, one: usize, two: usize) -> usize {
+        self.tick += 1;
+
+        let mut one = Some(one);
+        let mut two = Some(two);
+
+        loop {
+            if let Some(node) = one {
+                let base = self.find(node);
+
+                if self.marks[base] == self.tick {
+                    return base;
+                }
+
+                self.marks[base] = self.tick;
+
+                one = self.mates[base].map(|(mate, _)| {
+                    self.previous[mate]
+                        .expect("expected odd node to have its parent")
+                        .0
+                });
+            }
+
+            core::mem::swap(&mut one, &mut two);
+        }
+    }
+
+    /// Contracts the path from the even node `node` to the `base` of the blossom,
+    /// which is closed by the edge coming from `other`.
+    fn contract(&mut self, mut node: usize, mut other: usize, base: usize, mut edge: E) {
+        while self.find(node) != base {
+            self.previous[node] = Some((other, edge));
+
+            let (mate, _) = self.mates[node].expect("expected blossom node to be matched");
+
+            other = mate;
+
+            // odd nodes become even inside blossoms
+            if self.labels[other] == ODD {
+                self.labels[other] = EVEN;
+
+                self.queue.push_back(other);
+            }
+
+            if self.find(node) == node {
+                self.parents[node] = base;
+            }
+
+            if self.find(other) == other {
+                self.parents[other] = base;
+            }
+
+            (node, edge) = self.previous[other].expect("expected odd node to have its parent");
+        }
+    }
+
+    /// Searches for the augmenting path starting from the given free node, augmenting along it.
+    fn augment(&mut self, root: usize) -> bool {
+        self.queue.clear();
+
+        self.label(root, EVEN);
+
+        self.queue.push_back(root);
+
+        let mut found = false;
+
+        'search: while let Some(node) = self.queue.pop_front() {
+            for arc in self.offsets[node]..self.offsets[node + 1] {
+                let (other, edge) = self.arcs[arc];
+
+                if self.labels[other] == UNLABELED {
+                    self.previous[other] = Some((node, edge));
+
+                    self.label(other, ODD);
+
+                    let Some((mate, _)) = self.mates[other] else {
+                        self.flip(other);
+
+                        found = true;
+
+                        break 'search;
+                    };
+
+                    self.label(mate, EVEN);
+
+                    self.queue.push_back(mate);
+                } else if self.labels[other] == EVEN && self.find(node) != self.find(other) {
+                    let base = self.ancestor(node, other);
+
+                    self.contract(node, other, base, edge);
+                    self.contract(other, node, base, edge);
+                }
+            }
+        }
+
+        for &node in &self.touched {
+            self.labels[node] = UNLABELED;
+            self.previous[node] = None;
+            self.parents[node] = node;
+        }
+
+        self.touched.clear();
+
+        found
+    }
+
+    /// Flips the augmenting path ending at the given free node.
+    fn flip(&mut self, end: usize) {
+        let mut node = Some(end);
+
+        while let Some(current) = node {
+            let (other, edge) =
+                self.previous[current].expect("expected path node to have its parent");
+
+            node = self.mates[other].map(|(mate, _)| mate);
+
+            self.mates[current] = Some((other, edge));
+            self.mates[other] = Some((current, edge));
+        }
+    }
+}
+
+/// Finds the maximum-cardinality matching of the given graph using Edmonds' blossom algorithm.
+///
+/// Starting from the greedy matching, every free node grows the alternating forest via
+/// breadth-first search, contracting odd cycles (blossoms) into their bases via union-find,
+/// until some augmenting path is found. This function runs in `O(V E α(V))` time.
+///
+/// Loops are ignored.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn edmonds<G>(graph: G) -> MatchingOf<G>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    let (nodes, positions) = compact(&graph);
+
+    let count = nodes.len();
+
+    let mut offsets = vec![0];
+    let mut arcs = Vec::new();
+
+    for &node in &nodes {
+        for edge in graph.edges(node) {
+            let other = graph.opposite(edge, node).expect(EDGE);
+
+            if other != node {
+                arcs.push((positions[graph.node_index(other)], edge));
+            }
+        }
+
+        offsets.push(arcs.len());
+    }
+
+    let mut mates = vec![None; count];
+
+    // start from the greedy matching
+    for position in 0..count {
+        if mates[position].is_some() {
+            continue;
+        }
+
+        if let Some(&(other, edge)) = arcs[offsets[position]..offsets[position + 1]]
+            .iter()
+            .find(|&&(other, _)| mates[other].is_none())
+        {
+            mates[position] = Some((other, edge));
+            mates[other] = Some((position, edge));
+        }
+    }
+
+    let mut forest = Forest {
+        offsets,
+        arcs,
+        mates,
+        previous: vec![None; count],
+        labels: vec![UNLABELED; count],
+        parents: (0..count).collect(),
+        marks: vec![0; count],
+        tick: 0,
+        queue: VecDeque::new(),
+        touched: Vec::new(),
+    };
+
+    for root in 0..count {
+        if forest.mates[root].is_none() {
+            forest.augment(root);
+        }
+    }
+
+    let pairs = forest
+        .mates
+        .iter()
+        .enumerate()
+        .filter_map(|(position, mate)| {
+            mate.filter(|&(other, _)| position < other)
+                .map(|(other, edge)| (position, other, edge))
+        })
+        .collect();
+
+    collect(&graph, &nodes, pairs)
+}
+
+/// Represents the state of the weighted blossom algorithm.
+///
+/// Nodes are referred to by their positions `0..count`, while non-trivial blossoms
+/// are numbered `count..2 * count`. Every edge `k` has two endpoints, `2 * k` and `2 * k + 1`,
+/// referring to its first and second node respectively; flipping the lowest bit of some endpoint
+/// gives the opposite one.
+struct Blossoms<W> {
+    count: usize,
+    edges: Vec<(usize, usize, W)>,
+    neighbors: Vec<Vec<usize>>,
+    mates: Vec<usize>,
+    labels: Vec<u8>,
+    label_ends: Vec<usize>,
+    inside: Vec<usize>,
+    parents: Vec<usize>,
+    children: Vec<Vec<usize>>,
+    bases: Vec<usize>,
+    ends: Vec<Vec<usize>>,
+    best: Vec<usize>,
+    best_edges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    duals: Vec<W>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+/// Marks blossoms visited while scanning for the base of the new blossom.
+const BREADCRUMB: u8 = 4;
+
+impl<W: Weight + Div<Output = W>> Blossoms<W> {
+    fn new(count: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        let mut neighbors = vec![Vec::new(); count];
+
+        let mut top = W::ZERO;
+
+        for (k, &(one, two, weight)) in edges.iter().enumerate() {
+            neighbors[one].push(2 * k + 1);
+            neighbors[two].push(2 * k);
+
+            if weight.compare(&top).is_gt() {
+                top = weight;
+            }
+        }
+
+        let total = 2 * count;
+
+        let mut bases: Vec<usize> = (0..count).collect();
+        bases.resize(total, usize::LIMIT);
+
+        let mut duals = vec![top; count];
+        duals.resize(total, W::ZERO);
+
+        let size = edges.len();
+
+        Self {
+            count,
+            edges,
+            neighbors,
+            mates: vec![usize::LIMIT; count],
+            labels: vec![UNLABELED; total],
+            label_ends: vec![usize::LIMIT; total],
+            inside: (0..count).collect(),
+            parents: vec![usize::LIMIT; total],
+            children: vec![Vec::new(); total],
+            bases,
+            ends: vec![Vec::new(); total],
+            best: vec![usize::LIMIT; total],
+            best_edges: vec![None; total],
+            unused: (count..total).rev().collect(),
+            duals,
+            allowed: vec![false; size],
+            queue: Vec::new(),
+        }
+    }
+
+    /// Returns the node of the given endpoint.
+    fn endpoint(&self, end: usize) -> usize {
+        let (one, two, _) = self.edges[end / 2];
+
+        if end.is_multiple_of(2) { one } else { two }
+    }
+
+    /// Returns the slack of the given edge, which is twice its reduced cost.
+    fn slack(&self, edge: usize) -> W {
+        let (one, two, weight) = self.edges[edge];
+
+        self.duals[one] + self.duals[two] - (weight + weight)
+    }
+
+    /// Returns the nodes contained in the given blossom.
+    fn leaves(&self, blossom: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+
+        let mut stack = vec![blossom];
+
+        while let Some(current) = stack.pop() {
+            if current < self.count {
+                leaves.push(current);
+            } else {
+                stack.extend(self.children[current].iter().rev());
+            }
+        }
+
+        leaves
+    }
+
+    /// Labels the top-level blossom containing `node` via the given endpoint;
+    /// labeling it odd also labels the blossom matched to its base even.
+    fn assign(&mut self, mut node: usize, mut label: u8, mut end: usize) {
+        loop {
+            let blossom = self.inside[node];
+
+            self.labels[node] = label;
+            self.labels[blossom] = label;
+
+            self.label_ends[node] = end;
+            self.label_ends[blossom] = end;
+
+            self.best[node] = usize::LIMIT;
+            self.best[blossom] = usize::LIMIT;
+
+            if label == EVEN {
+                let leaves = self.leaves(blossom);
+
+                self.queue.extend(leaves);
+
+                return;
+            }
+
+            let mate = self.mates[self.bases[blossom]];
+
+            node = self.endpoint(mate);
+            label = EVEN;
+            end = mate ^ 1;
+        }
+    }
+
+    /// Traces back from two even nodes, returning the base of the new blossom if they are in
+    /// the same alternating tree, and [`usize::LIMIT`] if there is an augmenting path instead.
+    fn scan(&mut self, mut one: usize, mut two: usize) -> usize {
+        let mut path = Vec::new();
+
+        let mut base = usize::LIMIT;
+
+        while !one.is_limit() || !two.is_limit() {
+            let mut blossom = self.inside[one];
+
+            if self.labels[blossom] & BREADCRUMB != 0 {
+                base = self.bases[blossom];
+
+                break;
+            }
+
+            path.push(blossom);
+
+            self.labels[blossom] = EVEN | BREADCRUMB;
+
+            if self.label_ends[blossom].is_limit() {
+                // reached the root of the tree
+                one = usize::LIMIT;
+            } else {
+                one = self.endpoint(self.label_ends[blossom]);
+
+                blossom = self.inside[one];
+
+                one = self.endpoint(self.label_ends[blossom]);
+            }
+
+            if !two.is_limit() {
+                core::mem::swap(&mut one, &mut two);
+            }
+        }
+
+        for blossom in path {
+            self.labels[blossom] = EVEN;
+        }
+
+        base
+    }
+
+    /// Constructs the new blossom with the given base, closed by the given edge.
+    fn add(&mut self, base: usize, edge: usize) {
+        let (mut one, mut two, _) = self.edges[edge];
+
+        let base_blossom = self.inside[base];
+
+        let mut one_blossom = self.inside[one];
+        let mut two_blossom = self.inside[two];
+
+        let blossom = self.unused.pop().expect("expected unused blossom");
+
+        self.bases[blossom] = base;
+        self.parents[blossom] = usize::LIMIT;
+        self.parents[base_blossom] = blossom;
+
+        let mut path = Vec::new();
+        let mut ends = Vec::new();
+
+        while one_blossom != base_blossom {
+            self.parents[one_blossom] = blossom;
+
+            path.push(one_blossom);
+            ends.push(self.label_ends[one_blossom]);
+
+            one = self.endpoint(self.label_ends[one_blossom]);
+            one_blossom = self.inside[one];
+        }
+
+        path.push(base_blossom);
+
+        path.reverse();
+        ends.reverse();
+
+        ends.push(2 * edge);
+
+        while two_blossom != base_blossom {
+            self.parents[two_blossom] = blossom;
+
+            path.push(two_blossom);
+            ends.push(self.label_ends[two_blossom] ^ 1);
+
+            two = self.endpoint(self.label_ends[two_blossom]);
+            two_blossom = self.inside[two];
+        }
+
+        self.labels[blossom] = EVEN;
+        self.label_ends[blossom] = self.label_ends[base_blossom];
+        self.duals[blossom] = W::ZERO;
+
+        self.children[blossom] = path.clone();
+        self.ends[blossom] = ends;
+
+        for node in self.leaves(blossom) {
+            // odd nodes become even inside blossoms
+            if self.labels[self.inside[node]] == ODD {
+                self.queue.push(node);
+            }
+
+            self.inside[node] = blossom;
+        }
+
+        // compute the least-slack edges to other even blossoms
+
+        let mut best_to = vec![usize::LIMIT; 2 * self.count];
+
+        for child in path {
+            let list = match self.best_edges[child].take() {
+                Some(list) => list,
+                None => self
+                    .leaves(child)
+                    .into_iter()
+                    .flat_map(|node| self.neighbors[node].iter().map(|&end| end / 2))
+                    .collect(),
+            };
+
+            for edge in list {
+                let (one, two, _) = self.edges[edge];
+
+                let other = if self.inside[two] == blossom {
+                    one
+                } else {
+                    two
+                };
+
+                let other_blossom = self.inside[other];
+
+                if other_blossom != blossom
+                    && self.labels[other_blossom] == EVEN
+                    && (best_to[other_blossom].is_limit()
+                        || self
+                            .slack(edge)
+                            .compare(&self.slack(best_to[other_blossom]))
+                            .is_lt())
+                {
+                    best_to[other_blossom] = edge;
+                }
+            }
+
+            self.best[child] = usize::LIMIT;
+        }
+
+        let list: Vec<usize> = best_to
+            .into_iter()
+            .filter(|edge| !edge.is_limit())
+            .collect();
+
+        self.best[blossom] = usize::LIMIT;
+
+        for &edge in &list {
+            let best = self.best[blossom];
+
+            if best.is_limit() || self.slack(edge).compare(&self.slack(best)).is_lt() {
+                self.best[blossom] = edge;
+            }
+        }
+
+        self.best_edges[blossom] = Some(list);
+    }
+
+    /// Expands the given blossom; at the end of stages, sub-blossoms with zero duals
+    /// are expanded as well.
+    fn expand(&mut self, blossom: usize, end_stage: bool) {
+        let mut stack = vec![blossom];
+
+        while let Some(blossom) = stack.pop() {
+            let children = self.children[blossom].clone();
+
+            for &child in &children {
+                self.parents[child] = usize::LIMIT;
+
+                if child < self.count {
+                    self.inside[child] = child;
+                } else if end_stage && self.duals[child].compare(&W::ZERO).is_eq() {
+                    stack.push(child);
+                } else {
+                    for node in self.leaves(child) {
+                        self.inside[node] = child;
+                    }
+                }
+            }
+
+            if !end_stage && self.labels[blossom] == ODD {
+                self.relabel(blossom, &children);
+            }
+
+            self.labels[blossom] = UNLABELED;
+            self.label_ends[blossom] = usize::LIMIT;
+            self.children[blossom].clear();
+            self.ends[blossom].clear();
+            self.bases[blossom] = usize::LIMIT;
+            self.best_edges[blossom] = None;
+            self.best[blossom] = usize::LIMIT;
+
+            self.unused.push(blossom);
+        }
+    }
+
+    /// Relabels the children of the expanded odd blossom, so that the alternating path
+    /// from the child it was entered through to its base stays in the tree.
+    fn relabel(&mut self, blossom: usize, children: &[usize]) {
+        let length = children.len() as isize;
+
+        let at = |position: isize| position.rem_euclid(length) as usize;
+
+        let entry = self.inside[self.endpoint(self.label_ends[blossom] ^ 1)];
+
+        let mut position = children
+            .iter()
+            .position(|&child| child == entry)
+            .expect("expected entry child") as isize;
+
+        // go in the direction that gives the even-length path to the base
+        let (step, trick) = if position & 1 == 1 {
+            position -= length;
+
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        let mut end = self.label_ends[blossom];
+
+        while position != 0 {
+            let node = self.endpoint(end ^ 1);
+
+            let next = self.ends[blossom][at(position - trick as isize)];
+
+            self.labels[node] = UNLABELED;
+
+            let other = self.endpoint(next ^ trick ^ 1);
+
+            self.labels[other] = UNLABELED;
+
+            self.assign(node, ODD, end);
+
+            self.allowed[next / 2] = true;
+
+            position += step;
+
+            end = self.ends[blossom][at(position - trick as isize)] ^ trick;
+
+            self.allowed[end / 2] = true;
+
+            position += step;
+        }
+
+        // the base child gets labeled odd without labeling its mate
+
+        let child = children[at(position)];
+
+        let node = self.endpoint(end ^ 1);
+
+        self.labels[node] = ODD;
+        self.labels[child] = ODD;
+
+        self.label_ends[node] = end;
+        self.label_ends[child] = end;
+
+        self.best[child] = usize::LIMIT;
+
+        position += step;
+
+        // the remaining children get labeled only if they are reachable from outside
+
+        while children[at(position)] != entry {
+            let child = children[at(position)];
+
+            position += step;
+
+            if self.labels[child] == EVEN {
+                continue;
+            }
+
+            let Some(node) = self
+                .leaves(child)
+                .into_iter()
+                .find(|&node| self.labels[node] != UNLABELED)
+            else {
+                continue;
+            };
+
+            self.labels[node] = UNLABELED;
+
+            let mate = self.endpoint(self.mates[self.bases[child]]);
+
+            self.labels[mate] = UNLABELED;
+
+            self.assign(node, ODD, self.label_ends[node]);
+        }
+    }
+
+    /// Swaps matched and unmatched edges along the even path from the given node
+    /// to the base of the given blossom, making the node the new base.
+    fn rotate(&mut self, blossom: usize, node: usize) {
+        let mut tasks = vec![(blossom, node)];
+
+        while let Some((blossom, node)) = tasks.pop() {
+            let mut child = node;
+
+            while self.parents[child] != blossom {
+                child = self.parents[child];
+            }
+
+            // sub-blossoms are disjoint, so they can be handled in any order
+
+            if child >= self.count {
+                tasks.push((child, node));
+            }
+
+            let length = self.children[blossom].len() as isize;
+
+            let at = |position: isize| position.rem_euclid(length) as usize;
+
+            let start = self.children[blossom]
+                .iter()
+                .position(|&other| other == child)
+                .expect("expected child of blossom");
+
+            let mut position = start as isize;
+
+            let (step, trick) = if start & 1 == 1 {
+                position -= length;
+
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+
+            while position != 0 {
+                position += step;
+
+                let child = self.children[blossom][at(position)];
+
+                let end = self.ends[blossom][at(position - trick)] ^ trick as usize;
+
+                if child >= self.count {
+                    tasks.push((child, self.endpoint(end)));
+                }
+
+                position += step;
+
+                let child = self.children[blossom][at(position)];
+
+                if child >= self.count {
+                    tasks.push((child, self.endpoint(end ^ 1)));
+                }
+
+                let one = self.endpoint(end);
+                let two = self.endpoint(end ^ 1);
+
+                self.mates[one] = end ^ 1;
+                self.mates[two] = end;
+            }
+
+            self.children[blossom].rotate_left(start);
+            self.ends[blossom].rotate_left(start);
+
+            self.bases[blossom] = node;
+        }
+    }
+
+    /// Augments the matching along the path through the given edge.
+    fn augment(&mut self, edge: usize) {
+        let (one, two, _) = self.edges[edge];
+
+        for (mut node, mut end) in [(one, 2 * edge + 1), (two, 2 * edge)] {
+            loop {
+                let blossom = self.inside[node];
+
+                if blossom >= self.count {
+                    self.rotate(blossom, node);
+                }
+
+                self.mates[node] = end;
+
+                if self.label_ends[blossom].is_limit() {
+                    // reached the root of the tree
+                    break;
+                }
+
+                let odd = self.inside[self.endpoint(self.label_ends[blossom])];
+
+                let odd_end = self.label_ends[odd];
+
+                node = self.endpoint(odd_end);
+
+                let base = self.endpoint(odd_end ^ 1);
+
+                if odd >= self.count {
+                    self.rotate(odd, base);
+                }
+
+                self.mates[base] = odd_end;
+
+                end = odd_end ^ 1;
+            }
+        }
+    }
+
+    /// Scans the neighbors of the given even node, returning whether the matching was augmented.
+    fn scan_neighbors(&mut self, node: usize) -> bool {
+        for index in 0..self.neighbors[node].len() {
+            let end = self.neighbors[node][index];
+
+            let edge = end / 2;
+
+            let other = self.endpoint(end);
+
+            let blossom = self.inside[node];
+            let other_blossom = self.inside[other];
+
+            if blossom == other_blossom {
+                continue;
+            }
+
+            let mut slack = W::ZERO;
+
+            if !self.allowed[edge] {
+                slack = self.slack(edge);
+
+                if slack.compare(&W::ZERO).is_le() {
+                    self.allowed[edge] = true;
+                }
+            }
+
+            if self.allowed[edge] {
+                match self.labels[other_blossom] {
+                    UNLABELED => self.assign(other, ODD, end ^ 1),
+                    EVEN => {
+                        let base = self.scan(node, other);
+
+                        if base.is_limit() {
+                            self.augment(edge);
+
+                            return true;
+                        }
+
+                        self.add(base, edge);
+                    }
+                    _ => {
+                        if self.labels[other] == UNLABELED {
+                            self.labels[other] = ODD;
+                            self.label_ends[other] = end ^ 1;
+                        }
+                    }
+                }
+            } else if self.labels[other_blossom] == EVEN {
+                let best = self.best[blossom];
+
+                if best.is_limit() || slack.compare(&self.slack(best)).is_lt() {
+                    self.best[blossom] = edge;
+                }
+            } else if self.labels[other] == UNLABELED {
+                let best = self.best[other];
+
+                if best.is_limit() || slack.compare(&self.slack(best)).is_lt() {
+                    self.best[other] = edge;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Updates the duals by the largest amount keeping them feasible, returning whether
+    /// the stage is over, that is, whether the optimum was reached.
+    fn update(&mut self) -> bool {
+        let count = self.count;
+
+        let two = W::ONE + W::ONE;
+
+        // the least dual of nodes bounds the decrease of even node duals
+
+        let mut delta = self.duals[..count]
+            .iter()
+            .copied()
+            .reduce(|one, two| if two.compare(&one).is_lt() { two } else { one })
+            .unwrap_or(W::ZERO);
+
+        let mut kind = 1;
+        let mut target = usize::LIMIT;
+
+        // edges from even blossoms to free nodes
+
+        for node in 0..count {
+            let best = self.best[node];
+
+            if self.labels[self.inside[node]] == UNLABELED && !best.is_limit() {
+                let slack = self.slack(best);
+
+                if slack.compare(&delta).is_lt() {
+                    delta = slack;
+                    kind = 2;
+                    target = best;
+                }
+            }
+        }
+
+        // edges between even blossoms
+
+        for blossom in 0..2 * count {
+            let best = self.best[blossom];
+
+            if self.parents[blossom].is_limit() && self.labels[blossom] == EVEN && !best.is_limit()
+            {
+                let slack = self.slack(best) / two;
+
+                if slack.compare(&delta).is_lt() {
+                    delta = slack;
+                    kind = 3;
+                    target = best;
+                }
+            }
+        }
+
+        // duals of odd blossoms
+
+        for blossom in count..2 * count {
+            if !self.bases[blossom].is_limit()
+                && self.parents[blossom].is_limit()
+                && self.labels[blossom] == ODD
+                && self.duals[blossom].compare(&delta).is_lt()
+            {
+                delta = self.duals[blossom];
+                kind = 4;
+                target = blossom;
+            }
+        }
+
+        for node in 0..count {
+            match self.labels[self.inside[node]] {
+                EVEN => self.duals[node] = self.duals[node] - delta,
+                ODD => self.duals[node] = self.duals[node] + delta,
+                _ => {}
+            }
+        }
+
+        for blossom in count..2 * count {
+            if !self.bases[blossom].is_limit() && self.parents[blossom].is_limit() {
+                match self.labels[blossom] {
+                    EVEN => self.duals[blossom] = self.duals[blossom] + delta,
+                    ODD => self.duals[blossom] = self.duals[blossom] - delta,
+                    _ => {}
+                }
+            }
+        }
+
+        match kind {
+            1 => return true,
+            2 => {
+                self.allowed[target] = true;
+
+                let (one, two, _) = self.edges[target];
+
+                let node = if self.labels[self.inside[one]] == UNLABELED {
+                    two
+                } else {
+                    one
+                };
+
+                self.queue.push(node);
+            }
+            3 => {
+                self.allowed[target] = true;
+
+                let (one, _, _) = self.edges[target];
+
+                self.queue.push(one);
+            }
+            _ => self.expand(target, false),
+        }
+
+        false
+    }
+
+    /// Runs stages until no augmenting path improves the matching.
+    fn solve(&mut self) {
+        let count = self.count;
+
+        for _ in 0..count {
+            self.labels.fill(UNLABELED);
+            self.best.fill(usize::LIMIT);
+            self.best_edges[count..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for node in 0..count {
+                if self.mates[node].is_limit() && self.labels[self.inside[node]] == UNLABELED {
+                    self.assign(node, EVEN, usize::LIMIT);
+                }
+            }
+
+            let augmented = loop {
+                let mut augmented = false;
+
+                while !augmented && let Some(node) = self.queue.pop() {
+                    augmented = self.scan_neighbors(node);
+                }
+
+                if augmented {
+                    break true;
+                }
+
+                if self.update() {
+                    break false;
+                }
+            };
+
+            if !augmented {
+                break;
+            }
+
+            // expand even blossoms with zero duals at the end of the stage
+
+            for blossom in count..2 * count {
+                if self.parents[blossom].is_limit()
+                    && !self.bases[blossom].is_limit()
+                    && self.labels[blossom] == EVEN
+                    && self.duals[blossom].compare(&W::ZERO).is_eq()
+                {
+                    self.expand(blossom, true);
+                }
+            }
+        }
+    }
+}
+
+/// Finds the maximum-weight matching of the given graph using the weighted blossom algorithm.
+///
+/// This is Galil's `O(V^3)` formulation of Edmonds' primal-dual method: each stage grows
+/// alternating trees from free nodes along tight edges, forming and expanding blossoms
+/// and updating dual variables until some augmenting path is found or the duals of nodes
+/// reach zero, at which point the matching is optimal.
+///
+/// Edge weights are computed once per edge via the given `weight` function.
+/// Edges with non-positive weights are never matched, and loops are ignored.
+/// Integer weights are handled exactly, without any rounding.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn max_weight_matching<G, W, F>(graph: G, mut weight: F) -> MatchingOf<G>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints + DataRef,
+    W: Weight + Div<Output = W>,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let (nodes, positions) = compact(&graph);
+
+    let mut edges = Vec::new();
+    let mut identifiers = Vec::new();
+
+    for (position, &node) in nodes.iter().enumerate() {
+        for edge in graph.edges(node) {
+            let other = graph.opposite(edge, node).expect(EDGE);
+
+            let other_position = positions[graph.node_index(other)];
+
+            // every edge is seen from both of its endpoints, and loops are never matched
+            if position < other_position {
+                let value = weight(graph.edge_value(edge).expect(EDGE));
+
+                // matching edges with non-positive weights never increases the total weight
+                if value.compare(&W::ZERO).is_gt() {
+                    edges.push((position, other_position, value));
+                    identifiers.push(edge);
+                }
+            }
+        }
+    }
+
+    let mut blossoms = Blossoms::new(nodes.len(), edges);
+
+    blossoms.solve();
+
+    let pairs = blossoms
+        .mates
+        .iter()
+        .enumerate()
+        .filter(|&(_, end)| !end.is_limit())
+        .map(|(position, &end)| (position, blossoms.endpoint(end), end / 2))
+        .filter(|&(position, other, _)| position < other)
+        .map(|(position, other, edge)| (position, other, identifiers[edge]))
+        .collect();
+
+    collect(&graph, &nodes, pairs)
+}
+
+/// Represents Edmonds' maximum-cardinality matching algorithm.
+///
+/// See [`edmonds`] for more information.
+pub struct Edmonds;
+
+impl<G> Algorithm<G> for Edmonds
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints,
+{
+    type Output = MatchingOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        edmonds(graph)
+    }
+}
+
+/// Represents the weighted blossom algorithm.
+///
+/// See [`max_weight_matching`] for more information.
+pub struct MaxWeightMatching<F> {
+    weight: F,
+}
+
+impl<F> MaxWeightMatching<F> {
+    /// Constructs [`Self`] with the given `weight` function.
+    pub const fn new(weight: F) -> Self {
+        Self { weight }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for MaxWeightMatching<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + Edges + Endpoints + DataRef,
+    W: Weight + Div<Output = W>,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = MatchingOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        max_weight_matching(graph, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::UnGraph;
+
+    /// Checks that the matching is consistent, returning its matched pairs.
+    fn pairs<G>(graph: &G, matching: &MatchingOf<G>) -> Vec<(usize, usize)>
+    where
+        G: Undirected + NodeIdentifiers + NodeIndexed + Endpoints,
+    {
+        let mut pairs = Vec::new();
+
+        for &edge in &matching.edges {
+            let (one, two) = graph.endpoints(edge).unwrap();
+
+            let (one, two) = (graph.node_index(one), graph.node_index(two));
+
+            assert_ne!(one, two);
+
+            assert_eq!(
+                matching.mate_of(one).map(|node| graph.node_index(node)),
+                Some(two)
+            );
+            assert_eq!(
+                matching.mate_of(two).map(|node| graph.node_index(node)),
+                Some(one)
+            );
+
+            pairs.push((one.min(two), one.max(two)));
+        }
+
+        pairs.sort_unstable();
+
+        let matched = matching.mates.iter().flatten().count();
+
+        assert_eq!(matched, 2 * pairs.len());
+
+        pairs
+    }
+
+    #[test]
+    fn bipartite() {
+        let graph = UnGraph::unweighted(6, &[(0, 3), (1, 3), (2, 3), (2, 4), (0, 3)]);
+
+        let BipartiteMatching { matching, cover } = hopcroft_karp(&graph).unwrap();
+
+        let pairs = pairs(&graph, &matching);
+
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs.contains(&(2, 4)));
+
+        let mut cover: Vec<_> = cover.iter().map(|node| node.get()).collect();
+
+        cover.sort_unstable();
+
+        assert_eq!(cover, [2, 3]);
+    }
+
+    #[test]
+    fn perfect() {
+        let graph = UnGraph::unweighted(6, &[(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)]);
+
+        let matching = hopcroft_karp_with(&graph, |node| node.get() < 3).matching;
+
+        assert_eq!(pairs(&graph, &matching), [(0, 4), (1, 3), (2, 5)]);
+    }
+
+    #[test]
+    fn not_bipartite() {
+        let graph = UnGraph::unweighted(3, &[(0, 1), (1, 2), (2, 0)]);
+
+        assert_eq!(hopcroft_karp(&graph), Err(NotBipartite));
+    }
+
+    #[test]
+    fn non_positive() {
+        let graph = UnGraph::weighted(3, [(2, 0, 0), (2, 2, 6), (0, 1, -1)]);
+
+        let matching = max_weight_matching(&graph, |&weight| weight);
+
+        assert!(matching.is_empty());
+    }
+
+    #[test]
+    fn general() {
+        // the odd cycle forms a blossom, which has to be contracted to match everything
+        let graph =
+            UnGraph::unweighted(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 5), (6, 6)]);
+
+        let matching = edmonds(&graph);
+
+        let pairs = pairs(&graph, &matching);
+
+        assert_eq!(pairs.len(), 3);
+        assert!(pairs.contains(&(0, 5)));
+        assert_eq!(matching.mate_of(6), None);
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = UnGraph::weighted(4, [(0, 1, 5), (1, 2, 11), (2, 3, 5)]);
+
+        let matching = max_weight_matching(&graph, |&weight| weight);
+
+        assert_eq!(pairs(&graph, &matching), [(1, 2)]);
+
+        let graph = UnGraph::weighted(
+            6,
+            [
+                (0, 1, 6),
+                (1, 2, 6),
+                (2, 0, 6),
+                (2, 3, 7),
+                (0, 4, 2),
+                (4, 5, 3),
+            ],
+        );
+
+        let matching = max_weight_matching(&graph, |&weight| weight);
+
+        assert_eq!(pairs(&graph, &matching), [(0, 1), (2, 3), (4, 5)]);
+    }
+}
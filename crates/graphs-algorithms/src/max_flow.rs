@@ -0,0 +1,645 @@
+//! Maximum flows and minimum cuts of directed graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Directed, Undirected},
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::EdgeIdentifiers,
+    indexed::{EdgeIndexed, NodeIndexed},
+    limit::Limited,
+};
+
+use crate::{EDGE, weight::Weight};
+
+/// Represents maximum flows along with minimum cuts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MaxFlow<N, W> {
+    /// The total flow from the source to the sink.
+    pub value: W,
+
+    /// The flow through every edge, indexed by edge indices.
+    ///
+    /// Edge indices not present in the graph (as well as loops) have zero flow.
+    pub flows: Vec<W>,
+
+    /// The source side of some minimum cut, that is, the nodes reachable from the source
+    /// in the residual graph. The capacities of edges leaving it sum up to the flow value.
+    pub cut: Vec<N>,
+}
+
+impl<N, W> MaxFlow<N, W> {
+    /// Constructs [`Self`].
+    pub const fn new(value: W, flows: Vec<W>, cut: Vec<N>) -> Self {
+        Self { value, flows, cut }
+    }
+}
+
+/// Represents [`MaxFlow`] of the graph `G`, with edge capacities `W`.
+pub type MaxFlowOf<G, W> = MaxFlow<<G as Base>::NodeId, W>;
+
+/// Residual networks, where every edge is represented by the pair of arcs `2 * i` and `2 * i + 1`,
+/// which means that the reverse of any arc `a` is `a ^ 1`.
+///
+/// Since every edge gets its own pair of arcs, parallel and antiparallel edges are never mixed up.
+pub(crate) struct Network<W> {
+    /// The head of every arc.
+    pub(crate) heads: Vec<usize>,
+
+    /// The residual capacity of every arc.
+    pub(crate) residuals: Vec<W>,
+
+    /// The edge index of every pair of arcs.
+    pub(crate) edges: Vec<usize>,
+
+    /// The arcs leaving every node are `arcs[offsets[node]..offsets[node + 1]]`.
+    pub(crate) offsets: Vec<usize>,
+    pub(crate) arcs: Vec<usize>,
+}
+
+impl<W: Weight> Network<W> {
+    /// Builds the residual network of the given directed graph.
+    pub(crate) fn new<G, F>(graph: &G, capacity: F) -> Self
+    where
+        G: Directed + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+        F: FnMut(&G::EdgeValue) -> W,
+    {
+        Self::build(graph, capacity, false)
+    }
+
+    /// Builds the residual network of the given undirected graph,
+    /// where both arcs of every edge are given its capacity.
+    pub(crate) fn symmetric<G, F>(graph: &G, capacity: F) -> Self
+    where
+        G: Undirected + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+        F: FnMut(&G::EdgeValue) -> W,
+    {
+        Self::build(graph, capacity, true)
+    }
+
+    fn build<G, F>(graph: &G, mut capacity: F, symmetric: bool) -> Self
+    where
+        G: NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+        F: FnMut(&G::EdgeValue) -> W,
+    {
+        let bound = graph.node_bound();
+
+        let mut heads = Vec::new();
+        let mut residuals = Vec::new();
+        let mut edges = Vec::new();
+
+        let mut offsets = vec![0; bound + 1];
+
+        for edge in graph.edge_identifiers() {
+            let (source, target) = graph.endpoints(edge).expect(EDGE);
+
+            let source = graph.node_index(source);
+            let target = graph.node_index(target);
+
+            // loops never carry any useful flow
+            if source == target {
+                continue;
+            }
+
+            let value = capacity(graph.edge_value(edge).expect(EDGE));
+
+            heads.push(target);
+            residuals.push(value);
+
+            heads.push(source);
+            residuals.push(if symmetric { value } else { W::ZERO });
+
+            edges.push(graph.edge_index(edge));
+
+            offsets[source + 1] += 1;
+            offsets[target + 1] += 1;
+        }
+
+        for node in 0..bound {
+            offsets[node + 1] += offsets[node];
+        }
+
+        let mut fill = offsets.clone();
+
+        let mut arcs = vec![0; heads.len()];
+
+        for arc in 0..heads.len() {
+            // the tail of any arc is the head of its reverse
+            let tail = heads[arc ^ 1];
+
+            arcs[fill[tail]] = arc;
+            fill[tail] += 1;
+        }
+
+        Self {
+            heads,
+            residuals,
+            edges,
+            offsets,
+            arcs,
+        }
+    }
+
+    pub(crate) fn bound(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub(crate) fn arcs(&self, node: usize) -> &[usize] {
+        &self.arcs[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    pub(crate) fn has_residual(&self, arc: usize) -> bool {
+        self.residuals[arc] > W::ZERO
+    }
+
+    pub(crate) fn push(&mut self, arc: usize, amount: W) {
+        self.residuals[arc] = self.residuals[arc] - amount;
+        self.residuals[arc ^ 1] = self.residuals[arc ^ 1] + amount;
+    }
+
+    /// Computes distances from the `source` along arcs with positive residual capacities.
+    ///
+    /// Unreachable nodes have distance [`usize::LIMIT`].
+    pub(crate) fn distances(
+        &self,
+        source: usize,
+        distances: &mut [usize],
+        queue: &mut VecDeque<usize>,
+    ) {
+        distances.fill(usize::LIMIT);
+
+        distances[source] = 0;
+
+        queue.clear();
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            for &arc in self.arcs(node) {
+                let head = self.heads[arc];
+
+                if self.has_residual(arc) && distances[head].is_limit() {
+                    distances[head] = distances[node] + 1;
+
+                    queue.push_back(head);
+                }
+            }
+        }
+    }
+
+    /// Pushes the maximum flow from `source` to `sink` using Dinic's algorithm, returning its value.
+    pub(crate) fn dinic(&mut self, source: usize, sink: usize) -> W {
+        let bound = self.bound();
+
+        let mut levels = vec![usize::LIMIT; bound];
+        let mut queue = VecDeque::new();
+
+        let mut current = vec![0; bound];
+        let mut path = Vec::new();
+
+        let mut value = W::ZERO;
+
+        loop {
+            self.distances(source, &mut levels, &mut queue);
+
+            if levels[sink].is_limit() {
+                break;
+            }
+
+            current.copy_from_slice(&self.offsets[..bound]);
+
+            path.clear();
+
+            loop {
+                let node = path.last().map_or(source, |&arc| self.heads[arc]);
+
+                if node == sink {
+                    // augment along the path, then retreat to the first saturated arc
+
+                    let amount = path
+                        .iter()
+                        .map(|&arc| self.residuals[arc])
+                        .reduce(min)
+                        .expect("expected non-empty path");
+
+                    for &arc in &path {
+                        self.push(arc, amount);
+                    }
+
+                    value = value + amount;
+
+                    let saturated = path
+                        .iter()
+                        .position(|&arc| !self.has_residual(arc))
+                        .unwrap_or(path.len());
+
+                    path.truncate(saturated);
+
+                    continue;
+                }
+
+                // advance along the current arc, skipping arcs outside of the level graph
+
+                let end = self.offsets[node + 1];
+
+                while current[node] < end {
+                    let arc = self.arcs[current[node]];
+
+                    let head = self.heads[arc];
+
+                    if self.has_residual(arc) && levels[head] == levels[node] + 1 {
+                        break;
+                    }
+
+                    current[node] += 1;
+                }
+
+                if current[node] < end {
+                    path.push(self.arcs[current[node]]);
+
+                    continue;
+                }
+
+                // dead end, so remove the node from the level graph and retreat
+
+                levels[node] = usize::LIMIT;
+
+                let Some(arc) = path.pop() else {
+                    break;
+                };
+
+                current[self.heads[arc ^ 1]] += 1;
+            }
+        }
+
+        value
+    }
+
+    fn finish<G: NodeIndexed + EdgeIndexed>(
+        &self,
+        graph: &G,
+        source: usize,
+        value: W,
+    ) -> MaxFlowOf<G, W> {
+        let mut flows = vec![W::ZERO; graph.edge_bound()];
+
+        for (pair, &edge) in self.edges.iter().enumerate() {
+            // the flow through any edge is the residual capacity of its reverse arc
+            flows[edge] = self.residuals[2 * pair + 1];
+        }
+
+        let mut distances = vec![usize::LIMIT; self.bound()];
+
+        self.distances(source, &mut distances, &mut VecDeque::new());
+
+        let cut = distances
+            .iter()
+            .enumerate()
+            .filter(|(_, distance)| !distance.is_limit())
+            .map(|(index, _)| graph.node_id(index))
+            .collect();
+
+        MaxFlow::new(value, flows, cut)
+    }
+}
+
+fn min<W: Weight>(one: W, two: W) -> W {
+    if two.compare(&one).is_lt() { two } else { one }
+}
+
+/// Finds the maximum flow from `source` to `sink` in the given graph using Dinic's algorithm.
+///
+/// Each phase builds the level graph via breadth-first search from the `source`,
+/// and then saturates it with a blocking flow found via depth-first searches
+/// that never revisit dead ends. There are at most `V` phases.
+///
+/// Edge capacities are computed once per edge via the given `capacity` function.
+///
+/// # Panics
+///
+/// Panics if `source` and `sink` are the same node,
+/// or if the graph yields edges that are missing their endpoints or values.
+pub fn dinic<G, W, F>(graph: G, source: G::NodeId, sink: G::NodeId, capacity: F) -> MaxFlowOf<G, W>
+where
+    G: Directed + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    assert!(source != sink, "expected source and sink to be different");
+
+    let mut network = Network::new(&graph, capacity);
+
+    let source = graph.node_index(source);
+    let sink = graph.node_index(sink);
+
+    let value = network.dinic(source, sink);
+
+    network.finish(&graph, source, value)
+}
+
+/// Finds the maximum flow from `source` to `sink` in the given graph
+/// using the highest-label push-relabel algorithm with the gap heuristic.
+///
+/// Active nodes are discharged in the order of decreasing heights, which bounds
+/// the running time by `O(V^2 sqrt(E))`. Whenever some height below `V` becomes empty,
+/// all nodes above it are lifted over `V`, since they can no longer reach the `sink`.
+///
+/// Edge capacities are computed once per edge via the given `capacity` function.
+///
+/// # Panics
+///
+/// Panics if `source` and `sink` are the same node,
+/// or if the graph yields edges that are missing their endpoints or values.
+pub fn push_relabel<G, W, F>(
+    graph: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    capacity: F,
+) -> MaxFlowOf<G, W>
+where
+    G: Directed + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    assert!(source != sink, "expected source and sink to be different");
+
+    let mut network = Network::new(&graph, capacity);
+
+    let bound = network.bound();
+
+    let source = graph.node_index(source);
+    let sink = graph.node_index(sink);
+
+    let mut heights = vec![0; bound];
+    let mut excesses = vec![W::ZERO; bound];
+    let mut current = network.offsets[..bound].to_vec();
+
+    // heights never exceed `2V`, see the gap heuristic below
+    let mut counts = vec![0usize; 2 * bound + 1];
+    let mut buckets = vec![Vec::new(); 2 * bound + 1];
+
+    heights[source] = bound;
+    counts[0] = bound - 1;
+
+    // pushes `amount` along `arc`, activating its head if needed
+    let push = |network: &mut Network<W>,
+                excesses: &mut [W],
+                buckets: &mut [Vec<usize>],
+                heights: &[usize],
+                arc: usize,
+                amount: W| {
+        let head = network.heads[arc];
+        let tail = network.heads[arc ^ 1];
+
+        if head != source && head != sink && excesses[head] <= W::ZERO && amount > W::ZERO {
+            buckets[heights[head]].push(head);
+        }
+
+        network.push(arc, amount);
+
+        excesses[head] = excesses[head] + amount;
+
+        // the excess of the source is never tracked, which keeps unsigned weights working
+        if tail != source {
+            excesses[tail] = excesses[tail] - amount;
+        }
+    };
+
+    for index in network.offsets[source]..network.offsets[source + 1] {
+        let arc = network.arcs[index];
+
+        let amount = network.residuals[arc];
+
+        push(
+            &mut network,
+            &mut excesses,
+            &mut buckets,
+            &heights,
+            arc,
+            amount,
+        );
+    }
+
+    let mut highest = 0;
+
+    loop {
+        while buckets[highest].is_empty() {
+            if highest == 0 {
+                return network.finish(&graph, source, excesses[sink]);
+            }
+
+            highest -= 1;
+        }
+
+        let node = buckets[highest].pop().expect("expected active node");
+
+        // the node could have been lifted over by the gap heuristic after being activated
+        if heights[node] != highest {
+            buckets[heights[node]].push(node);
+
+            highest = heights[node];
+
+            continue;
+        }
+
+        while excesses[node] > W::ZERO {
+            let end = network.offsets[node + 1];
+
+            if current[node] == end {
+                // relabel the node to be just above its lowest residual neighbor
+
+                let old = heights[node];
+
+                let mut height = usize::LIMIT;
+
+                for index in network.offsets[node]..end {
+                    let arc = network.arcs[index];
+
+                    let candidate = heights[network.heads[arc]] + 1;
+
+                    if network.has_residual(arc) && candidate < height {
+                        height = candidate;
+
+                        current[node] = index;
+                    }
+                }
+
+                heights[node] = height;
+
+                counts[height] += 1;
+                counts[old] -= 1;
+
+                if counts[old] == 0 && old < bound {
+                    // gap: nodes above it can not reach the sink anymore
+
+                    for other in 0..bound {
+                        if old < heights[other] && heights[other] < bound {
+                            counts[heights[other]] -= 1;
+
+                            heights[other] = bound + 1;
+
+                            counts[bound + 1] += 1;
+                        }
+                    }
+                }
+
+                highest = heights[node];
+
+                continue;
+            }
+
+            let arc = network.arcs[current[node]];
+
+            let head = network.heads[arc];
+
+            if network.has_residual(arc) && heights[node] == heights[head] + 1 {
+                let amount = min(excesses[node], network.residuals[arc]);
+
+                push(
+                    &mut network,
+                    &mut excesses,
+                    &mut buckets,
+                    &heights,
+                    arc,
+                    amount,
+                );
+            } else {
+                current[node] += 1;
+            }
+        }
+    }
+}
+
+/// Represents Dinic's maximum flow algorithm.
+///
+/// See [`dinic`] for more information.
+pub struct Dinic<N, F> {
+    source: N,
+    sink: N,
+    capacity: F,
+}
+
+impl<N, F> Dinic<N, F> {
+    /// Constructs [`Self`] with the given `source`, `sink` and `capacity` function.
+    pub const fn new(source: N, sink: N, capacity: F) -> Self {
+        Self {
+            source,
+            sink,
+            capacity,
+        }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for Dinic<G::NodeId, F>
+where
+    G: Directed + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = MaxFlowOf<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        dinic(graph, self.source, self.sink, &mut self.capacity)
+    }
+}
+
+/// Represents the highest-label push-relabel maximum flow algorithm.
+///
+/// See [`push_relabel`] for more information.
+pub struct PushRelabel<N, F> {
+    source: N,
+    sink: N,
+    capacity: F,
+}
+
+impl<N, F> PushRelabel<N, F> {
+    /// Constructs [`Self`] with the given `source`, `sink` and `capacity` function.
+    pub const fn new(source: N, sink: N, capacity: F) -> Self {
+        Self {
+            source,
+            sink,
+            capacity,
+        }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for PushRelabel<G::NodeId, F>
+where
+    G: Directed + NodeIndexed + EdgeIdentifiers + EdgeIndexed + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = MaxFlowOf<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        push_relabel(graph, self.source, self.sink, &mut self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::DiGraph;
+
+    const EDGES: [(usize, usize, u32); 10] = [
+        (0, 1, 16),
+        (0, 2, 13),
+        (1, 3, 12),
+        (2, 1, 4),
+        (3, 2, 9),
+        (2, 4, 14),
+        (4, 3, 7),
+        (3, 5, 20),
+        (4, 5, 4),
+        (1, 1, 5),
+    ];
+
+    fn check(flow: &MaxFlow<NodeId<usize>, u32>) {
+        assert_eq!(flow.value, 23);
+
+        let mut balances = [0i64; 6];
+
+        for (&(source, target, capacity), &amount) in EDGES.iter().zip(&flow.flows) {
+            assert!(amount <= capacity);
+
+            balances[source] -= i64::from(amount);
+            balances[target] += i64::from(amount);
+        }
+
+        assert_eq!(balances, [-23, 0, 0, 0, 0, 23]);
+
+        let mut cut: Vec<_> = flow.cut.iter().map(|node| node.get()).collect();
+
+        cut.sort_unstable();
+
+        assert_eq!(cut, [0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn maximum_flow() {
+        let graph = DiGraph::weighted(6, EDGES);
+
+        let (source, sink) = (NodeId::new(0), NodeId::new(5));
+
+        check(&dinic(&graph, source, sink, |&capacity| capacity));
+        check(&push_relabel(&graph, source, sink, |&capacity| capacity));
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DiGraph::weighted(3, [(0, 1, 5), (2, 1, 5)]);
+
+        let flow = dinic(&graph, NodeId::new(0), NodeId::new(2), |&capacity| capacity);
+
+        assert_eq!(flow.value, 0);
+        assert_eq!(flow.cut.len(), 2);
+    }
+}
@@ -0,0 +1,1025 @@
+//! Minimum-cost flows of directed graphs.
+
+use core::{
+    cmp::Reverse,
+    ops::{Mul, Neg},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Directed,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::{EdgeIndexed, NodeIndexed},
+    limit::Limited,
+};
+use thiserror::Error;
+
+use crate::{EDGE, NODE, heap::Entry, max_flow::Network, weight::Weight};
+
+/// Represents minimum-cost flows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinCostFlow<W> {
+    /// The flow through every edge, indexed by edge indices.
+    ///
+    /// Edge indices not present in the graph have zero flow.
+    pub flows: Vec<W>,
+
+    /// The total cost of the flow, that is, the sum of flows multiplied by costs.
+    pub cost: W,
+}
+
+impl<W> MinCostFlow<W> {
+    /// Constructs [`Self`].
+    pub const fn new(flows: Vec<W>, cost: W) -> Self {
+        Self { flows, cost }
+    }
+}
+
+/// Represents errors returned when supplies and demands can not be satisfied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+pub enum Infeasible {
+    /// The total supply is not equal to the total demand.
+    #[error("supplies and demands are not balanced")]
+    Unbalanced,
+    /// Capacities are insufficient to route supplies to demands.
+    #[error("capacities are insufficient to satisfy demands")]
+    Insufficient,
+}
+
+/// Represents results of [`successive_shortest_paths`] and [`network_simplex`].
+pub type Output<W> = Result<MinCostFlow<W>, Infeasible>;
+
+/// Minimum-cost flow problems built from graphs.
+struct Problem<W> {
+    /// The residual network, see [`Network`].
+    network: Network<W>,
+
+    /// The cost of every edge in the network, by pairs of arcs.
+    costs: Vec<W>,
+
+    /// The excess of every node, which is positive for supplies and negative for demands.
+    excesses: Vec<W>,
+
+    /// The flow through every edge, which is initially only set for loops.
+    flows: Vec<W>,
+
+    /// The total cost, which initially only accounts for loops.
+    cost: W,
+}
+
+impl<W: Weight + Neg<Output = W> + Mul<Output = W>> Problem<W> {
+    fn new<G, S, F, C>(graph: &G, mut supply: S, mut capacity: F, mut cost: C) -> Self
+    where
+        G: Directed
+            + NodeIdentifiers
+            + NodeIndexed
+            + EdgeIdentifiers
+            + EdgeIndexed
+            + Endpoints
+            + DataRef,
+        S: FnMut(&G::NodeValue) -> W,
+        F: FnMut(&G::EdgeValue) -> W,
+        C: FnMut(&G::EdgeValue) -> W,
+    {
+        let network = Network::new(graph, &mut capacity);
+
+        let costs = network
+            .edges
+            .iter()
+            .map(|&index| cost(graph.edge_value(graph.edge_id(index)).expect(EDGE)))
+            .collect();
+
+        let mut excesses = vec![W::ZERO; graph.node_bound()];
+
+        for node in graph.node_identifiers() {
+            let value = graph.node_value(node).expect(NODE);
+
+            excesses[graph.node_index(node)] = supply(value);
+        }
+
+        let mut flows = vec![W::ZERO; graph.edge_bound()];
+
+        let mut total = W::ZERO;
+
+        // loops are not present in the network, and the negative ones are simply saturated
+
+        for edge in graph.edge_identifiers() {
+            let (source, target) = graph.endpoints(edge).expect(EDGE);
+
+            if source != target {
+                continue;
+            }
+
+            let value = graph.edge_value(edge).expect(EDGE);
+
+            let cost = cost(value);
+
+            if cost < W::ZERO {
+                let amount = capacity(value);
+
+                flows[graph.edge_index(edge)] = amount;
+
+                total = total + amount * cost;
+            }
+        }
+
+        Self {
+            network,
+            costs,
+            excesses,
+            flows,
+            cost: total,
+        }
+    }
+
+    fn check_balance(&self) -> Result<(), Infeasible> {
+        let mut balance = W::ZERO;
+
+        for &excess in &self.excesses {
+            balance = balance + excess;
+        }
+
+        if balance == W::ZERO {
+            Ok(())
+        } else {
+            Err(Infeasible::Unbalanced)
+        }
+    }
+
+    /// Returns the cost of the given arc, which is negated for reverse arcs.
+    fn arc_cost(&self, arc: usize) -> W {
+        let cost = self.costs[arc / 2];
+
+        if arc.is_multiple_of(2) { cost } else { -cost }
+    }
+
+    /// Collects flows from the residual network and computes the total cost.
+    fn finish(mut self) -> MinCostFlow<W> {
+        for (pair, &edge) in self.network.edges.iter().enumerate() {
+            let flow = self.network.residuals[2 * pair + 1];
+
+            self.flows[edge] = flow;
+
+            self.cost = self.cost + flow * self.costs[pair];
+        }
+
+        MinCostFlow::new(self.flows, self.cost)
+    }
+}
+
+fn successive<W: Weight + Neg<Output = W> + Mul<Output = W>>(mut problem: Problem<W>) -> Output<W> {
+    problem.check_balance()?;
+
+    let bound = problem.network.bound();
+
+    // saturate negative edges, so that all residual arcs have non-negative costs
+
+    for pair in 0..problem.costs.len() {
+        let arc = 2 * pair;
+
+        if problem.costs[pair] < W::ZERO {
+            let amount = problem.network.residuals[arc];
+
+            let head = problem.network.heads[arc];
+            let tail = problem.network.heads[arc ^ 1];
+
+            problem.network.push(arc, amount);
+
+            problem.excesses[head] = problem.excesses[head] + amount;
+            problem.excesses[tail] = problem.excesses[tail] - amount;
+        }
+    }
+
+    let mut potentials = vec![W::ZERO; bound];
+    let mut distances = vec![W::ZERO; bound];
+    let mut previous = vec![usize::LIMIT; bound];
+
+    // nodes reached and settled during the current search are marked with its number
+    let mut reached = vec![usize::LIMIT; bound];
+    let mut settled = vec![usize::LIMIT; bound];
+
+    let mut order = Vec::new();
+
+    let mut heap = BinaryHeap::new();
+
+    let mut search = 0;
+
+    for start in 0..bound {
+        while problem.excesses[start] > W::ZERO {
+            // find the closest node with some demand using reduced costs
+
+            heap.clear();
+            order.clear();
+
+            distances[start] = W::ZERO;
+            reached[start] = search;
+
+            heap.push(Reverse(Entry::new(W::ZERO, start)));
+
+            let mut found = usize::LIMIT;
+
+            while let Some(Reverse(Entry {
+                key: distance,
+                value: node,
+            })) = heap.pop()
+            {
+                if settled[node] == search {
+                    continue;
+                }
+
+                settled[node] = search;
+
+                order.push(node);
+
+                if problem.excesses[node] < W::ZERO {
+                    found = node;
+
+                    break;
+                }
+
+                for &arc in problem.network.arcs(node) {
+                    let head = problem.network.heads[arc];
+
+                    if !problem.network.has_residual(arc) || settled[head] == search {
+                        continue;
+                    }
+
+                    let reduced = problem.arc_cost(arc) + potentials[node] - potentials[head];
+
+                    let candidate = distance + reduced;
+
+                    if reached[head] != search || candidate < distances[head] {
+                        distances[head] = candidate;
+                        reached[head] = search;
+                        previous[head] = arc;
+
+                        heap.push(Reverse(Entry::new(candidate, head)));
+                    }
+                }
+            }
+
+            if found.is_limit() {
+                return Err(Infeasible::Insufficient);
+            }
+
+            // keep reduced costs non-negative, only settled nodes are closer than the target
+
+            let farthest = distances[found];
+
+            for &node in &order {
+                potentials[node] = potentials[node] + distances[node] - farthest;
+            }
+
+            let mut amount = if -problem.excesses[found] < problem.excesses[start] {
+                -problem.excesses[found]
+            } else {
+                problem.excesses[start]
+            };
+
+            let mut node = found;
+
+            while node != start {
+                let arc = previous[node];
+
+                let residual = problem.network.residuals[arc];
+
+                if residual < amount {
+                    amount = residual;
+                }
+
+                node = problem.network.heads[arc ^ 1];
+            }
+
+            let mut node = found;
+
+            while node != start {
+                let arc = previous[node];
+
+                problem.network.push(arc, amount);
+
+                node = problem.network.heads[arc ^ 1];
+            }
+
+            problem.excesses[start] = problem.excesses[start] - amount;
+            problem.excesses[found] = problem.excesses[found] + amount;
+
+            search += 1;
+        }
+    }
+
+    Ok(problem.finish())
+}
+
+/// Finds the minimum-cost flow satisfying node supplies using successive shortest paths.
+///
+/// Node supplies are computed via the given `supply` function, positive values being
+/// supplies and negative values being demands. Setting all supplies to zero gives
+/// minimum-cost circulations.
+///
+/// Edges with negative costs are saturated upfront, after which flow is repeatedly sent
+/// from supplies to the closest demands, found via Dijkstra's algorithm on reduced costs
+/// maintained with node potentials.
+///
+/// Edge capacities and costs are computed once per edge via the given `capacity`
+/// and `cost` functions. Capacities must be finite.
+///
+/// # Errors
+///
+/// Returns [`Infeasible`] if supplies and demands are not balanced,
+/// or if they can not be satisfied due to insufficient capacities.
+///
+/// # Panics
+///
+/// Panics if the graph yields nodes or edges that are missing their endpoints or values.
+pub fn successive_shortest_paths<G, W, S, F, C>(
+    graph: G,
+    supply: S,
+    capacity: F,
+    cost: C,
+) -> Output<W>
+where
+    G: Directed
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight + Neg<Output = W> + Mul<Output = W>,
+    S: FnMut(&G::NodeValue) -> W,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    successive(Problem::new(&graph, supply, capacity, cost))
+}
+
+/// Finds the maximum flow from `source` to `sink` in the given graph having the minimum cost.
+///
+/// The flow value is found via [`dinic`], after which the cheapest way to route it
+/// is found via [`successive_shortest_paths`], see them for more information.
+///
+/// [`dinic`]: crate::max_flow::dinic
+///
+/// # Panics
+///
+/// Panics if `source` and `sink` are the same node,
+/// or if the graph yields edges that are missing their endpoints or values.
+pub fn min_cost_max_flow<G, W, F, C>(
+    graph: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    mut capacity: F,
+    cost: C,
+) -> MinCostFlow<W>
+where
+    G: Directed
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight + Neg<Output = W> + Mul<Output = W>,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    let value = crate::max_flow::dinic(&graph, source, sink, &mut capacity).value;
+
+    let mut problem = Problem::new(&graph, |_| W::ZERO, capacity, cost);
+
+    problem.excesses[graph.node_index(source)] = value;
+    problem.excesses[graph.node_index(sink)] = -value;
+
+    successive(problem).expect("expected maximum flow to be feasible")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Lower,
+    Upper,
+    Tree,
+}
+
+/// Spanning tree structures of network simplex, rooted at the artificial node.
+struct Tree<W> {
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    capacities: Vec<W>,
+    costs: Vec<W>,
+    flows: Vec<W>,
+    states: Vec<State>,
+
+    parents: Vec<usize>,
+    /// The arc connecting every node to its parent.
+    predecessors: Vec<usize>,
+    /// Whether the predecessor arc of every node points to its parent.
+    up: Vec<bool>,
+    depths: Vec<usize>,
+    potentials: Vec<W>,
+
+    children: Vec<usize>,
+    next: Vec<usize>,
+    previous: Vec<usize>,
+}
+
+impl<W: Weight + Neg<Output = W>> Tree<W> {
+    fn reduced(&self, arc: usize) -> W {
+        self.costs[arc] + self.potentials[self.sources[arc]] - self.potentials[self.targets[arc]]
+    }
+
+    fn attach(&mut self, node: usize, parent: usize) {
+        let first = self.children[parent];
+
+        self.next[node] = first;
+        self.previous[node] = usize::LIMIT;
+
+        if !first.is_limit() {
+            self.previous[first] = node;
+        }
+
+        self.children[parent] = node;
+        self.parents[node] = parent;
+    }
+
+    fn detach(&mut self, node: usize) {
+        let (next, previous) = (self.next[node], self.previous[node]);
+
+        if previous.is_limit() {
+            self.children[self.parents[node]] = next;
+        } else {
+            self.next[previous] = next;
+        }
+
+        if !next.is_limit() {
+            self.previous[next] = previous;
+        }
+    }
+
+    /// Updates depths and potentials of the subtree rooted at the given `node`.
+    fn update(&mut self, node: usize, stack: &mut Vec<usize>) {
+        stack.push(node);
+
+        while let Some(node) = stack.pop() {
+            let parent = self.parents[node];
+            let arc = self.predecessors[node];
+
+            self.depths[node] = self.depths[parent] + 1;
+
+            // tree arcs have zero reduced costs
+            self.potentials[node] = if self.up[node] {
+                self.potentials[parent] - self.costs[arc]
+            } else {
+                self.potentials[parent] + self.costs[arc]
+            };
+
+            let mut child = self.children[node];
+
+            while !child.is_limit() {
+                stack.push(child);
+
+                child = self.next[child];
+            }
+        }
+    }
+
+    fn join(&self, mut one: usize, mut two: usize) -> usize {
+        while one != two {
+            if self.depths[one] < self.depths[two] {
+                two = self.parents[two];
+            } else {
+                one = self.parents[one];
+            }
+        }
+
+        one
+    }
+
+    fn pivot(
+        &mut self,
+        entering: usize,
+        stack: &mut Vec<usize>,
+        path: &mut Vec<(usize, usize, bool)>,
+    ) {
+        let lower = self.states[entering] == State::Lower;
+
+        let (first, second) = if lower {
+            (self.sources[entering], self.targets[entering])
+        } else {
+            (self.targets[entering], self.sources[entering])
+        };
+
+        let join = self.join(first, second);
+
+        // find the leaving arc, preferring the last blocking one to keep the tree strongly feasible
+
+        let mut delta = self.capacities[entering];
+
+        let mut leaving = usize::LIMIT;
+        let mut side = 0;
+
+        let mut node = first;
+
+        while node != join {
+            let arc = self.predecessors[node];
+
+            let residual = if self.up[node] {
+                self.flows[arc]
+            } else {
+                self.capacities[arc] - self.flows[arc]
+            };
+
+            if residual < delta {
+                delta = residual;
+                leaving = node;
+                side = 1;
+            }
+
+            node = self.parents[node];
+        }
+
+        let mut node = second;
+
+        while node != join {
+            let arc = self.predecessors[node];
+
+            let residual = if self.up[node] {
+                self.capacities[arc] - self.flows[arc]
+            } else {
+                self.flows[arc]
+            };
+
+            if residual <= delta {
+                delta = residual;
+                leaving = node;
+                side = 2;
+            }
+
+            node = self.parents[node];
+        }
+
+        // augment along the cycle
+
+        if delta > W::ZERO {
+            self.flows[entering] = if lower {
+                self.flows[entering] + delta
+            } else {
+                self.flows[entering] - delta
+            };
+
+            for (start, forward) in [(first, false), (second, true)] {
+                let mut node = start;
+
+                while node != join {
+                    let arc = self.predecessors[node];
+
+                    self.flows[arc] = if self.up[node] == forward {
+                        self.flows[arc] + delta
+                    } else {
+                        self.flows[arc] - delta
+                    };
+
+                    node = self.parents[node];
+                }
+            }
+        }
+
+        if leaving.is_limit() {
+            // the entering arc is blocking itself, so it just goes to its other bound
+
+            self.states[entering] = if lower { State::Upper } else { State::Lower };
+
+            return;
+        }
+
+        let arc = self.predecessors[leaving];
+
+        self.states[arc] = if self.flows[arc] > W::ZERO {
+            State::Upper
+        } else {
+            State::Lower
+        };
+
+        self.states[entering] = State::Tree;
+
+        let (inside, outside) = if side == 1 {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        // reverse the path from the inside endpoint to the leaving node,
+        // hanging the subtree of the leaving node onto the outside endpoint
+
+        path.clear();
+
+        let mut node = inside;
+
+        loop {
+            path.push((node, self.predecessors[node], self.up[node]));
+
+            if node == leaving {
+                break;
+            }
+
+            node = self.parents[node];
+        }
+
+        for &(node, _, _) in path.iter() {
+            self.detach(node);
+        }
+
+        self.attach(inside, outside);
+
+        self.predecessors[inside] = entering;
+        self.up[inside] = self.sources[entering] == inside;
+
+        for index in 1..path.len() {
+            let (parent, arc, up) = path[index - 1];
+
+            let (node, _, _) = path[index];
+
+            self.attach(node, parent);
+
+            // the arc connecting the node to its old child now connects it to its new parent
+            self.predecessors[node] = arc;
+            self.up[node] = !up;
+        }
+
+        self.update(inside, stack);
+    }
+}
+
+/// Finds the minimum-cost flow satisfying node supplies using the primal network simplex.
+///
+/// Node supplies are computed via the given `supply` function, positive values being
+/// supplies and negative values being demands. Setting all supplies to zero gives
+/// minimum-cost circulations.
+///
+/// The initial spanning tree consists of artificial edges connecting every node to
+/// an artificial root, with costs high enough to drive flow out of them whenever possible.
+/// Entering edges are chosen via block search, and leaving edges are chosen
+/// such that the tree stays strongly feasible, which prevents cycling.
+///
+/// Edge capacities and costs are computed once per edge via the given `capacity`
+/// and `cost` functions. Capacities must be finite.
+///
+/// # Errors
+///
+/// Returns [`Infeasible`] if supplies and demands are not balanced,
+/// or if they can not be satisfied due to insufficient capacities.
+///
+/// # Panics
+///
+/// Panics if the graph yields nodes or edges that are missing their endpoints or values.
+pub fn network_simplex<G, W, S, F, C>(graph: G, supply: S, capacity: F, cost: C) -> Output<W>
+where
+    G: Directed
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight + Neg<Output = W> + Mul<Output = W>,
+    S: FnMut(&G::NodeValue) -> W,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    let mut problem = Problem::new(&graph, supply, capacity, cost);
+
+    problem.check_balance()?;
+
+    let bound = problem.network.bound();
+    let root = bound;
+
+    let pairs = problem.costs.len();
+    let count = pairs + bound;
+
+    // artificial edges cost more than any simple path in the graph
+
+    let mut artificial = W::ZERO;
+
+    for &cost in &problem.costs {
+        artificial = artificial + if cost < W::ZERO { -cost } else { cost };
+    }
+
+    artificial = artificial + W::ONE;
+
+    let mut tree = Tree {
+        sources: Vec::with_capacity(count),
+        targets: Vec::with_capacity(count),
+        capacities: Vec::with_capacity(count),
+        costs: Vec::with_capacity(count),
+        flows: Vec::with_capacity(count),
+        states: Vec::with_capacity(count),
+
+        parents: vec![usize::LIMIT; bound + 1],
+        predecessors: vec![usize::LIMIT; bound + 1],
+        up: vec![false; bound + 1],
+        depths: vec![0; bound + 1],
+        potentials: vec![W::ZERO; bound + 1],
+
+        children: vec![usize::LIMIT; bound + 1],
+        next: vec![usize::LIMIT; bound + 1],
+        previous: vec![usize::LIMIT; bound + 1],
+    };
+
+    for pair in 0..pairs {
+        let arc = 2 * pair;
+
+        tree.sources.push(problem.network.heads[arc ^ 1]);
+        tree.targets.push(problem.network.heads[arc]);
+        tree.capacities.push(problem.network.residuals[arc]);
+        tree.costs.push(problem.costs[pair]);
+        tree.flows.push(W::ZERO);
+        tree.states.push(State::Lower);
+    }
+
+    for node in 0..bound {
+        let excess = problem.excesses[node];
+
+        let arc = tree.sources.len();
+
+        if excess < W::ZERO {
+            tree.sources.push(root);
+            tree.targets.push(node);
+            tree.flows.push(-excess);
+
+            tree.up[node] = false;
+            tree.potentials[node] = artificial;
+        } else {
+            tree.sources.push(node);
+            tree.targets.push(root);
+            tree.flows.push(excess);
+
+            tree.up[node] = true;
+            tree.potentials[node] = -artificial;
+        }
+
+        tree.capacities.push(W::MAX);
+        tree.costs.push(artificial);
+        tree.states.push(State::Tree);
+
+        tree.predecessors[node] = arc;
+        tree.depths[node] = 1;
+
+        tree.attach(node, root);
+    }
+
+    let mut block = 1;
+
+    while block * block < count {
+        block += 1;
+    }
+
+    let mut next = 0;
+
+    let mut stack = Vec::new();
+    let mut path = Vec::new();
+
+    loop {
+        // block search: pick the most violating arc among the first block containing any
+
+        let mut entering = usize::LIMIT;
+        let mut best = W::ZERO;
+
+        let mut arc = next;
+        let mut checked = 0;
+        let mut block_checked = 0;
+
+        while checked < count {
+            let violation = match tree.states[arc] {
+                State::Lower => tree.reduced(arc),
+                State::Upper => -tree.reduced(arc),
+                State::Tree => W::ZERO,
+            };
+
+            if violation < best {
+                best = violation;
+                entering = arc;
+            }
+
+            arc = (arc + 1) % count;
+
+            checked += 1;
+            block_checked += 1;
+
+            if block_checked == block {
+                if !entering.is_limit() {
+                    break;
+                }
+
+                block_checked = 0;
+            }
+        }
+
+        if entering.is_limit() {
+            break;
+        }
+
+        next = arc;
+
+        tree.pivot(entering, &mut stack, &mut path);
+    }
+
+    if tree.flows[pairs..].iter().any(|&flow| flow > W::ZERO) {
+        return Err(Infeasible::Insufficient);
+    }
+
+    for pair in 0..pairs {
+        problem.network.push(2 * pair, tree.flows[pair]);
+    }
+
+    Ok(problem.finish())
+}
+
+/// Represents the successive shortest paths minimum-cost flow algorithm.
+///
+/// See [`successive_shortest_paths`] for more information.
+pub struct SuccessiveShortestPaths<S, F, C> {
+    supply: S,
+    capacity: F,
+    cost: C,
+}
+
+impl<S, F, C> SuccessiveShortestPaths<S, F, C> {
+    /// Constructs [`Self`] with the given `supply`, `capacity` and `cost` functions.
+    pub const fn new(supply: S, capacity: F, cost: C) -> Self {
+        Self {
+            supply,
+            capacity,
+            cost,
+        }
+    }
+}
+
+impl<G, W, S, F, C> Algorithm<G> for SuccessiveShortestPaths<S, F, C>
+where
+    G: Directed
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight + Neg<Output = W> + Mul<Output = W>,
+    S: FnMut(&G::NodeValue) -> W,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Output<W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        successive_shortest_paths(graph, &mut self.supply, &mut self.capacity, &mut self.cost)
+    }
+}
+
+/// Represents the network simplex minimum-cost flow algorithm.
+///
+/// See [`network_simplex`] for more information.
+pub struct NetworkSimplex<S, F, C> {
+    supply: S,
+    capacity: F,
+    cost: C,
+}
+
+impl<S, F, C> NetworkSimplex<S, F, C> {
+    /// Constructs [`Self`] with the given `supply`, `capacity` and `cost` functions.
+    pub const fn new(supply: S, capacity: F, cost: C) -> Self {
+        Self {
+            supply,
+            capacity,
+            cost,
+        }
+    }
+}
+
+impl<G, W, S, F, C> Algorithm<G> for NetworkSimplex<S, F, C>
+where
+    G: Directed
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight + Neg<Output = W> + Mul<Output = W>,
+    S: FnMut(&G::NodeValue) -> W,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Output<W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        network_simplex(graph, &mut self.supply, &mut self.capacity, &mut self.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::id::NodeId;
+
+    use crate::testing::DiGraph;
+
+    type Arc = (i64, i64);
+
+    fn network(supplies: &[i64], edges: &[(usize, usize, Arc)]) -> DiGraph<Arc, i64> {
+        let mut graph = DiGraph::new();
+
+        for &supply in supplies {
+            graph.node(supply);
+        }
+
+        for &(source, target, arc) in edges {
+            graph.edge(source, target, arc);
+        }
+
+        graph
+    }
+
+    const EDGES: [(usize, usize, Arc); 5] = [
+        (0, 1, (3, 1)),
+        (0, 2, (2, 4)),
+        (1, 2, (2, 1)),
+        (1, 3, (2, 5)),
+        (2, 3, (4, 1)),
+    ];
+
+    fn solve(graph: &DiGraph<Arc, i64>) -> [Output<i64>; 2] {
+        [
+            successive_shortest_paths(
+                graph,
+                |&supply| supply,
+                |&(capacity, _)| capacity,
+                |&(_, cost)| cost,
+            ),
+            network_simplex(
+                graph,
+                |&supply| supply,
+                |&(capacity, _)| capacity,
+                |&(_, cost)| cost,
+            ),
+        ]
+    }
+
+    #[test]
+    fn minimum_cost() {
+        let graph = network(&[4, 0, 0, -4], &EDGES);
+
+        for output in solve(&graph) {
+            let flow = output.unwrap();
+
+            assert_eq!(flow.cost, 16);
+            assert_eq!(flow.flows, [2, 2, 2, 0, 4]);
+        }
+    }
+
+    #[test]
+    fn circulation() {
+        let graph = network(&[0, 0], &[(0, 1, (2, -3)), (1, 0, (1, 1)), (1, 1, (3, -1))]);
+
+        for output in solve(&graph) {
+            let flow = output.unwrap();
+
+            assert_eq!(flow.cost, -5);
+            assert_eq!(flow.flows, [1, 1, 3]);
+        }
+    }
+
+    #[test]
+    fn infeasible() {
+        for output in solve(&network(&[1, 0, 0, 0], &EDGES)) {
+            assert_eq!(output, Err(Infeasible::Unbalanced));
+        }
+
+        for output in solve(&network(&[6, 0, 0, -6], &EDGES)) {
+            assert_eq!(output, Err(Infeasible::Insufficient));
+        }
+    }
+
+    #[test]
+    fn maximum_flow() {
+        let graph = network(&[0; 4], &EDGES);
+
+        let flow = min_cost_max_flow(
+            &graph,
+            NodeId::new(0),
+            NodeId::new(3),
+            |&(capacity, _)| capacity,
+            |&(_, cost)| cost,
+        );
+
+        assert_eq!(flow.cost, 22);
+        assert_eq!(flow.flows[0] + flow.flows[1], 5);
+    }
+}
@@ -0,0 +1,483 @@
+//! Global minimum cuts and Gomory-Hu trees of undirected graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::collections::{BinaryHeap, VecDeque};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    build::Build,
+    create::Create,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::{EdgeIndexed, NodeIndexed},
+    limit::Limited,
+    recoverable::Recoverable,
+};
+use graphs_union_find::vec::UnionFind;
+
+use crate::{
+    EDGE,
+    build::{BuildError, BuildErrorOf},
+    heap::Entry,
+    max_flow::Network,
+    weight::Weight,
+};
+
+/// Represents minimum cuts, that is, partitions of nodes into two non-empty sides.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinCut<N, W> {
+    /// The total weight of edges crossing the cut.
+    pub weight: W,
+
+    /// The nodes on one side of the cut; the rest of the nodes are on the other side.
+    pub side: Vec<N>,
+}
+
+impl<N, W> MinCut<N, W> {
+    /// Constructs [`Self`].
+    pub const fn new(weight: W, side: Vec<N>) -> Self {
+        Self { weight, side }
+    }
+}
+
+/// Represents [`MinCut`] of the graph `G`, with edge weights `W`.
+pub type MinCutOf<G, W> = MinCut<<G as Base>::NodeId, W>;
+
+/// Represents results of [`gomory_hu`], building the tree `C`.
+pub type Output<C> = Result<C, BuildErrorOf<C>>;
+
+/// Finds the global minimum cut of the given graph using the Stoer-Wagner algorithm.
+///
+/// Each phase orders the remaining nodes by maximum adjacency, that is, always picking
+/// the node most tightly connected to the ones picked before it. The cut separating the last
+/// node is the minimum cut between the last two nodes, which are then merged together.
+/// The lightest of these cuts is the global minimum cut.
+///
+/// This function runs in `O(V E log E)` time. Edge weights are computed once per edge
+/// via the given `weight` function, and must be non-negative. Loops are ignored.
+///
+/// Returns [`None`] if the graph has less than two nodes, in which case there are no cuts.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn stoer_wagner<G, W, F>(graph: G, mut weight: F) -> Option<MinCutOf<G, W>>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let bound = graph.node_bound();
+
+    let mut adjacency = vec![Vec::new(); bound];
+
+    for edge in graph.edge_identifiers() {
+        let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+        let one = graph.node_index(one);
+        let two = graph.node_index(two);
+
+        // loops never cross any cuts
+        if one == two {
+            continue;
+        }
+
+        let value = weight(graph.edge_value(edge).expect(EDGE));
+
+        adjacency[one].push((two, value));
+        adjacency[two].push((one, value));
+    }
+
+    let mut active: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.node_index(node))
+        .collect();
+
+    if active.len() < 2 {
+        return None;
+    }
+
+    let mut members: Vec<Vec<usize>> = (0..bound).map(|index| vec![index]).collect();
+
+    let mut union_find = UnionFind::new(bound);
+
+    let mut keys = vec![W::ZERO; bound];
+    let mut added = vec![usize::LIMIT; bound];
+
+    let mut heap = BinaryHeap::new();
+
+    let mut best: Option<(W, Vec<usize>)> = None;
+
+    let mut phase = 0;
+
+    while active.len() > 1 {
+        heap.clear();
+
+        for &node in &active {
+            keys[node] = W::ZERO;
+        }
+
+        let mut previous = usize::LIMIT;
+        let mut last = usize::LIMIT;
+
+        // nodes not connected to the ones added so far are picked in order
+        let mut cursor = 0;
+
+        for _ in 0..active.len() {
+            let node = loop {
+                match heap.pop() {
+                    Some(Entry { value: node, .. }) if added[node] != phase => break node,
+                    Some(_) => {}
+                    None => {
+                        while added[active[cursor]] == phase {
+                            cursor += 1;
+                        }
+
+                        break active[cursor];
+                    }
+                }
+            };
+
+            added[node] = phase;
+
+            previous = last;
+            last = node;
+
+            for &(other, value) in &adjacency[node] {
+                let other = union_find.find_mut(other);
+
+                if added[other] != phase {
+                    keys[other] = keys[other] + value;
+
+                    heap.push(Entry::new(keys[other], other));
+                }
+            }
+        }
+
+        // the cut of the phase separates the last node from the rest
+
+        let cut = keys[last];
+
+        if best
+            .as_ref()
+            .is_none_or(|(weight, _)| cut.compare(weight).is_lt())
+        {
+            best = Some((cut, members[last].clone()));
+        }
+
+        union_find.union(previous, last);
+
+        let root = union_find.find_mut(last);
+
+        let other = if root == last { previous } else { last };
+
+        let moved = core::mem::take(&mut members[other]);
+        members[root].extend(moved);
+
+        let moved = core::mem::take(&mut adjacency[other]);
+        adjacency[root].extend(moved);
+
+        active.retain(|&node| node != other);
+
+        phase += 1;
+    }
+
+    best.map(|(weight, side)| {
+        let side = side.into_iter().map(|index| graph.node_id(index)).collect();
+
+        MinCut::new(weight, side)
+    })
+}
+
+/// Builds the Gomory-Hu tree of the given graph using Gusfield's algorithm.
+///
+/// The tree has the same nodes as the graph (every node holds the identifier of the original one)
+/// and its edges hold cut values, such that the minimum cut between any two nodes is equal to
+/// the lightest edge on the path between them in the tree. Moreover, removing this edge splits
+/// the tree into the two sides of the cut.
+///
+/// This function performs `V - 1` maximum flow computations via Dinic's algorithm,
+/// without contracting the graph. After every cut, the nodes on the side of the source
+/// hanging onto the sink are moved onto the source, which also takes the place of the sink
+/// if the parent of the sink is on the side of the source.
+///
+/// Edge capacities are computed once per edge via the given `capacity` function,
+/// and must be non-negative. Disconnected graphs give zero-valued edges.
+///
+/// # Errors
+///
+/// Returns [`BuildError`] if adding some node or edge to the tree fails.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn gomory_hu<G, W, F, C>(graph: G, capacity: F) -> Output<C>
+where
+    G: Undirected
+        + NodeIdentifiers
+        + NodeIndexed
+        + EdgeIdentifiers
+        + EdgeIndexed
+        + Endpoints
+        + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+    C: Create + Build<NodeValue = G::NodeId, EdgeValue = W> + Undirected,
+{
+    let mut network = Network::symmetric(&graph, capacity);
+
+    let capacities = network.residuals.clone();
+
+    let mut tree = C::empty();
+
+    let mut indices = Vec::new();
+    let mut nodes = Vec::new();
+
+    for node in graph.node_identifiers() {
+        let added = tree
+            .add_node(node)
+            .map_err(Recoverable::into_error)
+            .map_err(BuildError::Node)?;
+
+        indices.push(graph.node_index(node));
+        nodes.push(added);
+    }
+
+    let count = nodes.len();
+
+    let mut parents = vec![0; count];
+    let mut values = vec![W::ZERO; count];
+
+    let mut distances = vec![usize::LIMIT; network.bound()];
+    let mut queue = VecDeque::new();
+
+    for position in 1..count {
+        let parent = parents[position];
+
+        network.residuals.copy_from_slice(&capacities);
+
+        let value = network.dinic(indices[position], indices[parent]);
+
+        values[position] = value;
+
+        // nodes on the side of this node now hang onto it instead of the parent
+
+        network.distances(indices[position], &mut distances, &mut queue);
+
+        let on_side = |other: usize| !distances[indices[other]].is_limit();
+
+        for (other, hanging) in parents.iter_mut().enumerate() {
+            if other != position && *hanging == parent && on_side(other) {
+                *hanging = position;
+            }
+        }
+
+        // if the grandparent is on the side of this node too, this node takes the place
+        // of the parent, which hangs onto it instead
+        let grandparent = parents[parent];
+
+        if on_side(grandparent) {
+            parents[position] = grandparent;
+            parents[parent] = position;
+
+            values[position] = values[parent];
+            values[parent] = value;
+        }
+    }
+
+    for position in 1..count {
+        tree.add_edge_connecting(nodes[position], nodes[parents[position]], values[position])
+            .map_err(Recoverable::into_error)
+            .map_err(BuildError::Edge)?;
+    }
+
+    Ok(tree)
+}
+
+/// Represents the Stoer-Wagner global minimum cut algorithm.
+///
+/// See [`stoer_wagner`] for more information.
+pub struct StoerWagner<F> {
+    weight: F,
+}
+
+impl<F> StoerWagner<F> {
+    /// Constructs [`Self`] with the given `weight` function.
+    pub const fn new(weight: F) -> Self {
+        Self { weight }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for StoerWagner<F>
+where
+    G: Undirected + NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = Option<MinCutOf<G, W>>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        stoer_wagner(graph, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use graphs_core::{count::EdgeCount, edges::Edges, id::NodeId};
+
+    use crate::testing::{Node, UnGraph};
+
+    fn graph() -> UnGraph<u32> {
+        UnGraph::weighted(
+            6,
+            [
+                (0, 1, 5),
+                (1, 2, 5),
+                (2, 0, 5),
+                (3, 4, 5),
+                (4, 5, 5),
+                (5, 3, 5),
+                (2, 3, 1),
+                (0, 5, 2),
+                (1, 1, 7),
+            ],
+        )
+    }
+
+    #[test]
+    fn global() {
+        let cut = stoer_wagner(&graph(), |&weight| weight).unwrap();
+
+        let mut side: Vec<_> = cut.side.iter().map(|node| node.get()).collect();
+
+        side.sort_unstable();
+
+        assert_eq!(cut.weight, 3);
+        assert!(side == [0, 1, 2] || side == [3, 4, 5]);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(stoer_wagner(&UnGraph::unweighted(1, &[]), |()| 1), None);
+
+        let cut = stoer_wagner(&UnGraph::unweighted(3, &[(0, 1)]), |()| 1).unwrap();
+
+        assert_eq!(cut.weight, 0);
+    }
+
+    /// Finds the lightest edge on the path between the given nodes of the tree.
+    fn lightest(
+        tree: &UnGraph<u32, Node>,
+        node: Node,
+        goal: Node,
+        parent: Option<Node>,
+    ) -> Option<u32> {
+        if node == goal {
+            return Some(u32::MAX);
+        }
+
+        tree.edges(node).find_map(|edge| {
+            let (one, two) = tree.endpoints(edge).unwrap();
+
+            let other = if one == node { two } else { one };
+
+            if Some(other) == parent {
+                return None;
+            }
+
+            let lightest = lightest(tree, other, goal, Some(node))?;
+
+            Some(lightest.min(*tree.edge_value(edge).unwrap()))
+        })
+    }
+
+    #[test]
+    fn tree() {
+        let tree: UnGraph<u32, Node> = gomory_hu(&graph(), |&weight| weight).unwrap();
+
+        assert_eq!(tree.node_identifiers().count(), 6);
+        assert_eq!(tree.edge_count(), 5);
+
+        for node in tree.node_identifiers() {
+            assert_eq!(*tree.node_value(node).unwrap(), node);
+        }
+
+        let cut = |one, two| lightest(&tree, NodeId::new(one), NodeId::new(two), None).unwrap();
+
+        assert_eq!(cut(0, 1), 10);
+        assert_eq!(cut(1, 2), 10);
+        assert_eq!(cut(3, 4), 10);
+        assert_eq!(cut(0, 3), 3);
+        assert_eq!(cut(2, 5), 3);
+    }
+
+    /// Checks that removing every edge of the Gomory-Hu tree of the given graph
+    /// splits its nodes into the sides of the cut of the weight the edge holds.
+    fn check_sides(graph: &UnGraph<u32>) {
+        let tree: UnGraph<u32, Node> = gomory_hu(graph, |&weight| weight).unwrap();
+
+        for removed in tree.edge_identifiers() {
+            let (root, _) = tree.endpoints(removed).unwrap();
+
+            let mut side = vec![false; graph.node_bound()];
+            let mut stack = vec![root];
+
+            side[tree.node_value(root).unwrap().get()] = true;
+
+            while let Some(node) = stack.pop() {
+                for edge in tree.edges(node).filter(|&edge| edge != removed) {
+                    let other = tree.opposite(edge, node).unwrap();
+
+                    let original = tree.node_value(other).unwrap().get();
+
+                    if !side[original] {
+                        side[original] = true;
+
+                        stack.push(other);
+                    }
+                }
+            }
+
+            let weight: u32 = graph
+                .edge_identifiers()
+                .filter(|&edge| {
+                    let (one, two) = graph.endpoints(edge).unwrap();
+
+                    side[one.get()] != side[two.get()]
+                })
+                .map(|edge| *graph.edge_value(edge).unwrap())
+                .sum();
+
+            assert_eq!(weight, *tree.edge_value(removed).unwrap());
+        }
+    }
+
+    #[test]
+    fn sides() {
+        check_sides(&graph());
+
+        check_sides(&UnGraph::weighted(
+            7,
+            [
+                (1, 0, 1),
+                (2, 0, 3),
+                (1, 4, 7),
+                (2, 0, 2),
+                (3, 4, 5),
+                (5, 6, 2),
+                (0, 6, 5),
+            ],
+        ));
+    }
+}
@@ -1 +1,378 @@
+//! Minimum spanning trees (forests) of undirected graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::{Base, Undirected},
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::EdgeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
 use graphs_union_find::vec::UnionFind;
+
+use crate::{EDGE, weight::Weight};
+
+/// Represents minimum spanning trees (or forests, for disconnected graphs).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SpanningTree<E, W> {
+    /// The edges chosen to be in the tree.
+    pub edges: Vec<E>,
+
+    /// The total weight of the chosen edges.
+    pub weight: W,
+}
+
+impl<E, W> SpanningTree<E, W> {
+    /// Constructs [`Self`].
+    pub const fn new(edges: Vec<E>, weight: W) -> Self {
+        Self { edges, weight }
+    }
+}
+
+impl<E, W: Weight> SpanningTree<E, W> {
+    /// Constructs empty [`Self`].
+    pub const fn empty() -> Self {
+        Self::new(Vec::new(), W::ZERO)
+    }
+
+    fn push(&mut self, edge: E, weight: W) {
+        self.edges.push(edge);
+
+        self.weight = self.weight + weight;
+    }
+}
+
+/// Represents [`SpanningTree`] of the graph `G`, with edge weights `W`.
+pub type SpanningTreeOf<G, W> = SpanningTree<<G as Base>::EdgeId, W>;
+
+struct Candidate<E, W> {
+    edge: E,
+    one: usize,
+    two: usize,
+    weight: W,
+}
+
+impl<E, W> Candidate<E, W> {
+    const fn new(edge: E, one: usize, two: usize, weight: W) -> Self {
+        Self {
+            edge,
+            one,
+            two,
+            weight,
+        }
+    }
+}
+
+fn candidates<G, W, F>(graph: &G, mut weight: F) -> Vec<Candidate<G::EdgeId, W>>
+where
+    G: Undirected + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    graph
+        .edge_identifiers()
+        .filter_map(|edge| {
+            let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+            // loops never belong to spanning trees
+            if one == two {
+                return None;
+            }
+
+            let value = weight(graph.edge_value(edge).expect(EDGE));
+
+            Some(Candidate::new(
+                edge,
+                graph.node_index(one),
+                graph.node_index(two),
+                value,
+            ))
+        })
+        .collect()
+}
+
+/// Checks whether the candidate at `position` is lighter than the one at `other`.
+///
+/// Ties are broken by positions, which makes the order total and prevents cycles.
+fn lighter<E, W: Weight>(candidates: &[Candidate<E, W>], position: usize, other: usize) -> bool {
+    if other.is_limit() {
+        return true;
+    }
+
+    candidates[position]
+        .weight
+        .compare(&candidates[other].weight)
+        .then(position.cmp(&other))
+        .is_lt()
+}
+
+fn offer<E, W: Weight>(
+    candidates: &[Candidate<E, W>],
+    cheapest: &mut [usize],
+    position: usize,
+) {
+    let candidate = &candidates[position];
+
+    for component in [candidate.one, candidate.two] {
+        if lighter(candidates, position, cheapest[component]) {
+            cheapest[component] = position;
+        }
+    }
+}
+
+/// Contracts the endpoints of candidates to their components,
+/// dropping the ones that became internal to some component.
+fn contract<E, W>(candidates: &mut Vec<Candidate<E, W>>, union_find: &mut UnionFind) {
+    for candidate in candidates.iter_mut() {
+        candidate.one = union_find.find_mut(candidate.one);
+        candidate.two = union_find.find_mut(candidate.two);
+    }
+
+    candidates.retain(|candidate| candidate.one != candidate.two);
+}
+
+/// Merges components along the cheapest edges, returning whether anything was merged.
+fn merge<E: Copy, W: Weight>(
+    candidates: &[Candidate<E, W>],
+    cheapest: &[usize],
+    union_find: &mut UnionFind,
+    tree: &mut SpanningTree<E, W>,
+) -> bool {
+    let mut merged = false;
+
+    for &position in cheapest.iter().filter(|position| !position.is_limit()) {
+        let candidate = &candidates[position];
+
+        // both components can pick the same edge, so we only take it once
+        if union_find.union(candidate.one, candidate.two) {
+            tree.push(candidate.edge, candidate.weight);
+
+            merged = true;
+        }
+    }
+
+    merged
+}
+
+/// Finds the minimum spanning forest of the given graph using Borůvka's algorithm.
+///
+/// Each round, every component picks its cheapest outgoing edge, and all picked edges
+/// are used to merge components via [`UnionFind`]. There are at most `log(V)` rounds.
+///
+/// Edge weights are computed once per edge via the given `weight` function.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+pub fn boruvka<G, W, F>(graph: G, weight: F) -> SpanningTreeOf<G, W>
+where
+    G: Undirected + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    let bound = graph.node_bound();
+
+    let mut candidates = candidates(&graph, weight);
+
+    let mut union_find = UnionFind::new(bound);
+    let mut cheapest = vec![usize::LIMIT; bound];
+
+    let mut tree = SpanningTree::empty();
+
+    loop {
+        contract(&mut candidates, &mut union_find);
+
+        cheapest.fill(usize::LIMIT);
+
+        for position in 0..candidates.len() {
+            offer(&candidates, &mut cheapest, position);
+        }
+
+        if !merge(&candidates, &cheapest, &mut union_find, &mut tree) {
+            break;
+        }
+    }
+
+    tree
+}
+
+/// Parallel version of [`boruvka`].
+///
+/// Each round, the cheapest outgoing edges of components are searched for concurrently,
+/// splitting the remaining edges between [scoped threads].
+///
+/// [scoped threads]: std::thread::scope
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values.
+#[cfg(feature = "parallel")]
+pub fn boruvka_parallel<G, W, F>(graph: G, weight: F) -> SpanningTreeOf<G, W>
+where
+    G: Undirected + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    G::EdgeId: Sync,
+    W: Weight + Sync,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    use std::{num::NonZero, thread};
+
+    let bound = graph.node_bound();
+
+    let threads = thread::available_parallelism().map_or(1, NonZero::get);
+
+    let mut candidates = candidates(&graph, weight);
+
+    let mut union_find = UnionFind::new(bound);
+    let mut cheapest = vec![usize::LIMIT; bound];
+
+    let mut tree = SpanningTree::empty();
+
+    loop {
+        contract(&mut candidates, &mut union_find);
+
+        let chunk = candidates.len().div_ceil(threads).max(1);
+
+        let shared = candidates.as_slice();
+
+        let locals: Vec<Vec<usize>> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..candidates.len())
+                .step_by(chunk)
+                .map(|start| {
+                    scope.spawn(move || {
+                        let mut local = vec![usize::LIMIT; bound];
+
+                        for position in start..(start + chunk).min(shared.len()) {
+                            offer(shared, &mut local, position);
+                        }
+
+                        local
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("thread panicked"))
+                .collect()
+        });
+
+        cheapest.fill(usize::LIMIT);
+
+        for local in &locals {
+            for (best, &position) in cheapest.iter_mut().zip(local) {
+                if !position.is_limit() && lighter(shared, position, *best) {
+                    *best = position;
+                }
+            }
+        }
+
+        if !merge(shared, &cheapest, &mut union_find, &mut tree) {
+            break;
+        }
+    }
+
+    tree
+}
+
+/// Represents Borůvka's minimum spanning forest algorithm.
+///
+/// See [`boruvka`] for more information.
+pub struct Boruvka<F> {
+    weight: F,
+}
+
+impl<F> Boruvka<F> {
+    /// Constructs [`Self`] with the given `weight` function.
+    pub const fn new(weight: F) -> Self {
+        Self { weight }
+    }
+}
+
+impl<G, W, F> Algorithm<G> for Boruvka<F>
+where
+    G: Undirected + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    W: Weight,
+    F: FnMut(&G::EdgeValue) -> W,
+{
+    type Output = SpanningTreeOf<G, W>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        boruvka(graph, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::UnGraph;
+
+    #[test]
+    fn spanning_tree() {
+        let graph = UnGraph::weighted(
+            5,
+            [
+                (0, 1, 4),
+                (0, 2, 1),
+                (1, 2, 2),
+                (1, 3, 5),
+                (2, 3, 8),
+                (3, 4, 3),
+                (4, 4, -1),
+            ],
+        );
+
+        let mut tree = boruvka(&graph, |&weight| weight);
+
+        tree.edges.sort();
+
+        assert_eq!(tree.weight, 11);
+        assert_eq!(
+            tree.edges.iter().map(|edge| edge.get()).collect::<Vec<_>>(),
+            [1, 2, 3, 5]
+        );
+    }
+
+    #[test]
+    fn spanning_forest() {
+        let graph = UnGraph::weighted(5, [(0, 1, 2), (0, 1, 1), (2, 3, 7)]);
+
+        let tree = boruvka(&graph, |&weight| weight);
+
+        assert_eq!(tree.weight, 8);
+        assert_eq!(tree.edges.len(), 2);
+    }
+
+    #[test]
+    fn unweighted() {
+        let graph = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+
+        let tree = boruvka(&graph, |()| 1);
+
+        assert_eq!(tree.weight, 3);
+        assert_eq!(tree.edges.len(), 3);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel() {
+        let edges = (0..50).flat_map(|node| {
+            [
+                (node, (node + 1) % 50, (node * 7 % 11) as i64),
+                (node, (node + 13) % 50, (node * 5 % 13) as i64),
+            ]
+        });
+
+        let graph = UnGraph::weighted(50, edges);
+
+        let tree = boruvka(&graph, |&weight| weight);
+        let parallel = boruvka_parallel(&graph, |&weight| weight);
+
+        assert_eq!(tree.weight, parallel.weight);
+        assert_eq!(tree.edges.len(), 49);
+        assert_eq!(parallel.edges.len(), 49);
+    }
+}
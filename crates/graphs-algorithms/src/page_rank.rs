@@ -0,0 +1,454 @@
+//! PageRank and personalized PageRank.
+
+use core::mem::swap;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    kinds::Kind,
+};
+
+use thiserror::Error;
+
+use crate::{EDGE, compact::compact_with};
+
+/// Represents how the scores of dangling nodes, that is, nodes without outgoing edges,
+/// are redistributed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Dangling {
+    /// According to the personalization vector, which is uniform unless given.
+    #[default]
+    Personalization,
+
+    /// Uniformly across all nodes.
+    Uniform,
+
+    /// Not at all; the scores leak, and the final scores are normalized to sum to one.
+    Ignore,
+}
+
+/// Represents options of PageRank computations.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Options {
+    /// The probability of following edges rather than teleporting, usually `0.85`.
+    pub damping: f64,
+
+    /// The convergence threshold, per node, for the total absolute change of scores.
+    pub tolerance: f64,
+
+    /// The maximum number of iterations.
+    pub iterations: usize,
+
+    /// How the scores of dangling nodes are redistributed.
+    pub dangling: Dangling,
+}
+
+impl Options {
+    /// The default damping factor.
+    pub const DAMPING: f64 = 0.85;
+
+    /// The default convergence threshold.
+    pub const TOLERANCE: f64 = 1e-6;
+
+    /// The default maximum number of iterations.
+    pub const ITERATIONS: usize = 100;
+
+    /// Constructs [`Self`].
+    pub const fn new(damping: f64, tolerance: f64, iterations: usize, dangling: Dangling) -> Self {
+        Self {
+            damping,
+            tolerance,
+            iterations,
+            dangling,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::new(
+            Self::DAMPING,
+            Self::TOLERANCE,
+            Self::ITERATIONS,
+            Dangling::Personalization,
+        )
+    }
+}
+
+/// Represents scores of nodes found by PageRank.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Ranks {
+    /// The score of every node, indexed by node indices.
+    ///
+    /// Scores sum to one, and node indices not present in the graph have zero scores.
+    pub scores: Vec<f64>,
+
+    /// The number of iterations performed.
+    pub iterations: usize,
+
+    /// Whether the scores converged within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl Ranks {
+    /// Constructs [`Self`].
+    pub const fn new(scores: Vec<f64>, iterations: usize, converged: bool) -> Self {
+        Self {
+            scores,
+            iterations,
+            converged,
+        }
+    }
+
+    /// Returns the score of the node with the given index, if the index is in bounds.
+    pub fn score_of(&self, index: usize) -> Option<f64> {
+        self.scores.get(index).copied()
+    }
+}
+
+/// Represents errors returned when personalization vectors contain negative
+/// or non-finite values, or do not have any positive ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Error)]
+#[error("expected non-negative personalization with positive sum")]
+pub struct InvalidPersonalization;
+
+/// Computes PageRank of every node, with personalization over node positions given.
+fn rank<G, F>(graph: G, options: Options, personalization: Option<Vec<f64>>, mut weight: F) -> Ranks
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    let bound = graph.node_bound();
+
+    let (indices, positions) = compact_with(&graph, |node| graph.node_index(node));
+
+    let count = indices.len();
+
+    if count == 0 {
+        return Ranks::new(vec![0.0; bound], 0, true);
+    }
+
+    let uniform = 1.0 / count as f64;
+
+    let teleport = personalization.unwrap_or_else(|| vec![uniform; count]);
+
+    // the arcs along which scores flow, with their weights
+    let mut arcs = Vec::new();
+    let mut totals = vec![0.0; count];
+
+    for edge in graph.edge_identifiers() {
+        let (one, two) = graph.endpoints(edge).expect(EDGE);
+
+        let value = weight(graph.edge_value(edge).expect(EDGE));
+
+        // non-positive weights mean no edges
+        if value.is_nan() || value <= 0.0 {
+            continue;
+        }
+
+        let source = positions[graph.node_index(one)];
+        let target = positions[graph.node_index(two)];
+
+        arcs.push((source, target, value));
+        totals[source] += value;
+
+        if !G::Kind::DIRECTED && source != target {
+            arcs.push((target, source, value));
+            totals[target] += value;
+        }
+    }
+
+    for (source, _, value) in &mut arcs {
+        *value /= totals[*source];
+    }
+
+    let damping = options.damping;
+
+    let mut scores = vec![uniform; count];
+    let mut next = vec![0.0; count];
+
+    let mut iterations = 0;
+    let mut converged = false;
+
+    while iterations < options.iterations {
+        iterations += 1;
+
+        let leaked: f64 = (0..count)
+            .filter(|&position| totals[position] == 0.0)
+            .map(|position| scores[position])
+            .sum();
+
+        for (position, value) in next.iter_mut().enumerate() {
+            let spread = match options.dangling {
+                Dangling::Personalization => leaked * teleport[position],
+                Dangling::Uniform => leaked * uniform,
+                Dangling::Ignore => 0.0,
+            };
+
+            *value = damping * spread + (1.0 - damping) * teleport[position];
+        }
+
+        for &(source, target, value) in &arcs {
+            next[target] += damping * scores[source] * value;
+        }
+
+        let change: f64 = scores
+            .iter()
+            .zip(&next)
+            .map(|(&old, &new)| (new - old).abs())
+            .sum();
+
+        swap(&mut scores, &mut next);
+
+        if change < options.tolerance * count as f64 {
+            converged = true;
+
+            break;
+        }
+    }
+
+    let total: f64 = scores.iter().sum();
+
+    let mut result = vec![0.0; bound];
+
+    for (position, &index) in indices.iter().enumerate() {
+        result[index] = if total > 0.0 {
+            scores[position] / total
+        } else {
+            scores[position]
+        };
+    }
+
+    Ranks::new(result, iterations, converged)
+}
+
+/// Computes PageRank of every node in the given graph, treating all edges equally.
+///
+/// See [`page_rank_by`] for more information.
+pub fn page_rank<G>(graph: G, options: Options) -> Ranks
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    rank(graph, options, None, |_| 1.0)
+}
+
+/// Computes PageRank of every node in the given graph, with edge weights given by `weight`.
+///
+/// Scores flow along edges proportionally to their weights, while edges with non-positive
+/// weights are ignored; edges of undirected graphs carry scores both ways.
+/// Every iteration takes `O(V + E)` time, and iterations stop once the total absolute change
+/// of scores drops below [`tolerance`] times the number of nodes.
+///
+/// [`tolerance`]: Options::tolerance
+pub fn page_rank_by<G, F>(graph: G, options: Options, weight: F) -> Ranks
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    rank(graph, options, None, weight)
+}
+
+/// Computes personalized PageRank of every node in the given graph,
+/// with edge weights given by `weight`.
+///
+/// Teleports land on nodes proportionally to the given `personalization` vector,
+/// which is indexed by node indices; missing entries are treated as zeros.
+///
+/// See [`page_rank_by`] for more information.
+///
+/// # Errors
+///
+/// Returns [`InvalidPersonalization`] if the personalization vector contains negative
+/// or non-finite values, or if its entries for nodes in the graph do not sum to a positive value.
+pub fn personalized_page_rank<G, F>(
+    graph: G,
+    options: Options,
+    personalization: &[f64],
+    weight: F,
+) -> Result<Ranks, InvalidPersonalization>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    if personalization
+        .iter()
+        .any(|value| !value.is_finite() || *value < 0.0)
+    {
+        return Err(InvalidPersonalization);
+    }
+
+    let mut teleport: Vec<f64> = graph
+        .node_identifiers()
+        .map(|node| {
+            personalization
+                .get(graph.node_index(node))
+                .copied()
+                .unwrap_or(0.0)
+        })
+        .collect();
+
+    let total: f64 = teleport.iter().sum();
+
+    if total <= 0.0 {
+        return Err(InvalidPersonalization);
+    }
+
+    teleport.iter_mut().for_each(|value| *value /= total);
+
+    Ok(rank(graph, options, Some(teleport), weight))
+}
+
+/// Represents the PageRank algorithm, treating all edges equally.
+///
+/// See [`page_rank`] for more information.
+pub struct PageRank {
+    options: Options,
+}
+
+impl PageRank {
+    /// Constructs [`Self`] with the given `options`.
+    pub const fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<G> Algorithm<G> for PageRank
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    type Output = Ranks;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        page_rank(graph, self.options)
+    }
+}
+
+/// Represents the PageRank algorithm, with edge weights given by the closure.
+///
+/// See [`page_rank_by`] for more information.
+pub struct PageRankBy<F> {
+    options: Options,
+    weight: F,
+}
+
+impl<F> PageRankBy<F> {
+    /// Constructs [`Self`] with the given `options` and `weight` function.
+    pub const fn new(options: Options, weight: F) -> Self {
+        Self { options, weight }
+    }
+}
+
+impl<G, F> Algorithm<G> for PageRankBy<F>
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    F: FnMut(&G::EdgeValue) -> f64,
+{
+    type Output = Ranks;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        page_rank_by(graph, self.options, &mut self.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::DiGraph;
+
+    const EPSILON: f64 = 1e-6;
+
+    fn close(one: f64, two: f64) -> bool {
+        (one - two).abs() < EPSILON
+    }
+
+    fn sum(ranks: &Ranks) -> f64 {
+        ranks.scores.iter().sum()
+    }
+
+    #[test]
+    fn cycle() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (1, 2), (2, 0)]);
+
+        let ranks = page_rank(&graph, Options::default());
+
+        assert!(ranks.converged);
+        assert!(ranks.scores.iter().all(|&score| close(score, 1.0 / 3.0)));
+    }
+
+    #[test]
+    fn dangling() {
+        let graph = DiGraph::unweighted(4, &[(1, 0), (2, 0), (3, 0)]);
+
+        for dangling in [
+            Dangling::Personalization,
+            Dangling::Uniform,
+            Dangling::Ignore,
+        ] {
+            let options = Options {
+                dangling,
+                ..Options::default()
+            };
+
+            let ranks = page_rank(&graph, options);
+
+            assert!(ranks.converged);
+            assert!(close(sum(&ranks), 1.0));
+
+            assert!(close(ranks.scores[1], ranks.scores[2]));
+            assert!(close(ranks.scores[2], ranks.scores[3]));
+            assert!(ranks.scores[0] > ranks.scores[1]);
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = DiGraph::weighted(
+            4,
+            [
+                (0, 1, 3.0),
+                (0, 2, 1.0),
+                (1, 0, 1.0),
+                (2, 0, 1.0),
+                (0, 3, 0.0),
+            ],
+        );
+
+        let ranks = page_rank_by(&graph, Options::default(), |&weight| weight);
+
+        assert!(close(sum(&ranks), 1.0));
+        assert!(ranks.scores[1] > ranks.scores[2]);
+
+        // the zero-weight edge is ignored, so the last node only gets teleports,
+        // including its own score, as it is dangling
+        let damping = Options::DAMPING;
+
+        assert!(close(ranks.scores[3], (1.0 - damping) / (4.0 - damping)));
+    }
+
+    #[test]
+    fn personalized() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (1, 0), (2, 0)]);
+
+        let ranks = personalized_page_rank(&graph, Options::default(), &[1.0], |()| 1.0).unwrap();
+
+        assert!(close(sum(&ranks), 1.0));
+        assert_eq!(ranks.score_of(2), Some(0.0));
+        assert_eq!(ranks.score_of(3), None);
+
+        let invalid = [vec![-1.0, 2.0], vec![0.0, 0.0, 0.0], vec![f64::NAN]];
+
+        for personalization in invalid {
+            assert_eq!(
+                personalized_page_rank(&graph, Options::default(), &personalization, |()| 1.0),
+                Err(InvalidPersonalization)
+            );
+        }
+    }
+}
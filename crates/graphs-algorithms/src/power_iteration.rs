@@ -0,0 +1,559 @@
+//! Eigenvector, Katz and HITS centralities, computed via power iteration.
+
+use core::mem::swap;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm, identifiers::NodeIdentifiers, indexed::NodeIndexed, neighbors::Neighbors,
+
+};
+
+use crate::compact::compact_with;
+
+/// Represents options of power iterations.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Options {
+    /// The convergence threshold, per node, for the residual.
+    pub tolerance: f64,
+
+    /// The maximum number of iterations.
+    pub iterations: usize,
+}
+
+impl Options {
+    /// The default convergence threshold.
+    pub const TOLERANCE: f64 = 1e-6;
+
+    /// The default maximum number of iterations.
+    pub const ITERATIONS: usize = 100;
+
+    /// Constructs [`Self`].
+    pub const fn new(tolerance: f64, iterations: usize) -> Self {
+        Self {
+            tolerance,
+            iterations,
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::new(Self::TOLERANCE, Self::ITERATIONS)
+    }
+}
+
+/// Represents convergence diagnostics of power iterations.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Convergence {
+    /// The number of iterations performed.
+    pub iterations: usize,
+
+    /// The total absolute change of scores in the last iteration.
+    pub residual: f64,
+
+    /// Whether the residual dropped below the threshold within the maximum number of iterations.
+    pub converged: bool,
+}
+
+impl Convergence {
+    /// Constructs [`Self`].
+    pub const fn new(iterations: usize, residual: f64, converged: bool) -> Self {
+        Self {
+            iterations,
+            residual,
+            converged,
+        }
+    }
+}
+
+/// Represents scores of nodes, along with convergence diagnostics.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scores {
+    /// The score of every node, indexed by node indices.
+    ///
+    /// Node indices not present in the graph have zero scores.
+    pub scores: Vec<f64>,
+
+    /// The convergence diagnostics.
+    pub convergence: Convergence,
+}
+
+impl Scores {
+    /// Constructs [`Self`].
+    pub const fn new(scores: Vec<f64>, convergence: Convergence) -> Self {
+        Self {
+            scores,
+            convergence,
+        }
+    }
+
+    /// Returns the score of the node with the given index, if the index is in bounds.
+    pub fn score_of(&self, index: usize) -> Option<f64> {
+        self.scores.get(index).copied()
+    }
+}
+
+/// Represents hub and authority scores of nodes, along with convergence diagnostics.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Hits {
+    /// The hub score of every node, indexed by node indices.
+    ///
+    /// Hub scores sum to one, and node indices not present in the graph have zero scores.
+    pub hubs: Vec<f64>,
+
+    /// The authority score of every node, indexed by node indices.
+    ///
+    /// Authority scores sum to one, and node indices not present in the graph have zero scores.
+    pub authorities: Vec<f64>,
+
+    /// The convergence diagnostics, with residuals measured on hub scores.
+    pub convergence: Convergence,
+}
+
+impl Hits {
+    /// Constructs [`Self`].
+    pub const fn new(hubs: Vec<f64>, authorities: Vec<f64>, convergence: Convergence) -> Self {
+        Self {
+            hubs,
+            authorities,
+            convergence,
+        }
+    }
+}
+
+/// Computes square roots, which are only available in `core` via Newton's method.
+#[cfg(not(feature = "std"))]
+fn sqrt(value: f64) -> f64 {
+    if value <= 0.0 || !value.is_finite() {
+        return value.max(0.0);
+    }
+
+    // halving the exponent gives the good initial guess
+    let mut root = f64::from_bits((value.to_bits() >> 1) + (1023 << 51));
+
+    for _ in 0..8 {
+        root = 0.5 * (root + value / root);
+    }
+
+    root
+}
+
+#[cfg(feature = "std")]
+fn sqrt(value: f64) -> f64 {
+    value.sqrt()
+}
+
+/// Scales the given values to have the unit Euclidean norm, unless they are all zeros.
+fn normalize_euclidean(values: &mut [f64]) {
+    let norm = sqrt(values.iter().map(|value| value * value).sum());
+
+    if norm > 0.0 {
+        values.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+/// Scales the given values to sum to one, unless they sum to zero.
+fn normalize_sum(values: &mut [f64]) {
+    let total: f64 = values.iter().sum();
+
+    if total != 0.0 {
+        values.iter_mut().for_each(|value| *value /= total);
+    }
+}
+
+/// Returns the total absolute difference between the given values.
+fn difference(one: &[f64], two: &[f64]) -> f64 {
+    one.iter()
+        .zip(two)
+        .map(|(&one, &two)| (one - two).abs())
+        .sum()
+}
+
+/// Represents the power iteration engine, which multiplies score vectors
+/// by adjacency matrices of graphs, with nodes referred to by their positions.
+struct Engine {
+    indices: Vec<usize>,
+    starts: Vec<usize>,
+    targets: Vec<usize>,
+}
+
+impl Engine {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: NodeIdentifiers + NodeIndexed + Neighbors,
+    {
+        let (indices, positions) = compact_with(graph, |node| graph.node_index(node));
+
+        let mut starts = vec![0];
+        let mut targets = Vec::new();
+
+        for node in graph.node_identifiers() {
+            targets.extend(
+                graph
+                    .neighbors(node)
+                    .map(|neighbor| positions[graph.node_index(neighbor)]),
+            );
+
+            starts.push(targets.len());
+        }
+
+        Self {
+            indices,
+            starts,
+            targets,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn targets(&self, position: usize) -> &[usize] {
+        &self.targets[self.starts[position]..self.starts[position + 1]]
+    }
+
+    /// Adds the scores of nodes to their neighbors, that is, multiplies by the transposed matrix.
+    fn push(&self, scores: &[f64], into: &mut [f64]) {
+        for (position, &score) in scores.iter().enumerate() {
+            for &target in self.targets(position) {
+                into[target] += score;
+            }
+        }
+    }
+
+    /// Adds the scores of neighbors to their nodes, that is, multiplies by the matrix.
+    fn pull(&self, scores: &[f64], into: &mut [f64]) {
+        for (position, value) in into.iter_mut().enumerate() {
+            *value += self
+                .targets(position)
+                .iter()
+                .map(|&target| scores[target])
+                .sum::<f64>();
+        }
+    }
+
+    /// Iterates the given `step` from the given scores until the residual drops
+    /// below the threshold or the maximum number of iterations is reached.
+    ///
+    /// The step computes the next scores from the current ones, into the zeroed buffer.
+    fn iterate<S>(&self, options: Options, scores: &mut Vec<f64>, mut step: S) -> Convergence
+    where
+        S: FnMut(&[f64], &mut [f64]),
+    {
+        let count = self.count();
+
+        if count == 0 {
+            return Convergence::new(0, 0.0, true);
+        }
+
+        let mut next = vec![0.0; count];
+
+        let mut convergence = Convergence::default();
+
+        while convergence.iterations < options.iterations {
+            convergence.iterations += 1;
+
+            next.iter_mut().for_each(|value| *value = 0.0);
+
+            step(scores, &mut next);
+
+            convergence.residual = difference(scores, &next);
+
+            swap(scores, &mut next);
+
+            if convergence.residual < options.tolerance * count as f64 {
+                convergence.converged = true;
+
+                break;
+            }
+        }
+
+        convergence
+    }
+
+    /// Spreads the scores of positions into the vector indexed by node indices.
+    fn finish(&self, bound: usize, scores: &[f64]) -> Vec<f64> {
+        let mut result = vec![0.0; bound];
+
+        for (&index, &score) in self.indices.iter().zip(scores) {
+            result[index] = score;
+        }
+
+        result
+    }
+}
+
+/// Computes eigenvector centrality of every node in the given graph.
+///
+/// The score of every node is proportional to the sum of the scores of the nodes
+/// it is the neighbor of, that is, its predecessors in directed graphs.
+/// Scores form the principal eigenvector of the transposed adjacency matrix,
+/// normalized to have the unit Euclidean norm.
+///
+/// Neighbors of nodes in directed graphs are expected to be the targets of their outgoing
+/// edges, so that scores flow along edges; use [`Reversed`] graphs to let them flow backwards.
+///
+/// Iterations multiply by the adjacency matrix shifted by the identity, which keeps
+/// the eigenvectors, but makes the iteration converge on bipartite graphs too.
+/// Every iteration takes `O(V + E)` time.
+///
+/// [`Reversed`]: graphs_core::adapters::Reversed
+pub fn eigenvector<G>(graph: G, options: Options) -> Scores
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let engine = Engine::new(&graph);
+
+    let count = engine.count();
+
+    let mut scores = vec![1.0 / sqrt(count as f64); count];
+
+    let convergence = engine.iterate(options, &mut scores, |scores, next| {
+        next.copy_from_slice(scores);
+
+        engine.push(scores, next);
+
+        normalize_euclidean(next);
+    });
+
+    Scores::new(engine.finish(graph.node_bound(), &scores), convergence)
+}
+
+/// Computes Katz centrality of every node in the given graph.
+///
+/// The score of every node is `alpha` times the sum of the scores of the nodes
+/// it is the neighbor of, plus `beta`, which counts walks ending at the node,
+/// with longer walks attenuated by `alpha`. When `normalized`, scores are scaled
+/// to have the unit Euclidean norm.
+///
+/// Neighbors of nodes in directed graphs are expected to be the targets of their outgoing
+/// edges, as in [`eigenvector`].
+///
+/// Iterations only converge if `alpha` is less than the reciprocal of the largest eigenvalue
+/// of the adjacency matrix; diverging iterations can be detected via [`Convergence`].
+/// Every iteration takes `O(V + E)` time.
+pub fn katz<G>(graph: G, alpha: f64, beta: f64, normalized: bool, options: Options) -> Scores
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let engine = Engine::new(&graph);
+
+    let count = engine.count();
+
+    let mut scores = vec![0.0; count];
+
+    let convergence = engine.iterate(options, &mut scores, |scores, next| {
+        engine.push(scores, next);
+
+        next.iter_mut()
+            .for_each(|value| *value = alpha * *value + beta);
+    });
+
+    if normalized {
+        normalize_euclidean(&mut scores);
+    }
+
+    Scores::new(engine.finish(graph.node_bound(), &scores), convergence)
+}
+
+/// Computes hub and authority scores of every node in the given graph,
+/// using Kleinberg's HITS algorithm.
+///
+/// The authority score of every node is proportional to the sum of the hub scores
+/// of the nodes pointing to it, while the hub score of every node is proportional
+/// to the sum of the authority scores of the nodes it points to.
+/// Both hub and authority scores are normalized to sum to one.
+///
+/// Neighbors of nodes in directed graphs are expected to be the targets of their outgoing
+/// edges, that is, the nodes they point to; in [`Reversed`] graphs, hubs and authorities swap.
+///
+/// Every iteration takes `O(V + E)` time.
+///
+/// [`Reversed`]: graphs_core::adapters::Reversed
+pub fn hits<G>(graph: G, options: Options) -> Hits
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    let engine = Engine::new(&graph);
+
+    let count = engine.count();
+
+    let mut hubs = vec![1.0 / count as f64; count];
+    let mut authorities = vec![0.0; count];
+
+    let convergence = engine.iterate(options, &mut hubs, |hubs, next| {
+        authorities.iter_mut().for_each(|value| *value = 0.0);
+
+        engine.push(hubs, &mut authorities);
+
+        normalize_sum(&mut authorities);
+
+        engine.pull(&authorities, next);
+
+        normalize_sum(next);
+    });
+
+    let bound = graph.node_bound();
+
+    Hits::new(
+        engine.finish(bound, &hubs),
+        engine.finish(bound, &authorities),
+        convergence,
+    )
+}
+
+/// Represents the eigenvector centrality algorithm.
+///
+/// See [`eigenvector`] for more information.
+pub struct Eigenvector {
+    options: Options,
+}
+
+impl Eigenvector {
+    /// Constructs [`Self`] with the given `options`.
+    pub const fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<G> Algorithm<G> for Eigenvector
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Scores;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        eigenvector(graph, self.options)
+    }
+}
+
+/// Represents the Katz centrality algorithm.
+///
+/// See [`katz`] for more information.
+pub struct Katz {
+    alpha: f64,
+    beta: f64,
+    normalized: bool,
+    options: Options,
+}
+
+impl Katz {
+    /// Constructs [`Self`].
+    pub const fn new(alpha: f64, beta: f64, normalized: bool, options: Options) -> Self {
+        Self {
+            alpha,
+            beta,
+            normalized,
+            options,
+        }
+    }
+}
+
+impl<G> Algorithm<G> for Katz
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Scores;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        katz(graph, self.alpha, self.beta, self.normalized, self.options)
+    }
+}
+
+/// Represents Kleinberg's HITS algorithm.
+///
+/// See [`hits`] for more information.
+pub struct Kleinberg {
+    options: Options,
+}
+
+impl Kleinberg {
+    /// Constructs [`Self`] with the given `options`.
+    pub const fn new(options: Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<G> Algorithm<G> for Kleinberg
+where
+    G: NodeIdentifiers + NodeIndexed + Neighbors,
+{
+    type Output = Hits;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        hits(graph, self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, UnGraph};
+
+    const EPSILON: f64 = 1e-4;
+
+    fn assert_close(values: &[f64], expected: &[f64]) {
+        assert_eq!(values.len(), expected.len());
+
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < EPSILON,
+                "{values:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn star() {
+        let graph = UnGraph::unweighted(4, &[(0, 1), (0, 2), (0, 3)]);
+
+        let eigenvector = eigenvector(&graph, Options::default());
+
+        assert!(eigenvector.convergence.converged);
+
+        let leaf = 1.0 / 6.0_f64.sqrt();
+
+        assert_close(&eigenvector.scores, &[0.5_f64.sqrt(), leaf, leaf, leaf]);
+    }
+
+    #[test]
+    fn walks() {
+        let graph = DiGraph::unweighted(3, &[(0, 1), (1, 2)]);
+
+        let katz = katz(&graph, 0.5, 1.0, false, Options::default());
+
+        assert!(katz.convergence.converged);
+
+        assert_close(&katz.scores, &[1.0, 1.5, 1.75]);
+        assert_eq!(katz.score_of(3), None);
+    }
+
+    #[test]
+    fn divergence() {
+        let graph = DiGraph::unweighted(2, &[(0, 1), (1, 0)]);
+
+        let katz = katz(&graph, 2.0, 1.0, true, Options::new(Options::TOLERANCE, 10));
+
+        assert!(!katz.convergence.converged);
+        assert_eq!(katz.convergence.iterations, 10);
+    }
+
+    #[test]
+    fn hubs_and_authorities() {
+        let graph = DiGraph::unweighted(4, &[(0, 2), (1, 2), (0, 3)]);
+
+        let hits = hits(&graph, Options::default());
+
+        assert!(hits.convergence.converged);
+
+        // the scores are split in the golden ratio
+        let major = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let minor = 1.0 - major;
+
+        assert_close(&hits.hubs, &[major, minor, 0.0, 0.0]);
+        assert_close(&hits.authorities, &[0.0, 0.0, major, minor]);
+    }
+}
@@ -0,0 +1,41 @@
+//! Seeded pseudo-random numbers, so that randomized algorithms are reproducible.
+
+/// Mixes the bits of the given value via the SplitMix64 finalizer.
+pub(crate) const fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    value ^ (value >> 31)
+}
+
+/// Represents SplitMix64 generators.
+pub(crate) struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub(crate) const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) const fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        mix(self.state)
+    }
+
+    /// Returns the number in `0..bound`, which must be positive.
+    pub(crate) const fn below(&mut self, bound: usize) -> usize {
+        // the multiply-shift reduction, which is unbiased enough for our purposes
+        ((self.next() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Shuffles the given values in place, using the Fisher–Yates algorithm.
+    pub(crate) fn shuffle<T>(&mut self, values: &mut [T]) {
+        for index in (1..values.len()).rev() {
+            let other = self.below(index + 1);
+
+            values.swap(index, other);
+        }
+    }
+}
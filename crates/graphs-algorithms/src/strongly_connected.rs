@@ -0,0 +1,341 @@
+//! Strongly connected components of directed graphs.
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+    neighbors::{DirectedNeighbors, Neighbors},
+};
+
+/// Represents strongly connected components of graphs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Components<N> {
+    /// The components in reverse topological order.
+    ///
+    /// That is, there are no edges from any component to the ones following it.
+    pub components: Vec<Vec<N>>,
+
+    /// The component index of every node, indexed by node indices.
+    ///
+    /// Node indices not present in the graph are mapped to [`usize::LIMIT`].
+    pub indices: Vec<usize>,
+}
+
+impl<N> Components<N> {
+    /// Constructs [`Self`].
+    pub const fn new(components: Vec<Vec<N>>, indices: Vec<usize>) -> Self {
+        Self {
+            components,
+            indices,
+        }
+    }
+
+    /// Returns the number of components.
+    pub const fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Checks whether there are no components, meaning the graph has no nodes.
+    pub const fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns the component index of the node with the given index, if it is present.
+    pub fn index_of(&self, index: usize) -> Option<usize> {
+        self.indices
+            .get(index)
+            .copied()
+            .filter(|component| !component.is_limit())
+    }
+}
+
+/// Represents [`Components`] of the graph `G`.
+pub type ComponentsOf<G> = Components<<G as Base>::NodeId>;
+
+/// Finds strongly connected components of the given graph using Tarjan's algorithm.
+///
+/// This function uses an explicit stack instead of recursion, so it can handle deep graphs.
+///
+/// When applied to undirected graphs, this function finds their connected components.
+pub fn tarjan<G: NodeIdentifiers + Neighbors + NodeIndexed>(graph: G) -> ComponentsOf<G> {
+    let bound = graph.node_bound();
+
+    let mut discovered = vec![usize::LIMIT; bound];
+    let mut low = vec![usize::LIMIT; bound];
+    let mut on_stack = vec![false; bound];
+
+    let mut stack = Vec::new();
+    let mut frames = Vec::new();
+
+    let mut time = 0;
+
+    let mut components = Vec::new();
+    let mut indices = vec![usize::LIMIT; bound];
+
+    for start in graph.node_identifiers() {
+        let start_index = graph.node_index(start);
+
+        if !discovered[start_index].is_limit() {
+            continue;
+        }
+
+        discovered[start_index] = time;
+        low[start_index] = time;
+
+        time += 1;
+
+        stack.push(start);
+        on_stack[start_index] = true;
+
+        frames.push((start_index, graph.neighbors(start)));
+
+        while let Some((index, neighbors)) = frames.last_mut() {
+            let index = *index;
+
+            if let Some(neighbor) = neighbors.next() {
+                let neighbor_index = graph.node_index(neighbor);
+
+                if discovered[neighbor_index].is_limit() {
+                    discovered[neighbor_index] = time;
+                    low[neighbor_index] = time;
+
+                    time += 1;
+
+                    stack.push(neighbor);
+                    on_stack[neighbor_index] = true;
+
+                    frames.push((neighbor_index, graph.neighbors(neighbor)));
+                } else if on_stack[neighbor_index] {
+                    low[index] = low[index].min(discovered[neighbor_index]);
+                }
+
+                continue;
+            }
+
+            frames.pop();
+
+            if let Some(&(parent, _)) = frames.last() {
+                low[parent] = low[parent].min(low[index]);
+            }
+
+            if low[index] == discovered[index] {
+                // `index` is the root of the component, so pop it off the stack
+
+                let component_index = components.len();
+
+                let mut component = Vec::new();
+
+                while let Some(node) = stack.pop() {
+                    let node_index = graph.node_index(node);
+
+                    on_stack[node_index] = false;
+                    indices[node_index] = component_index;
+
+                    component.push(node);
+
+                    if node_index == index {
+                        break;
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+    }
+
+    Components::new(components, indices)
+}
+
+/// Finds strongly connected components of the given graph using Kosaraju's algorithm.
+///
+/// The first pass computes the post-order of outgoing depth-first searches,
+/// and the second pass collects components by searching along incoming edges.
+///
+/// This function uses explicit stacks instead of recursion, so it can handle deep graphs.
+pub fn kosaraju<G: NodeIdentifiers + DirectedNeighbors + NodeIndexed>(graph: G) -> ComponentsOf<G> {
+    let bound = graph.node_bound();
+
+    // first pass: compute post-order along outgoing edges
+
+    let mut discovered = vec![false; bound];
+
+    let mut order = Vec::new();
+    let mut frames = Vec::new();
+
+    for start in graph.node_identifiers() {
+        let start_index = graph.node_index(start);
+
+        if discovered[start_index] {
+            continue;
+        }
+
+        discovered[start_index] = true;
+
+        frames.push((start, graph.outgoing_neighbors(start)));
+
+        while let Some((node, neighbors)) = frames.last_mut() {
+            let node = *node;
+
+            if let Some(neighbor) = neighbors.next() {
+                let neighbor_index = graph.node_index(neighbor);
+
+                if !discovered[neighbor_index] {
+                    discovered[neighbor_index] = true;
+
+                    frames.push((neighbor, graph.outgoing_neighbors(neighbor)));
+                }
+            } else {
+                frames.pop();
+
+                order.push(node);
+            }
+        }
+    }
+
+    // second pass: collect components along incoming edges, in reverse post-order
+
+    let mut components = Vec::new();
+    let mut indices = vec![usize::LIMIT; bound];
+
+    let mut stack = Vec::new();
+
+    for &start in order.iter().rev() {
+        let start_index = graph.node_index(start);
+
+        if !indices[start_index].is_limit() {
+            continue;
+        }
+
+        let component_index = components.len();
+
+        let mut component = Vec::new();
+
+        indices[start_index] = component_index;
+
+        stack.push(start);
+
+        while let Some(node) = stack.pop() {
+            component.push(node);
+
+            for neighbor in graph.incoming_neighbors(node) {
+                let neighbor_index = graph.node_index(neighbor);
+
+                if indices[neighbor_index].is_limit() {
+                    indices[neighbor_index] = component_index;
+
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    // components were found in topological order, so we need to reverse them
+
+    components.reverse();
+
+    let last = components.len().saturating_sub(1);
+
+    for index in indices.iter_mut().filter(|index| !index.is_limit()) {
+        *index = last - *index;
+    }
+
+    Components::new(components, indices)
+}
+
+/// Represents Tarjan's strongly connected components algorithm.
+///
+/// See [`tarjan`] for more information.
+pub struct Tarjan;
+
+impl<G: NodeIdentifiers + Neighbors + NodeIndexed> Algorithm<G> for Tarjan {
+    type Output = ComponentsOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        tarjan(graph)
+    }
+}
+
+/// Represents Kosaraju's strongly connected components algorithm.
+///
+/// See [`kosaraju`] for more information.
+pub struct Kosaraju;
+
+impl<G: NodeIdentifiers + DirectedNeighbors + NodeIndexed> Algorithm<G> for Kosaraju {
+    type Output = ComponentsOf<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        kosaraju(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, Node, UnGraph};
+
+    const EDGES: [(usize, usize); 8] = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 3),
+        (4, 4),
+        (6, 0),
+    ];
+
+    fn check(components: &Components<Node>) {
+        assert_eq!(components.len(), 4);
+
+        let index = |node| components.index_of(node).unwrap();
+
+        assert_eq!(index(0), index(1));
+        assert_eq!(index(1), index(2));
+        assert_eq!(index(3), index(4));
+
+        assert_ne!(index(0), index(3));
+        assert_ne!(index(5), index(0));
+        assert_ne!(index(5), index(3));
+        assert_ne!(index(6), index(0));
+
+        // components are in reverse topological order
+        for (source, target) in EDGES {
+            assert!(index(source) >= index(target));
+        }
+
+        for (position, component) in components.components.iter().enumerate() {
+            for node in component {
+                assert_eq!(index(node.get()), position);
+            }
+        }
+
+        assert_eq!(components.index_of(7), None);
+    }
+
+    #[test]
+    fn directed() {
+        let graph = DiGraph::unweighted(7, &EDGES);
+
+        check(&tarjan(&graph));
+        check(&kosaraju(&graph));
+    }
+
+    #[test]
+    fn undirected() {
+        let graph = UnGraph::unweighted(5, &[(0, 1), (1, 2), (3, 4)]);
+
+        let components = tarjan(&graph);
+
+        assert_eq!(components.len(), 2);
+        assert_eq!(components.indices[0], components.indices[2]);
+        assert_ne!(components.indices[0], components.indices[3]);
+    }
+}
@@ -0,0 +1,339 @@
+//! Minimal adjacency-list graphs used for testing algorithms.
+
+use core::{convert::Infallible, iter::Map, marker::PhantomData, ops::Range};
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::vec;
+
+use graphs_core::{
+    base::Base,
+    build::{AddEdgeResult, AddNodeResult, Build, EdgeOf},
+    capacity::Capacities,
+    connections::{Connection, Kinded},
+    count::{EdgeCount, NodeCount},
+    create::Create,
+    data::{Data, DataRef},
+    direction::Direction,
+    edges::{DirectedEdges, Edges},
+    endpoints::Endpoints,
+    id::{EdgeId, NodeId},
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::{EdgeIndexed, NodeIndexed},
+    kinds::{self, Kind},
+    loops::Allow,
+    neighbors::{DirectedNeighbors, Neighbors},
+    types::Multiple,
+    visit::{Visit, Visitor},
+};
+
+pub type Node = NodeId<usize>;
+pub type Edge = EdgeId<usize>;
+
+/// Represents graphs allowing parallel edges and self-loops, stored as adjacency lists.
+pub struct Graph<K: Kind, E = (), V = ()> {
+    values: Vec<V>,
+    edges: Vec<(usize, usize, E)>,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+    kind: PhantomData<K>,
+}
+
+pub type UnGraph<E = (), V = ()> = Graph<kinds::Undirected, E, V>;
+pub type DiGraph<E = (), V = ()> = Graph<kinds::Directed, E, V>;
+
+impl<K: Kind, E, V> Graph<K, E, V> {
+    pub const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+
+    pub fn node(&mut self, value: V) -> Node {
+        self.values.push(value);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+
+        NodeId::new(self.outgoing.len() - 1)
+    }
+
+    pub fn edge(&mut self, source: usize, target: usize, value: E) -> Edge {
+        let index = self.edges.len();
+
+        self.edges.push((source, target, value));
+
+        self.outgoing[source].push(index);
+
+        if K::DIRECTED {
+            self.incoming[target].push(index);
+        } else if source != target {
+            self.outgoing[target].push(index);
+        }
+
+        EdgeId::new(index)
+    }
+
+    fn other(&self, edge: usize, node: usize) -> usize {
+        let (source, target, _) = self.edges[edge];
+
+        if source == node { target } else { source }
+    }
+}
+
+impl<K: Kind, E> Graph<K, E> {
+    /// Constructs graphs with `count` nodes and the given edges with values.
+    pub fn weighted<I: IntoIterator<Item = (usize, usize, E)>>(count: usize, edges: I) -> Self {
+        let mut graph = Self::new();
+
+        for _ in 0..count {
+            graph.node(());
+        }
+
+        for (source, target, value) in edges {
+            graph.edge(source, target, value);
+        }
+
+        graph
+    }
+}
+
+impl<K: Kind> Graph<K> {
+    /// Constructs graphs with `count` nodes and the given edges without values.
+    pub fn unweighted(count: usize, edges: &[(usize, usize)]) -> Self {
+        Self::weighted(
+            count,
+            edges.iter().map(|&(source, target)| (source, target, ())),
+        )
+    }
+}
+
+impl<K: Kind, E, V> Base for Graph<K, E, V> {
+    type NodeId = Node;
+    type EdgeId = Edge;
+    type Connection = Kinded<Node, K>;
+    type Kind = K;
+    type Type = Multiple;
+    type Loop = Allow;
+}
+
+impl<K: Kind, E, V> Data for Graph<K, E, V> {
+    type NodeValue = V;
+    type EdgeValue = E;
+}
+
+impl<K: Kind, E, V> DataRef for Graph<K, E, V> {
+    fn node_value(&self, node: Node) -> Option<&V> {
+        self.values.get(node.get())
+    }
+
+    fn edge_value(&self, edge: Edge) -> Option<&E> {
+        self.edges.get(edge.get()).map(|(_, _, value)| value)
+    }
+}
+
+impl<K: Kind, E, V> NodeCount for Graph<K, E, V> {
+    fn node_count(&self) -> usize {
+        self.outgoing.len()
+    }
+}
+
+impl<K: Kind, E, V> EdgeCount for Graph<K, E, V> {
+    fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<K: Kind, E, V> NodeIndexed for Graph<K, E, V> {
+    fn node_bound(&self) -> usize {
+        self.outgoing.len()
+    }
+
+    fn node_index(&self, node: Node) -> usize {
+        node.get()
+    }
+
+    fn node_id(&self, index: usize) -> Node {
+        NodeId::new(index)
+    }
+}
+
+impl<K: Kind, E, V> EdgeIndexed for Graph<K, E, V> {
+    fn edge_bound(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn edge_index(&self, edge: Edge) -> usize {
+        edge.get()
+    }
+
+    fn edge_id(&self, index: usize) -> Edge {
+        EdgeId::new(index)
+    }
+}
+
+impl<K: Kind, E, V> NodeIdentifiers for Graph<K, E, V> {
+    type Identifiers<'i>
+        = Map<Range<usize>, fn(usize) -> Node>
+    where
+        Self: 'i;
+
+    fn node_identifiers(&self) -> Self::Identifiers<'_> {
+        (0..self.outgoing.len()).map(NodeId::new as fn(usize) -> Node)
+    }
+}
+
+impl<K: Kind, E, V> EdgeIdentifiers for Graph<K, E, V> {
+    type Identifiers<'i>
+        = Map<Range<usize>, fn(usize) -> Edge>
+    where
+        Self: 'i;
+
+    fn edge_identifiers(&self) -> Self::Identifiers<'_> {
+        (0..self.edges.len()).map(EdgeId::new as fn(usize) -> Edge)
+    }
+}
+
+impl<K: Kind, E, V> Endpoints for Graph<K, E, V> {
+    fn connection(&self, edge: Edge) -> Option<Self::Connection> {
+        self.edges.get(edge.get()).map(|&(source, target, _)| {
+            Kinded::connecting(NodeId::new(source), NodeId::new(target))
+        })
+    }
+}
+
+impl<K: Kind, E, V> Edges for Graph<K, E, V> {
+    type Iterator<'e>
+        = vec::IntoIter<Edge>
+    where
+        Self: 'e;
+
+    fn edges(&self, node: Node) -> Self::Iterator<'_> {
+        let edges: Vec<_> = self.outgoing[node.get()]
+            .iter()
+            .map(|&edge| EdgeId::new(edge))
+            .collect();
+
+        edges.into_iter()
+    }
+}
+
+impl<K: Kind, E, V> Neighbors for Graph<K, E, V> {
+    type Iterator<'n>
+        = vec::IntoIter<Node>
+    where
+        Self: 'n;
+
+    fn neighbors(&self, node: Node) -> Self::Iterator<'_> {
+        let neighbors: Vec<_> = self.outgoing[node.get()]
+            .iter()
+            .map(|&edge| NodeId::new(self.other(edge, node.get())))
+            .collect();
+
+        neighbors.into_iter()
+    }
+}
+
+impl<E, V> DirectedEdges for DiGraph<E, V> {
+    type DirectedIterator<'e>
+        = vec::IntoIter<Edge>
+    where
+        Self: 'e;
+
+    fn edges_in(&self, direction: Direction, node: Node) -> Self::DirectedIterator<'_> {
+        let lists = if direction.is_outgoing() {
+            &self.outgoing
+        } else {
+            &self.incoming
+        };
+
+        let edges: Vec<_> = lists[node.get()]
+            .iter()
+            .map(|&edge| EdgeId::new(edge))
+            .collect();
+
+        edges.into_iter()
+    }
+}
+
+impl<E, V> DirectedNeighbors for DiGraph<E, V> {
+    type DirectedIterator<'n>
+        = vec::IntoIter<Node>
+    where
+        Self: 'n;
+
+    fn directed_neighbors(&self, node: Node, direction: Direction) -> Self::DirectedIterator<'_> {
+        let lists = if direction.is_outgoing() {
+            &self.outgoing
+        } else {
+            &self.incoming
+        };
+
+        let neighbors: Vec<_> = lists[node.get()]
+            .iter()
+            .map(|&edge| NodeId::new(self.other(edge, node.get())))
+            .collect();
+
+        neighbors.into_iter()
+    }
+}
+
+/// Represents visitors storing whether nodes were visited.
+pub struct Visited(Vec<bool>);
+
+impl Visitor<Node> for Visited {
+    fn visit(&mut self, node: Node) -> bool {
+        !core::mem::replace(&mut self.0[node.get()], true)
+    }
+
+    fn was_visited(&self, node: Node) -> bool {
+        self.0[node.get()]
+    }
+
+    fn unvisit(&mut self, node: Node) -> bool {
+        core::mem::replace(&mut self.0[node.get()], false)
+    }
+}
+
+impl<K: Kind, E, V> Visit for Graph<K, E, V> {
+    type Visitor = Visited;
+
+    fn build_visitor(&self) -> Self::Visitor {
+        Visited(vec![false; self.outgoing.len()])
+    }
+
+    fn reset_visitor(&self, visitor: &mut Self::Visitor) {
+        visitor.0.clear();
+        visitor.0.resize(self.outgoing.len(), false);
+    }
+}
+
+impl<K: Kind, E, V> Create for Graph<K, E, V> {
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn with_capacity(_: Capacities) -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Kind, E, V> Build for Graph<K, E, V> {
+    type NodeError = Infallible;
+    type EdgeError = Infallible;
+
+    fn add_node(&mut self, value: V) -> AddNodeResult<Self> {
+        Ok(self.node(value))
+    }
+
+    fn add_edge(&mut self, edge: EdgeOf<Self>) -> AddEdgeResult<Self> {
+        let (source, target) = edge.connection.into_parts();
+
+        Ok(self.edge(source.get(), target.get(), edge.value))
+    }
+}
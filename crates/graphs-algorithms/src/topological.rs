@@ -0,0 +1,366 @@
+//! Topological sorting of directed graphs.
+
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use graphs_core::{
+    algorithm::Algorithm,
+    base::Base,
+    edges::DirectedEdges,
+    endpoints::Endpoints,
+    identifiers::NodeIdentifiers,
+    indexed::NodeIndexed,
+    limit::Limited,
+};
+use thiserror::Error;
+
+use crate::EDGE;
+
+/// Represents errors returned when graphs contain cycles, along with some cycle found.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("graph contains a cycle of {} edges", .edges.len())]
+pub struct Cycle<E> {
+    /// The edges of the cycle, in order.
+    ///
+    /// The target of every edge is the source of the next one,
+    /// and the target of the last edge is the source of the first one.
+    pub edges: Vec<E>,
+}
+
+impl<E> Cycle<E> {
+    /// Constructs [`Self`].
+    pub const fn new(edges: Vec<E>) -> Self {
+        Self { edges }
+    }
+}
+
+/// Represents [`Cycle`] errors of the graph `G`.
+pub type CycleIn<G> = Cycle<<G as Base>::EdgeId>;
+
+/// Represents results of [`topological_sort`] and [`topological_sort_by`].
+pub type Output<G> = Result<Vec<<G as Base>::NodeId>, CycleIn<G>>;
+
+/// Represents queues of nodes that are ready to be ordered.
+trait Ready<N> {
+    fn push(&mut self, node: N);
+
+    fn pop(&mut self) -> Option<N>;
+}
+
+impl<N> Ready<N> for VecDeque<N> {
+    fn push(&mut self, node: N) {
+        self.push_back(node);
+    }
+
+    fn pop(&mut self) -> Option<N> {
+        self.pop_front()
+    }
+}
+
+/// Binary heaps ordered by the given `compare` function, popping the least nodes first.
+struct Heap<N, F> {
+    nodes: Vec<N>,
+    compare: F,
+}
+
+impl<N, F: FnMut(&N, &N) -> Ordering> Heap<N, F> {
+    const fn new(compare: F) -> Self {
+        Self {
+            nodes: Vec::new(),
+            compare,
+        }
+    }
+
+    fn less(&mut self, one: usize, two: usize) -> bool {
+        (self.compare)(&self.nodes[one], &self.nodes[two]).is_lt()
+    }
+}
+
+impl<N, F: FnMut(&N, &N) -> Ordering> Ready<N> for Heap<N, F> {
+    fn push(&mut self, node: N) {
+        self.nodes.push(node);
+
+        let mut child = self.nodes.len() - 1;
+
+        while child > 0 {
+            let parent = (child - 1) / 2;
+
+            if !self.less(child, parent) {
+                break;
+            }
+
+            self.nodes.swap(child, parent);
+
+            child = parent;
+        }
+    }
+
+    fn pop(&mut self) -> Option<N> {
+        let last = self.nodes.len().checked_sub(1)?;
+
+        self.nodes.swap(0, last);
+
+        let node = self.nodes.pop();
+
+        let length = self.nodes.len();
+
+        let mut parent = 0;
+
+        loop {
+            let left = 2 * parent + 1;
+            let right = left + 1;
+
+            let mut least = parent;
+
+            if left < length && self.less(left, least) {
+                least = left;
+            }
+
+            if right < length && self.less(right, least) {
+                least = right;
+            }
+
+            if least == parent {
+                break;
+            }
+
+            self.nodes.swap(parent, least);
+
+            parent = least;
+        }
+
+        node
+    }
+}
+
+fn kahn<G, R>(graph: G, mut ready: R) -> Output<G>
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+    R: Ready<G::NodeId>,
+{
+    let bound = graph.node_bound();
+
+    let mut degrees = vec![0; bound];
+
+    for node in graph.node_identifiers() {
+        let degree = graph.incoming_degree(node);
+
+        degrees[graph.node_index(node)] = degree;
+
+        if degree == 0 {
+            ready.push(node);
+        }
+    }
+
+    let mut order = Vec::new();
+
+    while let Some(node) = ready.pop() {
+        order.push(node);
+
+        for edge in graph.outgoing_edges(node) {
+            let (_, target) = graph.endpoints(edge).expect(EDGE);
+
+            let degree = &mut degrees[graph.node_index(target)];
+
+            *degree -= 1;
+
+            if *degree == 0 {
+                ready.push(target);
+            }
+        }
+    }
+
+    // nodes left with positive in-degrees all lie on or after cycles
+
+    let Some(start) = graph
+        .node_identifiers()
+        .find(|&node| degrees[graph.node_index(node)] > 0)
+    else {
+        return Ok(order);
+    };
+
+    Err(witness(&graph, &degrees, start))
+}
+
+/// Finds some cycle among the nodes left with positive in-degrees, starting from `start`.
+///
+/// Every such node has some incoming edge from another such node,
+/// so walking backwards along these edges eventually revisits some node.
+fn witness<G>(graph: &G, degrees: &[usize], start: G::NodeId) -> CycleIn<G>
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+{
+    let mut steps = vec![usize::LIMIT; graph.node_bound()];
+
+    let mut edges = Vec::new();
+
+    let mut node = start;
+
+    loop {
+        let index = graph.node_index(node);
+
+        if !steps[index].is_limit() {
+            // the walk went back to this node, so the cycle starts here
+
+            let mut cycle = edges.split_off(steps[index]);
+
+            cycle.reverse();
+
+            return Cycle::new(cycle);
+        }
+
+        steps[index] = edges.len();
+
+        let (edge, source) = graph
+            .incoming_edges(node)
+            .map(|edge| (edge, graph.endpoints(edge).expect(EDGE).0))
+            .find(|&(_, source)| degrees[graph.node_index(source)] > 0)
+            .expect("expected incoming edge from unordered node");
+
+        edges.push(edge);
+
+        node = source;
+    }
+}
+
+/// Sorts the nodes of the given graph topologically using Kahn's algorithm.
+///
+/// In-degrees are computed once and decremented as nodes get ordered,
+/// so every edge is inspected a constant number of times.
+///
+/// # Errors
+///
+/// Returns [`Cycle`] containing some cycle of the graph if it is not acyclic.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn topological_sort<G>(graph: G) -> Output<G>
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+{
+    kahn(graph, VecDeque::new())
+}
+
+/// Sorts the nodes of the given graph topologically using Kahn's algorithm,
+/// always picking the least of the nodes ready to be ordered according to `compare`.
+///
+/// This makes the order deterministic; for instance, passing [`Ord::cmp`]
+/// results in the lexicographically smallest topological order.
+///
+/// # Errors
+///
+/// Returns [`Cycle`] containing some cycle of the graph if it is not acyclic.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints.
+pub fn topological_sort_by<G, F>(graph: G, compare: F) -> Output<G>
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+    F: FnMut(&G::NodeId, &G::NodeId) -> Ordering,
+{
+    kahn(graph, Heap::new(compare))
+}
+
+/// Represents Kahn's topological sorting algorithm.
+///
+/// See [`topological_sort`] for more information.
+pub struct Kahn;
+
+impl<G> Algorithm<G> for Kahn
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+{
+    type Output = Output<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        topological_sort(graph)
+    }
+}
+
+/// Represents Kahn's topological sorting algorithm with ties broken by some comparator.
+///
+/// See [`topological_sort_by`] for more information.
+pub struct KahnBy<F> {
+    compare: F,
+}
+
+impl<F> KahnBy<F> {
+    /// Constructs [`Self`] with the given `compare` function.
+    pub const fn new(compare: F) -> Self {
+        Self { compare }
+    }
+}
+
+impl<G, F> Algorithm<G> for KahnBy<F>
+where
+    G: NodeIdentifiers + DirectedEdges + Endpoints + NodeIndexed,
+    F: FnMut(&G::NodeId, &G::NodeId) -> Ordering,
+{
+    type Output = Output<G>;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        topological_sort_by(graph, &mut self.compare)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::DiGraph;
+
+    #[test]
+    fn order() {
+        let edges = [(3, 1), (3, 0), (1, 0), (2, 0), (4, 2), (1, 2)];
+
+        let graph = DiGraph::unweighted(5, &edges);
+
+        let order = topological_sort(&graph).unwrap();
+
+        let mut positions = [0; 5];
+
+        for (position, node) in order.iter().enumerate() {
+            positions[node.get()] = position;
+        }
+
+        assert_eq!(order.len(), 5);
+
+        for (source, target) in edges {
+            assert!(positions[source] < positions[target]);
+        }
+
+        let order = topological_sort_by(&graph, Ord::cmp).unwrap();
+
+        assert_eq!(
+            order.iter().map(|node| node.get()).collect::<Vec<_>>(),
+            [3, 1, 4, 2, 0]
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        let graph = DiGraph::unweighted(5, &[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+
+        let cycle = topological_sort(&graph).unwrap_err();
+
+        let mut edges: Vec<_> = cycle.edges.iter().map(|edge| edge.get()).collect();
+
+        edges.sort_unstable();
+
+        assert_eq!(edges, [1, 2, 3]);
+
+        let graph = DiGraph::unweighted(2, &[(0, 1), (1, 1)]);
+
+        let cycle = topological_sort(&graph).unwrap_err();
+
+        assert_eq!(cycle.edges.len(), 1);
+        assert_eq!(cycle.edges[0].get(), 1);
+    }
+}
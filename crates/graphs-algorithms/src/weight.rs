@@ -0,0 +1,53 @@
+//! Weights of edges.
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, Sub},
+};
+
+/// Represents weights that can be summed and compared.
+///
+/// This trait is implemented for all primitive integers and floats.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// The largest representable weight (infinity for floats).
+    const MAX: Self;
+
+    /// Compares two weights, treating incomparable ones (such as `NaN`) as equal.
+    fn compare(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+}
+
+macro_rules! impl_integer_weight {
+    ($($int: ty),+ $(,)?) => {
+        $(
+            impl $crate::weight::Weight for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$int>::MAX;
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float_weight {
+    ($($float: ty),+ $(,)?) => {
+        $(
+            impl $crate::weight::Weight for $float {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const MAX: Self = <$float>::INFINITY;
+            }
+        )+
+    };
+}
+
+impl_integer_weight!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl_float_weight!(f32, f64);
@@ -0,0 +1,381 @@
+//! Weisfeiler-Lehman color refinement and graph fingerprints.
+
+use core::hash::{Hash, Hasher};
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use graphs_core::{
+    algorithm::Algorithm,
+    data::DataRef,
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::NodeIndexed,
+    kinds::Kind,
+};
+
+use crate::{EDGE, NODE, random::mix};
+
+/// Represents the results of color refinement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Refinement {
+    /// The final color of every node, indexed by node indices.
+    ///
+    /// Node indices not present in the graph have no colors.
+    pub colors: Vec<Option<u64>>,
+
+    /// The fingerprint of the whole graph.
+    pub fingerprint: u64,
+}
+
+impl Refinement {
+    /// Constructs [`Self`].
+    pub const fn new(colors: Vec<Option<u64>>, fingerprint: u64) -> Self {
+        Self {
+            colors,
+            fingerprint,
+        }
+    }
+
+    /// Returns the color of the node with the given index, if it is present.
+    pub fn color_of(&self, index: usize) -> Option<u64> {
+        self.colors.get(index).copied().flatten()
+    }
+}
+
+/// Represents stable hashers, which do not depend on any random state or on the platform.
+///
+/// This is the 64-bit FNV-1a hash, with the final value mixed via the `SplitMix64` finalizer.
+/// Integers are written in little-endian order, with sizes widened to 64 bits.
+struct Stable(u64);
+
+impl Stable {
+    const OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01B3;
+
+    const fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+
+    /// Writes the given length, widened to 64 bits.
+    fn write_length(&mut self, length: usize) {
+        self.write_u64(length as u64);
+    }
+
+    /// Writes the given colors, prefixed by their count.
+    fn write_colors(&mut self, colors: &[u64]) {
+        self.write_length(colors.len());
+
+        for &color in colors {
+            self.write_u64(color);
+        }
+    }
+}
+
+impl Hasher for Stable {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_length(value);
+    }
+
+    fn finish(&self) -> u64 {
+        mix(self.0)
+    }
+}
+
+/// Hashes the given value via the stable hasher.
+fn stable<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Stable::new();
+
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+const OUTGOING: u8 = 0;
+const INCOMING: u8 = 1;
+
+/// Refines node colors of the given graph for the given number of `iterations`
+/// using the Weisfeiler-Lehman algorithm, starting from the labels of nodes and edges
+/// computed via the `nodes` and `edges` functions.
+///
+/// Initially, every node is colored by (the stable hash of) its label. Then, during every
+/// iteration, every node is recolored by hashing its color along with the sorted multiset of
+/// the colors of its neighbors paired with the labels of the edges leading to them.
+/// For directed graphs, outgoing and incoming neighbors are distinguished.
+///
+/// The fingerprint combines the counts of nodes and edges along with the sorted multisets
+/// of colors after every iteration. Since nothing here depends on node identifiers or the order
+/// of iteration, isomorphic graphs always get identical colors (of corresponding nodes) and
+/// fingerprints. The converse does not hold, so different fingerprints prove non-isomorphism,
+/// while equal ones do not prove isomorphism.
+///
+/// Hashes are stable, that is, they do not change between runs and platforms, as long as
+/// the [`Hash`] implementations of labels do not. This function runs in `O(k E log E)` time.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values,
+/// or nodes that are missing their values.
+pub fn weisfeiler_lehman_by<G, L, M, F, H>(
+    graph: G,
+    iterations: usize,
+    mut nodes: F,
+    mut edges: H,
+) -> Refinement
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+    L: Hash,
+    M: Hash,
+    F: FnMut(&G::NodeValue) -> L,
+    H: FnMut(&G::EdgeValue) -> M,
+{
+    let bound = graph.node_bound();
+
+    let mut colors = vec![0; bound];
+    let mut present = Vec::new();
+
+    for node in graph.node_identifiers() {
+        let index = graph.node_index(node);
+
+        colors[index] = stable(&nodes(graph.node_value(node).expect(NODE)));
+
+        present.push(index);
+    }
+
+    // neighbors along with edge labels and directions, indexed by node indices
+
+    let mut adjacency: Vec<Vec<(usize, u64, u8)>> = vec![Vec::new(); bound];
+
+    let mut size = 0;
+
+    for edge in graph.edge_identifiers() {
+        let (source, target) = graph.endpoints(edge).expect(EDGE);
+
+        let source = graph.node_index(source);
+        let target = graph.node_index(target);
+
+        let label = stable(&edges(graph.edge_value(edge).expect(EDGE)));
+
+        if G::Kind::DIRECTED {
+            adjacency[source].push((target, label, OUTGOING));
+            adjacency[target].push((source, label, INCOMING));
+        } else {
+            adjacency[source].push((target, label, OUTGOING));
+
+            if source != target {
+                adjacency[target].push((source, label, OUTGOING));
+            }
+        }
+
+        size += 1;
+    }
+
+    let mut fingerprint = Stable::new();
+
+    fingerprint.write_length(present.len());
+    fingerprint.write_length(size);
+
+    let mut sorted = Vec::with_capacity(present.len());
+
+    let mut record = |colors: &[u64], fingerprint: &mut Stable| {
+        sorted.clear();
+        sorted.extend(present.iter().map(|&index| colors[index]));
+        sorted.sort_unstable();
+
+        fingerprint.write_colors(&sorted);
+    };
+
+    record(&colors, &mut fingerprint);
+
+    let mut next = vec![0; bound];
+    let mut signature = Vec::new();
+
+    for _ in 0..iterations {
+        for &index in &present {
+            signature.clear();
+
+            signature.extend(
+                adjacency[index]
+                    .iter()
+                    .map(|&(other, label, direction)| (direction, label, colors[other])),
+            );
+
+            signature.sort_unstable();
+
+            let mut hasher = Stable::new();
+
+            hasher.write_u64(colors[index]);
+            hasher.write_length(signature.len());
+
+            for &(direction, label, color) in &signature {
+                hasher.write_u8(direction);
+                hasher.write_u64(label);
+                hasher.write_u64(color);
+            }
+
+            next[index] = hasher.finish();
+        }
+
+        core::mem::swap(&mut colors, &mut next);
+
+        record(&colors, &mut fingerprint);
+    }
+
+    let mut result = vec![None; bound];
+
+    for &index in &present {
+        result[index] = Some(colors[index]);
+    }
+
+    Refinement::new(result, fingerprint.finish())
+}
+
+/// Refines node colors of the given graph for the given number of `iterations`
+/// using the Weisfeiler-Lehman algorithm, ignoring node and edge values.
+///
+/// See [`weisfeiler_lehman_by`] for more information.
+///
+/// # Panics
+///
+/// Panics if the graph yields edges that are missing their endpoints or values,
+/// or nodes that are missing their values.
+pub fn weisfeiler_lehman<G>(graph: G, iterations: usize) -> Refinement
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    weisfeiler_lehman_by(graph, iterations, |_| (), |_| ())
+}
+
+/// Represents the Weisfeiler-Lehman color refinement algorithm.
+///
+/// See [`weisfeiler_lehman`] for more information.
+pub struct WeisfeilerLehman {
+    iterations: usize,
+}
+
+impl WeisfeilerLehman {
+    /// Constructs [`Self`] with the given number of `iterations`.
+    pub const fn new(iterations: usize) -> Self {
+        Self { iterations }
+    }
+}
+
+impl<G> Algorithm<G> for WeisfeilerLehman
+where
+    G: NodeIdentifiers + NodeIndexed + EdgeIdentifiers + Endpoints + DataRef,
+{
+    type Output = Refinement;
+
+    fn perform(&mut self, graph: G) -> Self::Output {
+        weisfeiler_lehman(graph, self.iterations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{DiGraph, UnGraph};
+
+    #[test]
+    fn isomorphic() {
+        let one = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 3), (1, 3)]);
+        let two = UnGraph::unweighted(4, &[(3, 2), (2, 0), (0, 1), (2, 1)]);
+
+        let first = weisfeiler_lehman(&one, 3);
+        let second = weisfeiler_lehman(&two, 3);
+
+        assert_eq!(first.fingerprint, second.fingerprint);
+
+        // the isomorphism maps 0, 1, 2 and 3 to 3, 2, 0 and 1 respectively
+        for (node, image) in [(0, 3), (1, 2), (2, 0), (3, 1)] {
+            assert_eq!(first.color_of(node), second.color_of(image));
+        }
+
+        assert_eq!(first.color_of(4), None);
+    }
+
+    #[test]
+    fn distinguished() {
+        let path = UnGraph::unweighted(4, &[(0, 1), (1, 2), (2, 3)]);
+        let star = UnGraph::unweighted(4, &[(0, 1), (0, 2), (0, 3)]);
+
+        assert_eq!(
+            weisfeiler_lehman(&path, 0).fingerprint,
+            weisfeiler_lehman(&star, 0).fingerprint
+        );
+
+        assert_ne!(
+            weisfeiler_lehman(&path, 1).fingerprint,
+            weisfeiler_lehman(&star, 1).fingerprint
+        );
+
+        let forward = DiGraph::unweighted(3, &[(0, 1), (1, 2)]);
+        let inward = DiGraph::unweighted(3, &[(0, 1), (2, 1)]);
+
+        assert_ne!(
+            weisfeiler_lehman(&forward, 1).fingerprint,
+            weisfeiler_lehman(&inward, 1).fingerprint
+        );
+    }
+
+    #[test]
+    fn regular() {
+        // color refinement can not distinguish regular graphs of the same degree
+        let triangles = UnGraph::unweighted(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        let hexagon = UnGraph::unweighted(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+
+        assert_eq!(
+            weisfeiler_lehman(&triangles, 5).fingerprint,
+            weisfeiler_lehman(&hexagon, 5).fingerprint
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let one = UnGraph::weighted(3, [(0, 1, 1u8), (1, 2, 2)]);
+        let two = UnGraph::weighted(3, [(0, 1, 1u8), (1, 2, 1)]);
+
+        let refine = |graph| weisfeiler_lehman_by(graph, 1, |()| (), |&label| label).fingerprint;
+
+        assert_ne!(refine(&one), refine(&two));
+
+        assert_eq!(
+            weisfeiler_lehman(&one, 1).fingerprint,
+            weisfeiler_lehman(&two, 1).fingerprint
+        );
+    }
+
+    #[test]
+    fn stable() {
+        let graph = UnGraph::unweighted(3, &[(0, 1), (1, 2)]);
+
+        let refinement = weisfeiler_lehman(&graph, 2);
+
+        // fingerprints must not depend on the platform, in particular on its endianness and width
+        assert_eq!(refinement.fingerprint, 0xC3D6_277A_ECD1_2BEA);
+    }
+}
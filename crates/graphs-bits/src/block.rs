@@ -61,6 +61,10 @@ pub trait BitBlock:
     fn checked_shift_left(self, shift: Bits) -> Option<Self>;
     fn checked_shift_right(self, shift: Bits) -> Option<Self>;
 
+    fn count_ones(self) -> usize;
+
+    fn trailing_zeros(self) -> Bits;
+
     fn blocks(bits: usize) -> usize {
         blocks::<Self>(bits)
     }
@@ -139,6 +143,14 @@ macro_rules! impl_primitive {
                 fn checked_shift_right(self, shift: Bits) -> Option<Self> {
                     self.checked_shr(shift as $crate::block::Base)
                 }
+
+                fn count_ones(self) -> usize {
+                    self.count_ones() as usize
+                }
+
+                fn trailing_zeros(self) -> Bits {
+                    self.trailing_zeros() as Bits
+                }
             }
         )+
     };
@@ -1,6 +1,8 @@
 #[cfg(not(any(feature = "std", feature = "alloc")))]
 compile_error!("expected either `std` or `alloc` to be enabled");
 
+use core::slice;
+
 use graphs_core::{index::NodeTypeIndex, visit::Visitor};
 
 use crate::{
@@ -27,16 +29,175 @@ impl<B: BitBlock> BitSet<B> {
         Self { bits }
     }
 
+    pub fn with_capacity(bits: usize) -> Self {
+        Self::construct(BitVec::repeat(false, bits))
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.bits.len()
+    }
+
     pub fn insert(&mut self, value: usize) -> bool {
-        todo!()
+        if value >= self.bits.len() {
+            self.bits.resize(value + 1, false);
+        }
+
+        !self.bits.set(value, true).unwrap_or(true)
     }
 
     pub fn contains(&self, value: usize) -> bool {
-        todo!()
+        self.bits.get(value).unwrap_or(false)
     }
 
     pub fn remove(&mut self, value: usize) -> bool {
-        todo!()
+        self.bits.set(value, false).unwrap_or(false)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.none()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.blocks_mut().for_each(|block| *block = B::ZERO);
+    }
+
+    pub fn iter(&self) -> Iter<'_, B> {
+        Iter::new(self.bits.blocks())
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        if other.bits.len() > self.bits.len() {
+            self.bits.resize(other.bits.len(), false);
+        }
+
+        self.bits
+            .blocks_mut()
+            .zip(other.bits.blocks())
+            .for_each(|(block, &other)| *block = *block | other);
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        let mut others = other.bits.blocks();
+
+        for block in self.bits.blocks_mut() {
+            *block = others.next().map_or(B::ZERO, |&other| *block & other);
+        }
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        self.bits
+            .blocks_mut()
+            .zip(other.bits.blocks())
+            .for_each(|(block, &other)| *block = *block & !other);
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &Self) {
+        if other.bits.len() > self.bits.len() {
+            self.bits.resize(other.bits.len(), false);
+        }
+
+        self.bits
+            .blocks_mut()
+            .zip(other.bits.blocks())
+            .for_each(|(block, &other)| *block = *block ^ other);
+    }
+
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        self.bits
+            .blocks()
+            .zip(other.bits.blocks())
+            .map(|(&block, &other)| (block & other).count_ones())
+            .sum()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.bits
+            .blocks()
+            .zip(other.bits.blocks())
+            .all(|(&block, &other)| (block & other).is_zero())
+    }
+}
+
+impl<B: BitBlock> Clone for BitSet<B> {
+    fn clone(&self) -> Self {
+        Self::construct(self.bits.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.bits.clear();
+        self.bits.resize(source.bits.len(), false);
+
+        self.bits
+            .blocks_mut()
+            .zip(source.bits.blocks())
+            .for_each(|(block, &other)| *block = other);
+    }
+}
+
+impl<B: BitBlock> Extend<usize> for BitSet<B> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iterable: I) {
+        iterable.into_iter().for_each(|value| {
+            self.insert(value);
+        });
+    }
+}
+
+impl<B: BitBlock> FromIterator<usize> for BitSet<B> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iterable: I) -> Self {
+        let mut set = Self::new();
+
+        set.extend(iterable);
+
+        set
+    }
+}
+
+impl<'s, B: BitBlock> IntoIterator for &'s BitSet<B> {
+    type Item = usize;
+    type IntoIter = Iter<'s, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'s, B: BitBlock = DefaultBlock> {
+    blocks: slice::Iter<'s, B>,
+    current: B,
+    offset: usize,
+}
+
+impl<'s, B: BitBlock> Iter<'s, B> {
+    pub(crate) fn new(mut blocks: slice::Iter<'s, B>) -> Self {
+        let current = blocks.next().copied().unwrap_or(B::ZERO);
+
+        Self {
+            blocks,
+            current,
+            offset: 0,
+        }
+    }
+}
+
+impl<B: BitBlock> Iterator for Iter<'_, B> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current.is_zero() {
+            self.current = *self.blocks.next()?;
+
+            self.offset += B::BITS as usize;
+        }
+
+        let bit = self.current.trailing_zeros();
+
+        self.current = self.current & !B::flag(bit)?;
+
+        Some(self.offset + bit as usize)
     }
 }
 
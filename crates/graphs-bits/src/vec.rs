@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    block::{BitBlock, DefaultBlock, Overflow, bits, try_bits},
+    block::{self, BitBlock, DefaultBlock, Overflow, bits, try_bits},
     capacity::{Bits, Blocks, Capacity},
 };
 
@@ -30,6 +30,9 @@ pub struct BitVec<B: BitBlock = DefaultBlock> {
 
 impl<B: BitBlock> Clone for BitVec<B> {
     fn clone(&self) -> Self {
+        debug_assert!(self.is_last_block_fine());
+
+        // SAFETY: `self` upholds the invariants already
         unsafe { Self::construct_unchecked(self.storage.clone(), self.bits) }
     }
 }
@@ -58,7 +61,7 @@ impl<B: BitBlock> Extend<bool> for BitVec<B> {
 
         self.reserve_bits(lower);
 
-        // iterator.for_each(|bit| self.push(bit));
+        iterator.for_each(|bit| self.push(bit));
     }
 }
 
@@ -96,10 +99,13 @@ impl<B: BitBlock> BitVec<B> {
 
         let storage = vec![block; blocks];
 
+        // SAFETY: the blocks fit `count` bits, and the extra ones are unset right after
         let mut bits = unsafe { Self::construct_unchecked(storage, count) };
 
         bits.fix_last_block();
 
+        debug_assert!(bits.is_last_block_fine());
+
         bits
     }
 
@@ -127,6 +133,12 @@ impl<B: BitBlock> BitVec<B> {
         unsafe { Self::construct_unchecked(storage, 0) }
     }
 
+    /// Constructs [`Self`] from the given `storage`, using the first `bits` bits of it.
+    ///
+    /// # Safety
+    ///
+    /// The `storage` must contain exactly as many blocks as are needed to store `bits` bits,
+    /// and all bits of the last block past `bits` must be unset before the result is used.
     pub const unsafe fn construct_unchecked(storage: Vec<B>, bits: usize) -> Self {
         Self { storage, bits }
     }
@@ -169,7 +181,9 @@ impl<B: BitBlock> BitVec<B> {
     }
 
     pub fn try_reserve_blocks(&mut self, blocks: usize) -> Result<(), CapacityOverflow> {
-        todo!()
+        self.storage
+            .try_reserve(blocks)
+            .map_err(|_| CapacityOverflow)
     }
 
     pub fn shrink_to_fit(&mut self) {
@@ -193,6 +207,82 @@ impl<B: BitBlock> BitVec<B> {
     // }
 }
 
+impl<B: BitBlock> BitVec<B> {
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let (block, bit) = B::div_rem(index);
+
+        self.storage[block].get(bit as block::Bits)
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) -> Option<bool> {
+        let previous = self.get(index)?;
+
+        let (block, bit) = B::div_rem(index);
+
+        let flag = B::flag(bit as block::Bits)?;
+
+        let block = &mut self.storage[block];
+
+        *block = if value { *block | flag } else { *block & !flag };
+
+        Some(previous)
+    }
+
+    pub fn push(&mut self, value: bool) {
+        if self.extra() == 0 {
+            self.storage.push(B::ZERO);
+        }
+
+        self.bits += 1;
+
+        self.set(self.bits - 1, value);
+    }
+
+    pub fn resize(&mut self, length: usize, value: bool) {
+        if length <= self.len() {
+            self.storage.truncate(B::blocks(length));
+
+            self.bits = length;
+
+            self.fix_last_block();
+
+            return;
+        }
+
+        // fill the last block bit by bit, and then add whole blocks
+
+        while self.len() < length && self.extra() != 0 {
+            self.push(value);
+        }
+
+        let block = if value { B::ALL } else { B::ZERO };
+
+        self.storage.resize(B::blocks(length), block);
+
+        self.bits = length;
+
+        self.fix_last_block();
+    }
+
+    pub fn clear(&mut self) {
+        self.storage.clear();
+
+        self.bits = 0;
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.blocks().map(|&block| block.count_ones()).sum()
+    }
+}
+
 impl<B: BitBlock> BitVec<B> {
     pub fn all(&self) -> bool {
         let mut iterator = self.blocks().copied();
@@ -215,34 +305,38 @@ impl<B: BitBlock> BitVec<B> {
 
 pub(crate) struct LastBlockRef<'b, B: BitBlock = DefaultBlock> {
     block: &'b B,
-    extra: usize,
+    extra: block::Bits,
 }
 
 impl<'b, B: BitBlock> LastBlockRef<'b, B> {
-    pub(crate) const fn new(block: &'b B, extra: usize) -> Self {
+    pub(crate) const fn new(block: &'b B, extra: block::Bits) -> Self {
         Self { block, extra }
     }
 
     pub(crate) fn is_fine(&self) -> bool {
-        *self.block & B::inverse_mask(self.extra) == B::ZERO
+        *self.block & self.inverse_mask() == B::ZERO
     }
 
     pub fn fixed(&self) -> B {
-        *self.block & B::mask(self.extra)
+        *self.block & self.mask()
+    }
+
+    fn mask(&self) -> B {
+        B::mask(self.extra).unwrap_or(B::ALL)
     }
 
-    pub fn fixed_inverse(&self) -> B {
-        *self.block | B::inverse_mask(self.extra)
+    fn inverse_mask(&self) -> B {
+        !self.mask()
     }
 }
 
 pub(crate) struct LastBlockMut<'b, B: BitBlock = DefaultBlock> {
     block: &'b mut B,
-    extra: usize,
+    extra: block::Bits,
 }
 
 impl<'b, B: BitBlock> LastBlockMut<'b, B> {
-    pub(crate) const fn new(block: &'b mut B, extra: usize) -> Self {
+    pub(crate) const fn new(block: &'b mut B, extra: block::Bits) -> Self {
         Self { block, extra }
     }
 
@@ -250,26 +344,21 @@ impl<'b, B: BitBlock> LastBlockMut<'b, B> {
         LastBlockRef::new(self.block, self.extra)
     }
 
-    pub fn is_fine(&self) -> bool {
-        self.as_ref().is_fine()
-    }
-
     pub fn fix(&mut self) {
         *self.block = self.as_ref().fixed();
     }
-
-    pub fn fix_inverse(&mut self) {
-        *self.block = self.as_ref().fixed_inverse();
-    }
 }
 
 impl<B: BitBlock> BitVec<B> {
-    pub(crate) const fn extra(&self) -> usize {
-        self.len() % B::BITS
+    pub(crate) const fn extra(&self) -> block::Bits {
+        (self.len() % B::BITS as usize) as block::Bits
     }
 
     pub fn extra_mask(&self) -> B {
-        B::mask(self.extra())
+        match self.extra() {
+            0 => B::ALL,
+            extra => B::mask(extra).unwrap_or(B::ALL),
+        }
     }
 
     pub(crate) fn is_last_block_fine(&self) -> bool {
@@ -282,12 +371,6 @@ impl<B: BitBlock> BitVec<B> {
         }
     }
 
-    pub(crate) fn fix_last_block_inverse(&mut self) {
-        if let Some(mut last_block) = self.last_block_mut() {
-            last_block.fix_inverse();
-        }
-    }
-
     pub(crate) fn last_block(&self) -> Option<LastBlockRef<'_, B>> {
         let extra = self.extra();
 
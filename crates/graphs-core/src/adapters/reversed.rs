@@ -1,9 +1,21 @@
 use crate::{
     base::{Base, Directed},
     connections::Connection,
-    kinds::Kind,
+    count::{EdgeCount, NodeCount},
+    data::{Data, DataRef},
+    direction::Direction,
+    edges::{DirectedEdges, Edges},
+    endpoints::Endpoints,
+    identifiers::{EdgeIdentifiers, NodeIdentifiers},
+    indexed::{EdgeIndexed, NodeIndexed},
+    neighbors::{DirectedNeighbors, Neighbors},
+    visit::Visit,
 };
 
+/// Represents directed graphs with the directions of all edges reversed.
+///
+/// Outgoing neighbors and edges of nodes become incoming ones, and vice versa;
+/// nodes and edges themselves, along with their identifiers and values, are left intact.
 pub struct Reversed<G: Directed> {
     graph: G,
 }
@@ -12,9 +24,9 @@ impl<G: Directed> Base for Reversed<G> {
     type NodeId = G::NodeId;
     type EdgeId = G::EdgeId;
 
-    type Connection = <G::Connection as Connection>::Inverse;
+    type Connection = G::Connection;
 
-    type Kind = <G::Kind as Kind>::Inverse;
+    type Kind = G::Kind;
     type Type = G::Type;
     type Loop = G::Loop;
 }
@@ -36,3 +48,150 @@ impl<G: Directed> Reversed<G> {
         self.graph
     }
 }
+
+impl<G: Directed + Data> Data for Reversed<G> {
+    type NodeValue = G::NodeValue;
+    type EdgeValue = G::EdgeValue;
+}
+
+impl<G: Directed + DataRef> DataRef for Reversed<G> {
+    fn node_value(&self, id: Self::NodeId) -> Option<&Self::NodeValue> {
+        self.graph.node_value(id)
+    }
+
+    fn edge_value(&self, id: Self::EdgeId) -> Option<&Self::EdgeValue> {
+        self.graph.edge_value(id)
+    }
+}
+
+impl<G: Directed + NodeCount> NodeCount for Reversed<G> {
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+impl<G: Directed + EdgeCount> EdgeCount for Reversed<G> {
+    fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+impl<G: Directed + NodeIdentifiers> NodeIdentifiers for Reversed<G> {
+    type Identifiers<'i>
+        = G::Identifiers<'i>
+    where
+        Self: 'i;
+
+    fn node_identifiers(&self) -> Self::Identifiers<'_> {
+        self.graph.node_identifiers()
+    }
+}
+
+impl<G: Directed + EdgeIdentifiers> EdgeIdentifiers for Reversed<G> {
+    type Identifiers<'i>
+        = G::Identifiers<'i>
+    where
+        Self: 'i;
+
+    fn edge_identifiers(&self) -> Self::Identifiers<'_> {
+        self.graph.edge_identifiers()
+    }
+}
+
+impl<G: Directed + NodeIndexed> NodeIndexed for Reversed<G> {
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+
+    fn node_index(&self, id: Self::NodeId) -> usize {
+        self.graph.node_index(id)
+    }
+
+    fn node_id(&self, index: usize) -> Self::NodeId {
+        self.graph.node_id(index)
+    }
+}
+
+impl<G: Directed + EdgeIndexed> EdgeIndexed for Reversed<G> {
+    fn edge_bound(&self) -> usize {
+        self.graph.edge_bound()
+    }
+
+    fn edge_index(&self, id: Self::EdgeId) -> usize {
+        self.graph.edge_index(id)
+    }
+
+    fn edge_id(&self, index: usize) -> Self::EdgeId {
+        self.graph.edge_id(index)
+    }
+}
+
+impl<G: Directed + Endpoints> Endpoints for Reversed<G> {
+    fn connection(&self, edge: Self::EdgeId) -> Option<Self::Connection> {
+        self.graph.connection(edge).map(|mut connection| {
+            connection.reverse();
+
+            connection
+        })
+    }
+}
+
+impl<G: DirectedNeighbors> Neighbors for Reversed<G> {
+    type Iterator<'n>
+        = G::DirectedIterator<'n>
+    where
+        Self: 'n;
+
+    fn neighbors(&self, node: Self::NodeId) -> Self::Iterator<'_> {
+        self.graph.incoming_neighbors(node)
+    }
+}
+
+impl<G: DirectedNeighbors> DirectedNeighbors for Reversed<G> {
+    type DirectedIterator<'n>
+        = G::DirectedIterator<'n>
+    where
+        Self: 'n;
+
+    fn directed_neighbors(
+        &self,
+        node: Self::NodeId,
+        direction: Direction,
+    ) -> Self::DirectedIterator<'_> {
+        self.graph.directed_neighbors(node, direction.reversed())
+    }
+}
+
+impl<G: DirectedEdges> Edges for Reversed<G> {
+    type Iterator<'e>
+        = G::DirectedIterator<'e>
+    where
+        Self: 'e;
+
+    fn edges(&self, node: Self::NodeId) -> Self::Iterator<'_> {
+        self.graph.incoming_edges(node)
+    }
+}
+
+impl<G: DirectedEdges> DirectedEdges for Reversed<G> {
+    type DirectedIterator<'e>
+        = G::DirectedIterator<'e>
+    where
+        Self: 'e;
+
+    fn edges_in(&self, direction: Direction, node: Self::NodeId) -> Self::DirectedIterator<'_> {
+        self.graph.edges_in(direction.reversed(), node)
+    }
+}
+
+impl<G: Directed + Visit> Visit for Reversed<G> {
+    type Visitor = G::Visitor;
+
+    fn build_visitor(&self) -> Self::Visitor {
+        self.graph.build_visitor()
+    }
+
+    fn reset_visitor(&self, visitor: &mut Self::Visitor) {
+        self.graph.reset_visitor(visitor);
+    }
+}
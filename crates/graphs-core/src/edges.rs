@@ -75,3 +75,25 @@ pub trait DirectedEdges: Directed + Edges {
         self.incoming_edges(node).next().is_some()
     }
 }
+
+impl<G: DirectedEdges + ?Sized> DirectedEdges for &G {
+    type DirectedIterator<'e>
+        = G::DirectedIterator<'e>
+    where
+        Self: 'e;
+
+    fn edges_in(&self, direction: Direction, node: Self::NodeId) -> Self::DirectedIterator<'_> {
+        (*self).edges_in(direction, node)
+    }
+}
+
+impl<G: DirectedEdges + ?Sized> DirectedEdges for &mut G {
+    type DirectedIterator<'e>
+        = G::DirectedIterator<'e>
+    where
+        Self: 'e;
+
+    fn edges_in(&self, direction: Direction, node: Self::NodeId) -> Self::DirectedIterator<'_> {
+        (**self).edges_in(direction, node)
+    }
+}
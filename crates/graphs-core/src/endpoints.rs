@@ -0,0 +1,34 @@
+//! Traits for graphs that can return endpoints of edges.
+
+use crate::{base::Base, connections::Connection};
+
+/// Represents graphs that can return the [`Connection`] of the given edge.
+pub trait Endpoints: Base {
+    /// Returns the connection of the given `edge`, if any.
+    fn connection(&self, edge: Self::EdgeId) -> Option<Self::Connection>;
+
+    /// Returns the endpoints of the given `edge`, if any.
+    fn endpoints(&self, edge: Self::EdgeId) -> Option<(Self::NodeId, Self::NodeId)> {
+        self.connection(edge).map(Connection::into_parts)
+    }
+
+    /// Returns the endpoint of the given `edge` opposite to the given `node`, if any.
+    ///
+    /// For loops, this method returns the `node` itself.
+    fn opposite(&self, edge: Self::EdgeId, node: Self::NodeId) -> Option<Self::NodeId> {
+        self.endpoints(edge)
+            .map(|(one, two)| if one == node { two } else { one })
+    }
+}
+
+impl<G: Endpoints + ?Sized> Endpoints for &G {
+    fn connection(&self, edge: Self::EdgeId) -> Option<Self::Connection> {
+        (*self).connection(edge)
+    }
+}
+
+impl<G: Endpoints + ?Sized> Endpoints for &mut G {
+    fn connection(&self, edge: Self::EdgeId) -> Option<Self::Connection> {
+        (**self).connection(edge)
+    }
+}
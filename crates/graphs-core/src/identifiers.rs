@@ -31,7 +31,7 @@ impl<G: NodeIdentifiers + ?Sized> NodeIdentifiers for &mut G {
 }
 
 pub trait EdgeIdentifiers: Base {
-    type Identifiers<'i>: Iterator<Item = Self::NodeId>
+    type Identifiers<'i>: Iterator<Item = Self::EdgeId>
     where
         Self: 'i;
 
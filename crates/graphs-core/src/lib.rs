@@ -24,6 +24,7 @@ pub mod data;
 pub mod degree;
 pub mod direction;
 pub mod edges;
+pub mod endpoints;
 pub mod exhaust;
 pub mod extend;
 pub mod find;
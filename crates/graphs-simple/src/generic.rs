@@ -7,9 +7,11 @@ use graphs_core::{
     base::Base,
     capacity::{Capacities, EdgeCapacity, NodeCapacity},
     clear::{Clear, ClearEdges},
+    connections::Connection as _,
     count::{Counts, EdgeCount, NodeCount},
     create::Create,
     data::{Data, DataMut, DataRef},
+    endpoints::Endpoints,
     index::{DefaultUntypedIndex, EdgeIndex, Index, NodeIndex, UntypedIndex},
     indexed::{EdgeIndexed, NodeIndexed},
     kinds::{DefaultKind, Kind},
@@ -104,6 +106,18 @@ impl<N, E, I: UntypedIndex, K: Kind, T: Type, L: Loop> DataMut for GenericGraph<
     }
 }
 
+impl<N, E, I: UntypedIndex, K: Kind, T: Type, L: Loop> Endpoints
+    for GenericGraph<N, E, I, K, T, L>
+{
+    fn connection(&self, edge: Self::EdgeId) -> Option<Self::Connection> {
+        self.edge(edge).map(|edge| {
+            let (one, two) = edge.connection.parts();
+
+            Connection::connecting(*one, *two)
+        })
+    }
+}
+
 impl<N, E, I: UntypedIndex, K: Kind, T: Type, L: Loop> NodeCount
     for GenericGraph<N, E, I, K, T, L>
 {
@@ -6,7 +6,9 @@
 extern crate alloc;
 
 pub mod rank;
+pub mod rollback;
 pub mod vec;
 
 pub use rank::{Rank, ZERO};
+pub use rollback::RollbackUnionFind;
 pub use vec::UnionFind;
@@ -0,0 +1,117 @@
+use core::mem::swap;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use graphs_core::index::{DefaultUntypedIndex, Index};
+
+/// Represents union-find structures that can undo unions.
+///
+/// Unions are performed by size without path compression, so every union can be
+/// rolled back in constant time, while finding takes logarithmic time.
+pub struct RollbackUnionFind<K: Index = DefaultUntypedIndex> {
+    parent: Vec<K>,
+    size: Vec<usize>,
+    history: Vec<K>,
+}
+
+impl<K: Index> RollbackUnionFind<K> {
+    pub const fn empty() -> Self {
+        Self::construct(Vec::new(), Vec::new())
+    }
+
+    const fn construct(parent: Vec<K>, size: Vec<usize>) -> Self {
+        Self {
+            parent,
+            size,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn new(count: usize) -> Self {
+        let parent = (0..count).map(K::of).collect();
+
+        let size = vec![1; count];
+
+        Self::construct(parent, size)
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn find(&self, key: K) -> K {
+        let mut key = key;
+
+        loop {
+            let parent = self.parent[key.index()];
+
+            if parent == key {
+                return key;
+            }
+
+            key = parent;
+        }
+    }
+
+    pub fn same_set(&self, one: K, two: K) -> bool {
+        self.find(one) == self.find(two)
+    }
+
+    /// Merges the sets containing `one` and `two`.
+    ///
+    /// Returns [`true`] if the sets were merged, or [`false`] if they were already the same set.
+    pub fn union(&mut self, one: K, two: K) -> bool {
+        let mut one_root = self.find(one);
+        let mut two_root = self.find(two);
+
+        if one_root == two_root {
+            return false;
+        }
+
+        // union by size
+
+        if self.size[one_root.index()] < self.size[two_root.index()] {
+            swap(&mut one_root, &mut two_root);
+        }
+
+        self.parent[two_root.index()] = one_root;
+        self.size[one_root.index()] += self.size[two_root.index()];
+
+        self.history.push(two_root);
+
+        true
+    }
+
+    /// Returns the current time, which is the number of unions performed.
+    ///
+    /// This can later be passed to [`rollback`] to undo unions performed after it.
+    ///
+    /// [`rollback`]: Self::rollback
+    pub const fn time(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes all unions performed after the given `time`.
+    pub fn rollback(&mut self, time: usize) {
+        let Self {
+            parent,
+            size,
+            history,
+        } = self;
+
+        // undo unions in reverse order
+
+        for child in history.drain(time..).rev() {
+            let index = child.index();
+
+            size[parent[index].index()] -= size[index];
+
+            parent[index] = child;
+        }
+    }
+}
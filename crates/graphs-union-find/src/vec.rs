@@ -1,11 +1,11 @@
-use core::{fmt, marker::PhantomData};
+use core::cmp::Ordering;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
 use graphs_core::index::{DefaultUntypedIndex, Index};
 
-use crate::rank::Rank;
+use crate::rank::{Rank, ZERO};
 
 pub struct UnionFind<K: Index = DefaultUntypedIndex> {
     parent: Vec<K>,
@@ -83,6 +83,91 @@ impl<K: Index> UnionFind<K> {
     pub const fn len(&self) -> usize {
         self.parent.len()
     }
+
+    pub fn new(count: usize) -> Self {
+        let parent = (0..count).map(K::of).collect();
+
+        let rank = vec![ZERO; count];
+
+        Self::construct(parent, rank)
+    }
+
+    pub fn new_set(&mut self) -> K {
+        let key = K::of(self.len());
+
+        self.parent.push(key);
+        self.rank.push(ZERO);
+
+        key
+    }
+
+    pub fn find(&self, key: K) -> K {
+        let mut key = key;
+
+        loop {
+            let parent = self.parent[key.index()];
+
+            if parent == key {
+                return key;
+            }
+
+            key = parent;
+        }
+    }
+
+    pub fn find_mut(&mut self, key: K) -> K {
+        let root = self.find(key);
+
+        // path compression
+
+        let mut key = key;
+
+        while key != root {
+            let index = key.index();
+
+            key = self.parent[index];
+
+            self.parent[index] = root;
+        }
+
+        root
+    }
+
+    pub fn same_set(&self, one: K, two: K) -> bool {
+        self.find(one) == self.find(two)
+    }
+
+    pub fn same_set_mut(&mut self, one: K, two: K) -> bool {
+        self.find_mut(one) == self.find_mut(two)
+    }
+
+    /// Merges the sets containing `one` and `two`.
+    ///
+    /// Returns [`true`] if the sets were merged, or [`false`] if they were already the same set.
+    pub fn union(&mut self, one: K, two: K) -> bool {
+        let one_root = self.find_mut(one);
+        let two_root = self.find_mut(two);
+
+        if one_root == two_root {
+            return false;
+        }
+
+        let one_index = one_root.index();
+        let two_index = two_root.index();
+
+        // union by rank
+
+        match self.rank[one_index].cmp(&self.rank[two_index]) {
+            Ordering::Less => self.parent[one_index] = two_root,
+            Ordering::Greater => self.parent[two_index] = one_root,
+            Ordering::Equal => {
+                self.parent[two_index] = one_root;
+                self.rank[one_index] += 1;
+            }
+        }
+
+        true
+    }
 }
 
 impl<K: Index> UnionFindMethods for UnionFind<K> {